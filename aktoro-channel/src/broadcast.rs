@@ -0,0 +1,341 @@
+//! A broadcast channel, where every
+//! receiver gets a clone of every
+//! message sent over the channel.
+//!
+//! The channel keeps the `cap` last
+//! messages sent over it, and a receiver
+//! that is too slow to receive them
+//! before they are overwritten gets an
+//! error containing the number of
+//! messages it missed.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task;
+use std::task::Context;
+use std::task::Poll;
+
+use crossbeam_utils::atomic::AtomicCell;
+use futures_core::Stream;
+
+use crate::error::*;
+
+type Waker = Arc<AtomicCell<Option<task::Waker>>>;
+
+/// A broadcast channel's sender allowing
+/// to send messages to all its receivers.
+pub struct Sender<T> {
+    /// The channel the sender will send
+    /// messages over.
+    channel: Arc<Channel<T>>,
+}
+
+/// A broadcast channel's receiver allowing
+/// to get all the messages sent over it
+/// (after its creation) either via the
+/// [`try_recv`] method or the `Stream`
+/// implementation.
+///
+/// [`try_recv`]: #method.try_recv
+pub struct Receiver<T> {
+    /// The channel the receiver will
+    /// receive messages from.
+    channel: Arc<Channel<T>>,
+    /// The sequence number of the next
+    /// message the receiver will get.
+    next: u64,
+    /// A reference to the space the
+    /// receiver was assigned to store
+    /// its waker.
+    waker: Waker,
+}
+
+/// The channel shared by the senders and
+/// receivers of a broadcast channel.
+struct Channel<T> {
+    /// The buffer holding the last
+    /// messages sent over the channel.
+    buffer: Mutex<Buffer<T>>,
+    /// The maximum number of messages
+    /// kept in the buffer.
+    cap: usize,
+    /// Whether the channel is closed.
+    closed: AtomicBool,
+    /// The number of senders connected
+    /// to the channel.
+    senders: AtomicUsize,
+    /// The number of receivers connected
+    /// to the channel.
+    recvers: AtomicUsize,
+}
+
+/// The buffer of a broadcast channel.
+struct Buffer<T> {
+    /// The last messages sent over the
+    /// channel.
+    msgs: VecDeque<T>,
+    /// The sequence number of the oldest
+    /// message in `msgs`.
+    head: u64,
+    /// The spaces the receivers were
+    /// assigned to store their waker (one
+    /// per receiver, so that a receiver
+    /// polled several times only stores
+    /// its latest waker).
+    wakers: Vec<Waker>,
+}
+
+/// Creates a new broadcast channel keeping
+/// the `cap` last messages sent over it,
+/// returning a sender and a receiver
+/// connected to it.
+pub fn new<T: Clone>(cap: usize) -> (Sender<T>, Receiver<T>) {
+    let channel = Arc::new(Channel {
+        buffer: Mutex::new(Buffer {
+            msgs: VecDeque::with_capacity(cap.max(1)),
+            head: 0,
+            wakers: vec![],
+        }),
+        cap: cap.max(1),
+        closed: AtomicBool::new(false),
+        senders: AtomicUsize::new(1),
+        recvers: AtomicUsize::new(0),
+    });
+
+    let sender = Sender {
+        channel: channel.clone(),
+    };
+
+    let recver = sender.subscribe();
+
+    (sender, recver)
+}
+
+impl<T: Clone> Sender<T> {
+    /// Sends a message to all the receivers
+    /// connected to the channel, returning
+    /// the number of receivers or an error
+    /// if the channel is closed.
+    ///
+    /// If the channel's buffer is full, the
+    /// oldest message is overwritten.
+    pub fn send(&self, msg: T) -> Result<usize, TrySendError<T>> {
+        if self.channel.closed.load(Ordering::SeqCst) {
+            return Err(TrySendError::closed(msg));
+        }
+
+        let recvers = self.channel.recvers.load(Ordering::SeqCst);
+
+        // If no receiver is connected,
+        // there is no need to keep the
+        // message.
+        if recvers == 0 {
+            return Ok(0);
+        }
+
+        let wakers = {
+            let mut buffer = self.channel.buffer.lock().unwrap();
+
+            if buffer.msgs.len() == self.channel.cap {
+                buffer.msgs.pop_front();
+                buffer.head += 1;
+            }
+
+            buffer.msgs.push_back(msg);
+
+            buffer.take_wakers()
+        };
+
+        // We wake up the receivers waiting
+        // for a new message.
+        for waker in wakers {
+            waker.wake();
+        }
+
+        Ok(recvers)
+    }
+
+    /// Creates a new receiver connected to
+    /// the channel, that will receive the
+    /// messages sent after its creation.
+    pub fn subscribe(&self) -> Receiver<T> {
+        Channel::subscribe(&self.channel)
+    }
+
+    /// Returns the number of receivers
+    /// connected to the channel.
+    pub fn receivers(&self) -> usize {
+        self.channel.recvers.load(Ordering::SeqCst)
+    }
+
+    /// Closes the channel.
+    pub fn close_channel(&self) {
+        self.channel.close();
+    }
+}
+
+impl<T: Clone> Receiver<T> {
+    /// Tries to receive the next message
+    /// from the channel, returning an error
+    /// if the receiver missed messages
+    /// (after which the receiver will
+    /// receive the oldest message available)
+    /// or if the channel is closed and all
+    /// messages have been received.
+    pub fn try_recv(&mut self) -> Result<Option<T>, TryRecvError> {
+        let buffer = self.channel.buffer.lock().unwrap();
+
+        // If the receiver is too late, we
+        // skip the messages it missed.
+        if self.next < buffer.head {
+            let missed = buffer.head - self.next;
+            self.next = buffer.head;
+
+            return Err(TryRecvError::lagged(missed));
+        }
+
+        let pos = (self.next - buffer.head) as usize;
+        if let Some(msg) = buffer.msgs.get(pos) {
+            self.next += 1;
+            Ok(Some(msg.clone()))
+        } else if self.channel.closed.load(Ordering::SeqCst) {
+            Err(TryRecvError::closed())
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Creates a new receiver connected to
+    /// the channel, that will receive the
+    /// messages sent after its creation.
+    pub fn subscribe(&self) -> Receiver<T> {
+        Channel::subscribe(&self.channel)
+    }
+
+    /// Whether the channel the receiver
+    /// is connected to is closed.
+    pub fn is_closed(&self) -> bool {
+        self.channel.closed.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> Channel<T> {
+    /// Creates a new receiver connected to
+    /// `channel`, that will receive the
+    /// messages sent after its creation.
+    fn subscribe(channel: &Arc<Self>) -> Receiver<T> {
+        let mut buffer = channel.buffer.lock().unwrap();
+
+        channel.recvers.fetch_add(1, Ordering::SeqCst);
+
+        Receiver {
+            channel: channel.clone(),
+            next: buffer.head + buffer.msgs.len() as u64,
+            waker: buffer.register(),
+        }
+    }
+
+    /// Closes the channel, waking up the
+    /// receivers waiting for a new
+    /// message.
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+
+        let wakers = self.buffer.lock().unwrap().take_wakers();
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Buffer<T> {
+    /// Assigns a new space to store a
+    /// receiver's waker.
+    fn register(&mut self) -> Waker {
+        let waker = Arc::new(AtomicCell::new(None));
+        self.wakers.push(waker.clone());
+
+        waker
+    }
+
+    /// Takes the wakers of the receivers
+    /// waiting for a new message.
+    fn take_wakers(&mut self) -> Vec<task::Waker> {
+        self.wakers
+            .iter()
+            .filter_map(|waker| waker.swap(None))
+            .collect()
+    }
+}
+
+impl<T: Clone> Stream for Receiver<T> {
+    /// Either a message or an error if the
+    /// receiver missed messages.
+    type Item = Result<T, TryRecvError>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Result<T, TryRecvError>>> {
+        let recver = self.get_mut();
+
+        match recver.try_recv() {
+            Ok(Some(msg)) => return Poll::Ready(Some(Ok(msg))),
+            Err(ref err) if err.is_closed() => return Poll::Ready(None),
+            Err(err) => return Poll::Ready(Some(Err(err))),
+            Ok(None) => (),
+        }
+
+        // We register the stream's waker
+        // before trying again, in case a
+        // message has been sent in the
+        // meantime.
+        recver.waker.store(Some(ctx.waker().clone()));
+
+        match recver.try_recv() {
+            Ok(Some(msg)) => Poll::Ready(Some(Ok(msg))),
+            Err(ref err) if err.is_closed() => Poll::Ready(None),
+            Err(err) => Poll::Ready(Some(Err(err))),
+            Ok(None) => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.senders.fetch_add(1, Ordering::SeqCst);
+
+        Sender {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> Unpin for Receiver<T> {}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        // If this was the last sender, we
+        // close the channel.
+        if self.channel.senders.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.channel.close();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.channel.recvers.fetch_sub(1, Ordering::SeqCst);
+
+        let waker = &self.waker;
+        self.channel
+            .buffer
+            .lock()
+            .unwrap()
+            .wakers
+            .retain(|waker_| !Arc::ptr_eq(waker_, waker));
+    }
+}
@@ -1,11 +1,13 @@
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use crossbeam_queue::ArrayQueue;
 use crossbeam_queue::SegQueue;
 
 use crate::channel::Channel;
 use crate::counters::Counters;
+use crate::overflow::Overflow;
 use crate::queue::Queue;
 use crate::receiver::Receiver;
 use crate::sender::Sender;
@@ -17,6 +19,14 @@ pub struct Builder {
     /// channel, or `None` if it
     /// should be unbounded.
     cap: Option<usize>,
+    /// The policy used when a message
+    /// is sent while the channel is
+    /// bounded and full.
+    overflow: Overflow,
+    /// The number of priority levels
+    /// of the channel (`1` if it isn't
+    /// prioritized).
+    prios: usize,
     /// The limit of messages that
     /// can be send over the channel,
     /// or `None` if no limit should
@@ -40,6 +50,8 @@ impl Builder {
     /// Creates a new builder with
     /// the default configuration:
     /// - unbounded
+    /// - rejecting messages when full
+    /// - not prioritized
     /// - no limit of messages
     /// - no limit of senders
     /// - no limit of receivers
@@ -62,6 +74,29 @@ impl Builder {
         self
     }
 
+    /// Sets the policy used when a
+    /// message is sent while the channel
+    /// is bounded and full (if it isn't
+    /// bounded, only [`Overflow::Latest`]
+    /// has an effect).
+    ///
+    /// [`Overflow::Latest`]: enum.Overflow.html#variant.Latest
+    pub fn overflow(mut self, overflow: Overflow) -> Builder {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the number of priority
+    /// levels of the channel, messages
+    /// with a higher priority being
+    /// received first (if the channel is
+    /// bounded, each level has `cap` as
+    /// its buffer capacity).
+    pub fn priorities(mut self, levels: usize) -> Builder {
+        self.prios = levels.max(1);
+        self
+    }
+
     /// Sets the maximum number of
     /// messages that the channel will
     /// be able to pass.
@@ -118,11 +153,25 @@ impl Builder {
     /// connected to it.
     pub fn build<T>(self) -> (Sender<T>, Receiver<T>) {
         // We create either a bounded or
-        // unbounded queue.
-        let queue = if let Some(cap) = self.cap {
-            Queue::Bounded(ArrayQueue::new(cap))
+        // unbounded queue (conflating
+        // channels only hold one message)
+        // for each priority level.
+        let mut queues = (0..self.prios)
+            .map(|_| {
+                if self.overflow == Overflow::Latest {
+                    Queue::Bounded(ArrayQueue::new(1))
+                } else if let Some(cap) = self.cap {
+                    Queue::Bounded(ArrayQueue::new(cap))
+                } else {
+                    Queue::Unbounded(SegQueue::new())
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let queue = if queues.len() == 1 {
+            queues.pop().unwrap()
         } else {
-            Queue::Unbounded(SegQueue::new())
+            Queue::Prioritized(queues)
         };
 
         // We create the channel and put
@@ -130,9 +179,13 @@ impl Builder {
         // reference counted pointer.
         let channel = Arc::new(Channel {
             queue,
+            overflow: self.overflow,
             closed: AtomicBool::new(false),
             counters: Counters::new(self.msgs, self.senders, self.recvers),
             wakers: SegQueue::new(),
+            send_wakers: SegQueue::new(),
+            dead_letters: Mutex::new(None),
+            dropped_letters: Mutex::new(None),
         });
 
         // We return a sender and a
@@ -146,6 +199,8 @@ impl Default for Builder {
     fn default() -> Self {
         Builder {
             cap: None,
+            overflow: Overflow::default(),
+            prios: 1,
             msgs: None,
             senders: None,
             recvers: None,
@@ -1,7 +1,10 @@
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::task;
+use std::task::Poll;
+use std::time::Instant;
 
 use crossbeam_queue::SegQueue;
 use crossbeam_utils::atomic::AtomicCell;
@@ -9,10 +12,16 @@ use crossbeam_utils::atomic::AtomicCell;
 use crate::counters::Counters;
 use crate::error::*;
 use crate::message::Message;
+use crate::overflow::Overflow;
 use crate::queue::Queue;
+use crate::stats::ChannelStats;
 
 type Waker = Arc<AtomicCell<(bool, Option<task::Waker>)>>;
 
+/// A function that the messages which
+/// expired or were dropped are passed to.
+pub(crate) type Sink<T> = Arc<dyn Fn(T) + Send + Sync>;
+
 /// A channel allowing senders to pass
 /// messages over it, and receivers to
 /// retrieve them.
@@ -21,6 +30,9 @@ pub(crate) struct Channel<T> {
     /// messages that have not been
     /// received yet.
     pub(crate) queue: Queue<Message<T>>,
+    /// The policy used when a message
+    /// is sent while the queue is full.
+    pub(crate) overflow: Overflow,
     /// Whether the channel is closed.
     pub(crate) closed: AtomicBool,
     /// The counters used to store the
@@ -31,6 +43,17 @@ pub(crate) struct Channel<T> {
     /// A list of the wakers that can
     /// be used to wake up receivers.
     pub(crate) wakers: SegQueue<Waker>,
+    /// A list of the wakers of the
+    /// senders waiting for the queue
+    /// to have free space.
+    pub(crate) send_wakers: SegQueue<task::Waker>,
+    /// The function that the expired
+    /// messages are passed to (if any).
+    pub(crate) dead_letters: Mutex<Option<Sink<T>>>,
+    /// The function that the messages
+    /// dropped because of the overflow
+    /// policy are passed to (if any).
+    pub(crate) dropped_letters: Mutex<Option<Sink<T>>>,
 }
 
 impl<T> Channel<T> {
@@ -52,19 +75,145 @@ impl<T> Channel<T> {
             return Err(TrySendError::limit(msg.msg));
         }
 
-        // We try to push the message
-        // over the queue.
-        self.queue
-            .push(msg)
-            .map_err(|msg| TrySendError::full(msg.msg))?;
-
-        // We notify a receiver that a
-        // new message is available.
-        self.notify();
+        // We only count the message as
+        // sent (and notify a receiver) if
+        // it wasn't dropped.
+        if self.push(msg)? {
+            self.counters.add_sent(1);
+            self.notify();
+        }
 
         Ok(())
     }
 
+    /// Tries to send all the messages
+    /// yielded by `msgs` over the channel,
+    /// updating the counters by batches and
+    /// only notifying a receiver once,
+    /// returning the number of messages
+    /// sent or an error containing the
+    /// first message that couldn't be sent
+    /// (the messages yielded before it have
+    /// been sent).
+    pub(crate) fn try_send_many<I>(&self, msgs: I) -> Result<usize, TrySendError<T>>
+    where
+        I: IntoIterator<Item = Message<T>>,
+    {
+        let mut msgs = msgs.into_iter();
+
+        let mut sent = 0;
+        // The number of messages that
+        // were pushed over the queue
+        // (that is, sent but not dropped).
+        let mut pushed = 0;
+        // The number of messages that we
+        // are allowed to send without
+        // updating the counters.
+        let mut reserved = 0;
+
+        let res = loop {
+            let msg = if let Some(msg) = msgs.next() {
+                msg
+            } else {
+                break Ok(sent);
+            };
+
+            if self.is_closed() {
+                break Err(TrySendError::closed(msg.msg));
+            }
+
+            // If we aren't allowed to send
+            // more messages, we increase
+            // the number of messages inside
+            // the inner counters by as much
+            // as the iterator should yield.
+            if reserved == 0 {
+                reserved = self.counters.add_msgs(msgs.size_hint().0 + 1);
+
+                if reserved == 0 {
+                    break Err(TrySendError::limit(msg.msg));
+                }
+            }
+
+            reserved -= 1;
+            match self.push(msg) {
+                Ok(true) => pushed += 1,
+                Ok(false) => (),
+                Err(err) => break Err(err),
+            }
+
+            sent += 1;
+        };
+
+        // We release the messages that
+        // we were allowed to send but
+        // didn't...
+        self.counters.sub_msgs(reserved);
+        self.counters.add_sent(pushed);
+
+        // ...and notify a receiver that
+        // new messages are available.
+        if pushed > 0 {
+            self.notify();
+        }
+
+        res
+    }
+
+    /// Pushes a message over the queue,
+    /// handling the case where it's full
+    /// depending on the overflow policy and
+    /// returning whether it was pushed (or
+    /// dropped because of it).
+    fn push(&self, mut msg: Message<T>) -> Result<bool, TrySendError<T>> {
+        let prio = msg.prio;
+        while let Err(msg_) = self.queue.push(msg, prio) {
+            match self.overflow {
+                // We allow another message
+                // to be sent and return an
+                // error...
+                Overflow::Reject => {
+                    self.counters.sub_msg();
+                    return Err(TrySendError::full(msg_.msg));
+                }
+                // ...or drop the new message...
+                Overflow::DropNewest => {
+                    self.counters.sub_msg();
+                    self.counters.add_dropped();
+                    self.dropped(msg_);
+                    return Ok(false);
+                }
+                // ...or drop the oldest one
+                // (with the same priority),
+                // allowing another message to
+                // be sent, and try again.
+                Overflow::DropOldest | Overflow::Latest => {
+                    if let Some(oldest) = self.queue.pop_level(prio) {
+                        self.counters.sub_msg();
+                        self.counters.add_dropped();
+                        self.dropped(oldest);
+                    }
+
+                    msg = msg_;
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Passes a message dropped because of
+    /// the overflow policy to the dropped
+    /// letters sink if there is one.
+    fn dropped(&self, msg: Message<T>) {
+        // We clone the sink to release the
+        // lock before calling it.
+        let sink = self.dropped_letters.lock().unwrap().clone();
+        if let Some(sink) = sink {
+            sink(msg.msg);
+        }
+    }
+
     /// Tries to receive a message from the
     /// channel if one is available.
     pub(crate) fn try_recv(&self) -> Result<Option<Message<T>>, TryRecvError> {
@@ -81,7 +230,129 @@ impl<T> Channel<T> {
         // could return `None` if the
         // message was already poped).
         } else {
-            Ok(self.queue.pop())
+            let msg = self.pop();
+
+            // We notify the waiting
+            // senders that space has been
+            // freed in the queue.
+            if msg.is_some() {
+                self.counters.add_recved(1);
+                self.notify_senders();
+            }
+
+            Ok(msg)
+        }
+    }
+
+    /// Pops a message from the queue,
+    /// skipping the expired ones (which are
+    /// counted and passed to the dead letters
+    /// sink if there is one).
+    fn pop(&self) -> Option<Message<T>> {
+        let mut now = None;
+
+        while let Some(msg) = self.queue.pop() {
+            let now = *now.get_or_insert_with(Instant::now);
+            if !msg.is_expired(now) {
+                return Some(msg);
+            }
+
+            self.counters.add_expired();
+            self.notify_senders();
+
+            // We clone the sink to release
+            // the lock before calling it.
+            let sink = self.dead_letters.lock().unwrap().clone();
+            if let Some(sink) = sink {
+                sink(msg.msg);
+            }
+        }
+
+        None
+    }
+
+    /// Tries to receive at most `max`
+    /// messages from the channel, passing
+    /// them to `recv` and only notifying the
+    /// waiting senders once, returning the
+    /// number of messages received or an
+    /// error if the channel is closed and
+    /// empty.
+    pub(crate) fn try_recv_many<F>(&self, max: usize, mut recv: F) -> Result<usize, TryRecvError>
+    where
+        F: FnMut(Message<T>),
+    {
+        let mut recved = 0;
+        while recved < max {
+            if let Some(msg) = self.pop() {
+                recv(msg);
+                recved += 1;
+            } else {
+                break;
+            }
+        }
+
+        // If no message was available, we
+        // return an error if the channel
+        // is closed...
+        if recved == 0 {
+            if max > 0 && self.check_is_closed() && self.queue.is_empty() {
+                return Err(TryRecvError::closed());
+            }
+        // ...or notify the waiting senders
+        // that space has been freed in the
+        // queue.
+        } else {
+            self.counters.add_recved(recved);
+            self.notify_senders();
+        }
+
+        Ok(recved)
+    }
+
+    /// Tries to send a message over the
+    /// channel with the given priority,
+    /// storing it back in `msg` and
+    /// registering the context's waker if
+    /// the channel is full.
+    pub(crate) fn poll_send(
+        &self,
+        ctx: &mut task::Context,
+        msg: &mut Option<T>,
+        prio: usize,
+    ) -> Poll<Result<(), TrySendError<T>>> {
+        let msg_ = if let Some(msg) = msg.take() {
+            msg
+        } else {
+            return Poll::Ready(Ok(()));
+        };
+
+        let msg_ = match self.try_send(Message::new(msg_).with_prio(prio)) {
+            Err(err) => {
+                if err.is_full() {
+                    err.into_msg()
+                } else {
+                    return Poll::Ready(Err(err));
+                }
+            }
+            Ok(()) => return Poll::Ready(Ok(())),
+        };
+
+        // We register the waker before trying
+        // again, in case a message has been
+        // received in the meantime.
+        self.send_wakers.push(ctx.waker().clone());
+
+        match self.try_send(Message::new(msg_).with_prio(prio)) {
+            Err(err) => {
+                if err.is_full() {
+                    *msg = Some(err.into_msg());
+                    Poll::Pending
+                } else {
+                    Poll::Ready(Err(err))
+                }
+            }
+            Ok(()) => Poll::Ready(Ok(())),
         }
     }
 
@@ -90,24 +361,83 @@ impl<T> Channel<T> {
         self.wakers.push(waker);
     }
 
+    /// Wakes up all the receivers waiting
+    /// for a message, which is needed when
+    /// the channel is closed or when its
+    /// last sender disconnects.
+    pub(crate) fn notify_recvers(&self) {
+        let mut wakers = vec![];
+        while let Ok(waker) = self.wakers.pop() {
+            wakers.push(waker);
+        }
+
+        for waker in wakers {
+            // We only keep the wakers of the
+            // receivers that are still
+            // connected.
+            match waker.swap((true, None)) {
+                (true, waker_) => {
+                    self.wakers.push(waker);
+
+                    if let Some(waker_) = waker_ {
+                        waker_.wake();
+                    }
+                }
+                (false, _) => waker.store((false, None)),
+            }
+        }
+    }
+
+    /// Wakes up all the senders waiting
+    /// for the queue to have free space.
+    fn notify_senders(&self) {
+        while let Ok(waker) = self.send_wakers.pop() {
+            waker.wake();
+        }
+    }
+
     /// Notifies a waker if one is
     /// available.
     fn notify(&self) {
-        if let Ok(waker) = self.wakers.pop() {
+        // We only try each waker once, as none
+        // of the receivers might be waiting for
+        // a message (they will then get it the
+        // next time they are polled).
+        for _ in 0..self.wakers.len() {
+            let waker = match self.wakers.pop() {
+                Ok(waker) => waker,
+                Err(_) => return,
+            };
+
             match waker.swap((true, None)) {
                 (true, Some(waker_)) => {
                     self.wakers.push(waker);
                     waker_.wake();
+                    return;
                 }
-                (true, None) => {
-                    self.wakers.push(waker);
-                    self.notify();
-                }
-                _ => self.notify(),
+                (true, None) => self.wakers.push(waker),
+                (false, _) => (),
             }
         }
     }
 
+    /// Returns a snapshot of the channel's
+    /// state (closing the channel if no
+    /// sender is connected to it anymore).
+    pub(crate) fn stats(&self) -> ChannelStats {
+        ChannelStats {
+            len: self.queue.len(),
+            capacity: self.queue.capacity(),
+            sent: self.counters.sent(),
+            received: self.counters.recved(),
+            dropped: self.counters.dropped(),
+            expired: self.counters.expired(),
+            senders: self.counters.senders(),
+            receivers: self.counters.recvers(),
+            closed: self.check_is_closed(),
+        }
+    }
+
     /// Whether the queue is empty.
     pub(crate) fn is_empty(&self) -> bool {
         self.queue.is_empty()
@@ -139,5 +469,7 @@ impl<T> Channel<T> {
     /// Closes the channel.
     pub(crate) fn close(&self) {
         self.closed.store(true, Ordering::SeqCst);
+        self.notify_senders();
+        self.notify_recvers();
     }
 }
@@ -14,6 +14,20 @@ pub(crate) struct Counters {
     /// The number of receivers
     /// connected to the channel.
     crecvers: AtomicUsize,
+    /// The number of messages that
+    /// were dropped because the channel
+    /// was full.
+    cdropped: AtomicUsize,
+    /// The total number of messages
+    /// sent over the channel (even if
+    /// there isn't any limit).
+    csent: AtomicUsize,
+    /// The total number of messages
+    /// received from the channel.
+    crecved: AtomicUsize,
+    /// The number of messages that
+    /// expired before being received.
+    cexpired: AtomicUsize,
 
     /// The number of messages that
     /// can be sent over the channel
@@ -37,6 +51,10 @@ impl Counters {
             cmsgs: msgs.map(|_| AtomicUsize::new(0)),
             csenders: AtomicUsize::new(0),
             crecvers: AtomicUsize::new(0),
+            cdropped: AtomicUsize::new(0),
+            csent: AtomicUsize::new(0),
+            crecved: AtomicUsize::new(0),
+            cexpired: AtomicUsize::new(0),
 
             lmsgs: msgs,
             lsenders: senders,
@@ -50,6 +68,64 @@ impl Counters {
         self.csenders.load(Ordering::SeqCst)
     }
 
+    /// Gets the current number of
+    /// receivers connected to the channel.
+    pub(crate) fn recvers(&self) -> usize {
+        self.crecvers.load(Ordering::SeqCst)
+    }
+
+    /// Gets the total number of messages
+    /// sent over the channel.
+    pub(crate) fn sent(&self) -> usize {
+        self.csent.load(Ordering::SeqCst)
+    }
+
+    /// Gets the total number of messages
+    /// received from the channel.
+    pub(crate) fn recved(&self) -> usize {
+        self.crecved.load(Ordering::SeqCst)
+    }
+
+    /// Gets the number of messages that
+    /// expired before being received.
+    pub(crate) fn expired(&self) -> usize {
+        self.cexpired.load(Ordering::SeqCst)
+    }
+
+    /// Increases the number of messages
+    /// that expired before being received.
+    pub(crate) fn add_expired(&self) {
+        self.cexpired.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Increases the total number of
+    /// messages sent over the channel
+    /// by `n`.
+    pub(crate) fn add_sent(&self, n: usize) {
+        self.csent.fetch_add(n, Ordering::SeqCst);
+    }
+
+    /// Increases the total number of
+    /// messages received from the channel
+    /// by `n`.
+    pub(crate) fn add_recved(&self, n: usize) {
+        self.crecved.fetch_add(n, Ordering::SeqCst);
+    }
+
+    /// Gets the number of messages that
+    /// were dropped because the channel
+    /// was full.
+    pub(crate) fn dropped(&self) -> usize {
+        self.cdropped.load(Ordering::SeqCst)
+    }
+
+    /// Increases the number of messages
+    /// that were dropped because the
+    /// channel was full.
+    pub(crate) fn add_dropped(&self) {
+        self.cdropped.fetch_add(1, Ordering::SeqCst);
+    }
+
     /// Increases the total number of
     /// messages sent over the channel if
     /// necessary.
@@ -78,6 +154,56 @@ impl Counters {
         Ok(())
     }
 
+    /// Increases the total number of
+    /// messages sent over the channel by
+    /// at most `n` if necessary, returning
+    /// by how much it was increased (which
+    /// is less than `n` if the limit has
+    /// been reached).
+    pub(crate) fn add_msgs(&self, n: usize) -> usize {
+        if let Some(counter) = &self.cmsgs {
+            let limit = self.lmsgs.unwrap();
+
+            // We CAS the sent messages
+            // counter to increase it of
+            // as much as allowed by the
+            // limit.
+            loop {
+                let cur = counter.load(Ordering::SeqCst);
+                let new = (cur + n).min(limit.max(cur));
+
+                if new == cur {
+                    return 0;
+                }
+
+                if counter.compare_and_swap(cur, new, Ordering::SeqCst) == cur {
+                    return new - cur;
+                }
+            }
+        }
+
+        n
+    }
+
+    /// Decreases the total number of
+    /// messages sent over the channel by
+    /// `n` if necessary.
+    pub(crate) fn sub_msgs(&self, n: usize) {
+        if let Some(counter) = &self.cmsgs {
+            counter.fetch_sub(n, Ordering::SeqCst);
+        }
+    }
+
+    /// Decreases the total number of
+    /// messages sent over the channel if
+    /// necessary (e.g. because a message
+    /// couldn't be pushed to the queue).
+    pub(crate) fn sub_msg(&self) {
+        if let Some(counter) = &self.cmsgs {
+            counter.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
     /// Increases the counter for the
     /// number of senders connected to the
     /// channel.
@@ -107,6 +233,28 @@ impl Counters {
         Ok(())
     }
 
+    /// Increases the counter for the
+    /// number of senders connected to the
+    /// channel if at least one sender is
+    /// still connected to it.
+    pub(crate) fn revive_sender(&self) -> Result<(), ()> {
+        // We CAS the counter to increase it
+        // of 1 and return an error if it is
+        // 0 or above the limit.
+        loop {
+            let cur = self.csenders.load(Ordering::SeqCst);
+            let new = cur + 1;
+
+            if cur == 0 || self.lsenders.map(|limit| new > limit).unwrap_or(false) {
+                return Err(());
+            }
+
+            if self.csenders.compare_and_swap(cur, new, Ordering::SeqCst) == cur {
+                return Ok(());
+            }
+        }
+    }
+
     /// Increases the counter for the
     /// number of receivers connected to
     /// the channel.
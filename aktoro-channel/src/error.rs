@@ -30,6 +30,12 @@ pub struct TryRecvError {
     kind: RecvErrorKind,
 }
 
+#[derive(Eq, PartialEq, Clone, Debug)]
+/// An error returned by a oneshot
+/// channel's receiver when the sender
+/// was dropped without sending a value.
+pub struct Canceled;
+
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub enum CloneErrorKind {
     /// The maximum number of sender or
@@ -63,6 +69,10 @@ pub enum RecvErrorKind {
     Disconnected,
     /// The channel is closed.
     Closed,
+    /// The receiver of a broadcast channel
+    /// was too slow and missed the given
+    /// number of messages.
+    Lagged(u64),
 }
 
 impl CloneError {
@@ -193,6 +203,14 @@ impl TryRecvError {
         }
     }
 
+    /// Creates a new "receiver lagged"
+    /// error.
+    pub(crate) fn lagged(missed: u64) -> Self {
+        TryRecvError {
+            kind: RecvErrorKind::Lagged(missed),
+        }
+    }
+
     /// Whether the error occured because
     /// the receiver is disconnected from
     /// the channel.
@@ -205,6 +223,18 @@ impl TryRecvError {
     pub fn is_closed(&self) -> bool {
         self.kind == RecvErrorKind::Closed
     }
+
+    /// Returns the number of messages that
+    /// the receiver of a broadcast channel
+    /// missed because it was too slow, if
+    /// this is why the error occured.
+    pub fn lagged_by(&self) -> Option<u64> {
+        if let RecvErrorKind::Lagged(missed) = self.kind {
+            Some(missed)
+        } else {
+            None
+        }
+    }
 }
 
 impl error::Error for CloneError {}
@@ -213,6 +243,8 @@ impl<T> error::Error for TrySendError<T> {}
 
 impl error::Error for TryRecvError {}
 
+impl error::Error for Canceled {}
+
 impl Display for CloneError {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         match self.kind {
@@ -242,10 +274,21 @@ impl Display for TryRecvError {
                 write!(fmt, "receive failed because already disconnected",)
             }
             RecvErrorKind::Closed => write!(fmt, "receive failed because channel closed",),
+            RecvErrorKind::Lagged(missed) => write!(
+                fmt,
+                "receive failed because receiver lagged ({} messages missed)",
+                missed,
+            ),
         }
     }
 }
 
+impl Display for Canceled {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "receive failed because sender dropped",)
+    }
+}
+
 impl<T> Debug for TrySendError<T> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         fmt.debug_struct("TrySendError")
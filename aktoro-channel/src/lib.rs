@@ -1,15 +1,29 @@
+pub mod broadcast;
 pub mod error;
+pub mod oneshot;
+pub mod watch;
 
 mod builder;
 mod channel;
 mod counters;
 mod message;
 mod notify;
+mod overflow;
+mod park;
 mod queue;
 mod receiver;
+mod select;
 mod sender;
+mod stats;
 
 pub use builder::Builder;
 pub use notify::Notify;
+pub use overflow::Overflow;
 pub use receiver::Receiver;
+pub use select::Select;
+pub use select::SelectMode;
+pub use select::Selected;
 pub use sender::Sender;
+pub use sender::Sending;
+pub use sender::WeakSender;
+pub use stats::ChannelStats;
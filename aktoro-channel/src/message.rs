@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use crate::notify::Notify;
 
 /// A wrapper around a message that
@@ -5,6 +7,13 @@ use crate::notify::Notify;
 /// notifier.
 pub(crate) struct Message<T> {
     pub(crate) msg: T,
+    /// The message's priority (only used
+    /// if the channel is prioritized).
+    pub(crate) prio: usize,
+    /// The instant after which the message
+    /// expires and won't be received (if
+    /// any).
+    deadline: Option<Instant>,
     notify: Option<Notify>,
 }
 
@@ -13,7 +22,34 @@ impl<T> Message<T> {
     /// contaning `msg` but no
     /// notifier.
     pub(crate) fn new(msg: T) -> Self {
-        Message { msg, notify: None }
+        Message {
+            msg,
+            prio: 0,
+            deadline: None,
+            notify: None,
+        }
+    }
+
+    /// Sets the message's priority.
+    pub(crate) fn with_prio(mut self, prio: usize) -> Self {
+        self.prio = prio;
+        self
+    }
+
+    /// Sets the instant after which the
+    /// message expires.
+    pub(crate) fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Whether the message has expired.
+    pub(crate) fn is_expired(&self, now: Instant) -> bool {
+        if let Some(deadline) = self.deadline {
+            deadline <= now
+        } else {
+            false
+        }
     }
 
     /// Creates a new `Message`
@@ -26,6 +62,8 @@ impl<T> Message<T> {
         (
             Message {
                 msg,
+                prio: 0,
+                deadline: None,
                 notify: Some(notify.0),
             },
             notify.1,
@@ -1,13 +1,9 @@
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::atomic::AtomicBool;
-use std::sync::atomic::Ordering;
-use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
-use std::task::Waker;
 
-use crossbeam_utils::atomic::AtomicCell;
+use crate::oneshot;
 
 /// A sender or receiver, with the sender
 /// being able to notify the receiver
@@ -15,41 +11,36 @@ use crossbeam_utils::atomic::AtomicCell;
 /// the receiver being able to register
 /// a `Waker` to be waked up after the
 /// completion.
-pub struct Notify(Arc<Inner>);
+///
+/// If the sender is dropped without
+/// notifying the receiver, the receiver
+/// never completes.
+pub struct Notify(Inner);
 
-/// The inner "channel" of `Notify`.
-struct Inner {
-    /// Whether the sender has already
-    /// completed the action and notified
-    /// the receiver.
-    done: AtomicBool,
-    /// A pointer to an optional waker
-    /// that will be notified when the
-    /// action is completed.
-    waker: AtomicCell<Option<Waker>>,
+/// Either the sender or the receiver of
+/// the inner oneshot channel.
+enum Inner {
+    Sender(oneshot::Sender<()>),
+    Receiver(Option<oneshot::Receiver<()>>),
 }
 
 impl Notify {
     /// Creates a new channel, returning
     /// a sender and a receiver.
     pub fn new() -> (Self, Self) {
-        let inner = Arc::new(Inner {
-            done: AtomicBool::new(false),
-            waker: AtomicCell::new(None),
-        });
+        let (sender, recver) = oneshot::new();
 
-        (Notify(inner.clone()), Notify(inner))
+        (
+            Notify(Inner::Sender(sender)),
+            Notify(Inner::Receiver(Some(recver))),
+        )
     }
 
     /// Stores the action as bein completed,
     /// eventually waking up the receiver.
     pub fn done(self) {
-        // We store the action as being completed.
-        self.0.done.store(true, Ordering::SeqCst);
-
-        // We eventually wake the receiver up.
-        if let Some(waker) = self.0.waker.swap(None) {
-            waker.wake();
+        if let Inner::Sender(sender) = self.0 {
+            sender.send(()).ok();
         }
     }
 }
@@ -58,15 +49,31 @@ impl Future for Notify {
     type Output = ();
 
     fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<()> {
-        // If the action has been completed, we
-        // complete the future too.
-        if self.0.done.load(Ordering::SeqCst) {
-            Poll::Ready(())
-        // Otherwise, we register the future's
-        // waker.
+        let recver = match &mut self.get_mut().0 {
+            Inner::Receiver(recver) => recver,
+            Inner::Sender(_) => return Poll::Pending,
+        };
+
+        let res = if let Some(inner) = recver {
+            match Pin::new(inner).poll(ctx) {
+                Poll::Ready(res) => res,
+                Poll::Pending => return Poll::Pending,
+            }
         } else {
-            self.0.waker.store(Some(ctx.waker().clone()));
-            Poll::Pending
+            return Poll::Ready(());
+        };
+
+        match res {
+            // If the action has been completed,
+            // we complete the future too.
+            Ok(()) => {
+                recver.take();
+                Poll::Ready(())
+            }
+            // Otherwise, the sender has been
+            // dropped and the action will never
+            // be completed.
+            Err(_) => Poll::Pending,
         }
     }
 }
@@ -0,0 +1,200 @@
+//! A oneshot channel, allowing to send
+//! a single value from a sender to a
+//! receiver.
+//!
+//! The receiver is a future resolving
+//! with either the sent value or with
+//! [`Canceled`] if the sender was dropped
+//! without sending anything.
+//!
+//! [`Canceled`]: ../error/struct.Canceled.html
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use crossbeam_utils::atomic::AtomicCell;
+
+use crate::error::Canceled;
+
+/// A oneshot channel's sender, allowing
+/// to send a single value to the receiver.
+///
+/// If the sender is dropped without having
+/// sent a value, the receiver resolves with
+/// [`Canceled`].
+///
+/// [`Canceled`]: ../error/struct.Canceled.html
+pub struct Sender<T> {
+    inner: Arc<Inner<T>>,
+}
+
+/// A oneshot channel's receiver, which is
+/// a future resolving with the value sent
+/// by the sender.
+pub struct Receiver<T> {
+    inner: Arc<Inner<T>>,
+}
+
+/// The inner "channel" shared by the sender
+/// and the receiver.
+struct Inner<T> {
+    /// The value that the sender has sent
+    /// or `None` if no value has been sent
+    /// yet, or if the receiver has already
+    /// read it.
+    value: AtomicCell<Option<T>>,
+    /// Whether the sender has either sent
+    /// a value or been dropped.
+    complete: AtomicBool,
+    /// Whether the receiver has been closed
+    /// or dropped.
+    closed: AtomicBool,
+    /// A pointer to an optional waker that
+    /// will be notified when the channel
+    /// completes.
+    waker: AtomicCell<Option<Waker>>,
+}
+
+/// Creates a new oneshot channel, returning
+/// a sender and a receiver connected to it.
+pub fn new<T>() -> (Sender<T>, Receiver<T>) {
+    let inner = Arc::new(Inner {
+        value: AtomicCell::new(None),
+        complete: AtomicBool::new(false),
+        closed: AtomicBool::new(false),
+        waker: AtomicCell::new(None),
+    });
+
+    (
+        Sender {
+            inner: inner.clone(),
+        },
+        Receiver { inner },
+    )
+}
+
+impl<T> Sender<T> {
+    /// Sends a value to the receiver,
+    /// returning it back if the receiver
+    /// has been closed or dropped.
+    pub fn send(self, value: T) -> Result<(), T> {
+        if self.inner.closed.load(Ordering::SeqCst) {
+            return Err(value);
+        }
+
+        self.inner.value.store(Some(value));
+        self.inner.complete();
+
+        Ok(())
+    }
+
+    /// Whether the receiver has been closed
+    /// or dropped, in which case sending a
+    /// value would fail.
+    pub fn is_canceled(&self) -> bool {
+        self.inner.closed.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Tries to receive the value sent by
+    /// the sender, returning `None` if it
+    /// hasn't sent one yet or [`Canceled`]
+    /// if it was dropped without sending
+    /// one (or if the value was already
+    /// received).
+    ///
+    /// [`Canceled`]: ../error/struct.Canceled.html
+    pub fn try_recv(&mut self) -> Result<Option<T>, Canceled> {
+        if let Some(value) = self.inner.value.swap(None) {
+            return Ok(Some(value));
+        }
+
+        if self.inner.complete.load(Ordering::SeqCst) {
+            // The value could have been
+            // sent in the meantime.
+            if let Some(value) = self.inner.value.swap(None) {
+                Ok(Some(value))
+            } else {
+                Err(Canceled)
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Closes the receiver, making the
+    /// sender unable to send a value (a
+    /// value sent before can still be
+    /// received).
+    pub fn close(&mut self) {
+        self.inner.closed.store(true, Ordering::SeqCst);
+    }
+}
+
+impl<T> Inner<T> {
+    /// Marks the channel as completed and
+    /// eventually wakes up the receiver.
+    fn complete(&self) {
+        if self.complete.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        if let Some(waker) = self.waker.swap(None) {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Future for Receiver<T> {
+    type Output = Result<T, Canceled>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Result<T, Canceled>> {
+        let recver = self.get_mut();
+
+        // If the channel is completed, we
+        // complete the future.
+        match recver.try_recv() {
+            Ok(Some(value)) => return Poll::Ready(Ok(value)),
+            Err(err) => return Poll::Ready(Err(err)),
+            Ok(None) => (),
+        }
+
+        // Otherwise, we store the future's
+        // waker...
+        recver.inner.waker.store(Some(ctx.waker().clone()));
+
+        // ...and check again in case the
+        // channel was completed in the
+        // meantime.
+        match recver.try_recv() {
+            Ok(Some(value)) => Poll::Ready(Ok(value)),
+            Err(err) => Poll::Ready(Err(err)),
+            Ok(None) => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Unpin for Receiver<T> {}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        // If the sender is dropped without
+        // having sent a value, we notify the
+        // receiver (this does nothing if a
+        // value was already sent).
+        self.inner.complete();
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.inner.closed.store(true, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,28 @@
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+/// The policy used by a bounded channel
+/// when a message is sent while its
+/// buffer is full.
+pub enum Overflow {
+    /// The new message is rejected and
+    /// an error is returned to the
+    /// sender.
+    Reject,
+    /// The oldest message in the buffer
+    /// is dropped to make room for the
+    /// new one (ring buffer semantics).
+    DropOldest,
+    /// The new message is silently
+    /// dropped.
+    DropNewest,
+    /// The buffer only holds the latest
+    /// message sent, replacing it every
+    /// time a new one is (the channel's
+    /// capacity is ignored).
+    Latest,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow::Reject
+    }
+}
@@ -0,0 +1,51 @@
+use std::mem;
+use std::sync::Arc;
+use std::task::RawWaker;
+use std::task::RawWakerVTable;
+use std::task::Waker;
+use std::thread;
+use std::thread::Thread;
+
+/// The vtable of the wakers created by
+/// [`waker`].
+///
+/// [`waker`]: fn.waker.html
+static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop);
+
+/// Creates a waker unparking the current
+/// thread when waked up, allowing to use
+/// the channels' waker registries to block
+/// a thread.
+pub(crate) fn waker() -> Waker {
+    let thread = Arc::new(thread::current());
+
+    unsafe { Waker::from_raw(raw(thread)) }
+}
+
+fn raw(thread: Arc<Thread>) -> RawWaker {
+    RawWaker::new(Arc::into_raw(thread) as *const (), &VTABLE)
+}
+
+unsafe fn clone(ptr: *const ()) -> RawWaker {
+    let thread = Arc::from_raw(ptr as *const Thread);
+    let cloned = thread.clone();
+
+    // We don't want to decrease the
+    // reference count of the original
+    // waker.
+    mem::forget(thread);
+
+    raw(cloned)
+}
+
+unsafe fn wake(ptr: *const ()) {
+    Arc::from_raw(ptr as *const Thread).unpark();
+}
+
+unsafe fn wake_by_ref(ptr: *const ()) {
+    (&*(ptr as *const Thread)).unpark();
+}
+
+unsafe fn drop(ptr: *const ()) {
+    mem::drop(Arc::from_raw(ptr as *const Thread));
+}
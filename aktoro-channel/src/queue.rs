@@ -8,23 +8,34 @@ pub(crate) enum Queue<T> {
     Bounded(ArrayQueue<T>),
     /// The unbounded queue variant.
     Unbounded(SegQueue<T>),
+    /// The prioritized queue variant,
+    /// containing a queue for each
+    /// priority level (from the lowest
+    /// priority to the highest one).
+    Prioritized(Vec<Queue<T>>),
 }
 
 impl<T> Queue<T> {
     /// Pushes a message over the queue if
-    /// the inner buffer isn't full.
-    pub(crate) fn push(&self, msg: T) -> Result<(), T> {
+    /// the inner buffer isn't full, with
+    /// the given priority (if the queue is
+    /// prioritized, the priority is capped
+    /// to the highest level).
+    pub(crate) fn push(&self, msg: T, prio: usize) -> Result<(), T> {
         match self {
             Queue::Bounded(queue) => queue.push(msg).map_err(|err| err.0),
             Queue::Unbounded(queue) => {
                 queue.push(msg);
                 Ok(())
             }
+            Queue::Prioritized(queues) => queues[prio.min(queues.len() - 1)].push(msg, 0),
         }
     }
 
     /// Pops a message from the queue if
-    /// one is available.
+    /// one is available, starting with
+    /// the highest priority level if the
+    /// queue is prioritized.
     pub(crate) fn pop(&self) -> Option<T> {
         match self {
             Queue::Bounded(queue) => {
@@ -41,6 +52,38 @@ impl<T> Queue<T> {
                     None
                 }
             }
+            Queue::Prioritized(queues) => queues.iter().rev().find_map(|queue| queue.pop()),
+        }
+    }
+
+    /// Pops a message from the queue if
+    /// one is available, only looking at
+    /// the given priority level if the
+    /// queue is prioritized.
+    pub(crate) fn pop_level(&self, prio: usize) -> Option<T> {
+        match self {
+            Queue::Prioritized(queues) => queues[prio.min(queues.len() - 1)].pop(),
+            queue => queue.pop(),
+        }
+    }
+
+    /// Returns the number of messages
+    /// inside the queue.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Queue::Bounded(queue) => queue.len(),
+            Queue::Unbounded(queue) => queue.len(),
+            Queue::Prioritized(queues) => queues.iter().map(|queue| queue.len()).sum(),
+        }
+    }
+
+    /// Returns the queue's capacity, or
+    /// `None` if it is unbounded.
+    pub(crate) fn capacity(&self) -> Option<usize> {
+        match self {
+            Queue::Bounded(queue) => Some(queue.capacity()),
+            Queue::Unbounded(_) => None,
+            Queue::Prioritized(queues) => queues.iter().map(|queue| queue.capacity()).sum(),
         }
     }
 
@@ -50,6 +93,7 @@ impl<T> Queue<T> {
         match self {
             Queue::Bounded(queue) => queue.is_empty(),
             Queue::Unbounded(queue) => queue.is_empty(),
+            Queue::Prioritized(queues) => queues.iter().all(|queue| queue.is_empty()),
         }
     }
 }
@@ -1,8 +1,12 @@
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::task::Context;
 use std::task::Poll;
 use std::task::Waker;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures_core::FusedStream;
@@ -10,6 +14,9 @@ use futures_core::Stream;
 
 use crate::channel::Channel;
 use crate::error::*;
+use crate::park;
+use crate::sender::Sender;
+use crate::stats::ChannelStats;
 
 /// A channel's receiver allowing to
 /// get messages from it either via the
@@ -60,6 +67,181 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Tries to receive at most `max`
+    /// messages from the channel, pushing
+    /// them to `buf` and returning how many
+    /// were received, or an error if the
+    /// channel is closed and empty.
+    ///
+    /// The waiting senders are only notified
+    /// once, which makes this method faster
+    /// than calling [`try_recv`] for each
+    /// message.
+    ///
+    /// [`try_recv`]: #method.try_recv
+    pub fn try_recv_many(&self, buf: &mut Vec<T>, max: usize) -> Result<usize, TryRecvError> {
+        if let Some(channel) = &self.channel {
+            channel.try_recv_many(max, |msg| buf.push(msg.unwrap()))
+        } else {
+            Err(TryRecvError::disconnected())
+        }
+    }
+
+    /// Receives a message from the channel,
+    /// blocking the current thread until one
+    /// is available, or returning an error if
+    /// the channel is closed and empty.
+    ///
+    /// This method allows to receive messages
+    /// from threads that aren't running an
+    /// executor and shouldn't be called from
+    /// asynchronous code.
+    pub fn recv_blocking(&self) -> Result<T, TryRecvError> {
+        loop {
+            if let Some(msg) = self.recv_parked(None)? {
+                return Ok(msg);
+            }
+        }
+    }
+
+    /// Receives a message from the channel
+    /// like [`recv_blocking`], but returning
+    /// `None` if no message is available
+    /// before `timeout` elapses.
+    ///
+    /// [`recv_blocking`]: #method.recv_blocking
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Option<T>, TryRecvError> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return self.try_recv();
+            }
+
+            if let Some(msg) = self.recv_parked(Some(deadline - now))? {
+                return Ok(Some(msg));
+            }
+        }
+    }
+
+    /// Tries to receive a message, parking
+    /// the current thread (for at most
+    /// `timeout` if provided) if none is
+    /// available.
+    fn recv_parked(&self, timeout: Option<Duration>) -> Result<Option<T>, TryRecvError> {
+        // We register a waker unparking the
+        // current thread before trying to
+        // receive a message, in case one is
+        // sent in the meantime.
+        self.waker.store((true, Some(park::waker())));
+
+        match self.try_recv() {
+            Ok(None) => (),
+            res => {
+                self.waker.store((true, None));
+                return res;
+            }
+        }
+
+        if let Some(timeout) = timeout {
+            thread::park_timeout(timeout);
+        } else {
+            thread::park();
+        }
+
+        self.waker.store((true, None));
+        Ok(None)
+    }
+
+    /// Returns a snapshot of the state of
+    /// the channel the receiver is connected
+    /// to (or of a closed and empty
+    /// channel if it is disconnected).
+    pub fn stats(&self) -> ChannelStats {
+        if let Some(channel) = &self.channel {
+            channel.stats()
+        } else {
+            ChannelStats {
+                closed: true,
+                ..ChannelStats::default()
+            }
+        }
+    }
+
+    /// Sets the sender that the messages
+    /// expiring before being received will be
+    /// sent to (instead of being dropped),
+    /// replacing the previous one.
+    pub fn dead_letters(&self, sink: Sender<T>)
+    where
+        T: Send + 'static,
+    {
+        let sink = Mutex::new(sink);
+        self.on_expired(move |msg| {
+            sink.lock().unwrap().try_send(msg).ok();
+        });
+    }
+
+    /// Sets the function that the messages
+    /// expiring before being received will be
+    /// passed to (instead of being dropped),
+    /// replacing the previous one.
+    ///
+    /// It is called by the receiver that
+    /// found the expired message.
+    pub fn on_expired<F>(&self, sink: F)
+    where
+        F: Fn(T) + Send + Sync + 'static,
+    {
+        if let Some(channel) = &self.channel {
+            *channel.dead_letters.lock().unwrap() = Some(Arc::new(sink));
+        }
+    }
+
+    /// Sets the sender that the messages
+    /// dropped because the channel was full
+    /// (depending on its overflow policy) will
+    /// be sent to, replacing the previous one.
+    pub fn dropped_letters(&self, sink: Sender<T>)
+    where
+        T: Send + 'static,
+    {
+        let sink = Mutex::new(sink);
+        self.on_dropped(move |msg| {
+            sink.lock().unwrap().try_send(msg).ok();
+        });
+    }
+
+    /// Sets the function that the messages
+    /// dropped because the channel was full
+    /// (depending on its overflow policy) will
+    /// be passed to, replacing the previous one.
+    ///
+    /// It is called by the sender whose
+    /// message caused another one (or itself)
+    /// to be dropped.
+    pub fn on_dropped<F>(&self, sink: F)
+    where
+        F: Fn(T) + Send + Sync + 'static,
+    {
+        if let Some(channel) = &self.channel {
+            *channel.dropped_letters.lock().unwrap() = Some(Arc::new(sink));
+        }
+    }
+
+    /// Returns the number of messages that
+    /// were dropped because the channel
+    /// was full (depending on its overflow
+    /// policy).
+    pub fn dropped(&self) -> usize {
+        if let Some(channel) = &self.channel {
+            channel.counters.dropped()
+        } else {
+            0
+        }
+    }
+
     /// Whether the channel the receiver
     /// is connected to is closed.
     pub fn is_closed(&self) -> bool {
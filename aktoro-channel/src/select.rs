@@ -0,0 +1,219 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::FusedStream;
+use futures_core::Stream;
+
+use crate::receiver::Receiver;
+
+type Inner<T> = Pin<Box<dyn Stream<Item = T> + Send>>;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+/// The order in which [`Select`] polls its
+/// receivers.
+///
+/// [`Select`]: struct.Select.html
+pub enum SelectMode {
+    /// The receivers are always polled in
+    /// the order they were added in, which
+    /// means that the first ones can starve
+    /// the others.
+    Biased,
+    /// The receivers are polled starting
+    /// from the one after the receiver that
+    /// last yielded a message, so that all
+    /// of them get a chance to be polled.
+    RoundRobin,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+/// An item yielded by [`Select`].
+///
+/// [`Select`]: struct.Select.html
+pub enum Selected<T> {
+    /// A message received by the receiver
+    /// at the given index.
+    Msg(usize, T),
+    /// The receiver at the given index has
+    /// been closed (it won't be polled
+    /// anymore).
+    Closed(usize),
+}
+
+/// A stream waiting on several receivers
+/// at once, yielding the messages received
+/// by any of them along with the index of
+/// the receiver, and notifying when one of
+/// them is closed.
+///
+/// Receivers of different message types
+/// can be added using [`push_map`] to map
+/// their messages to a common type (e.g.
+/// an enum).
+///
+/// The stream ends when all the receivers
+/// have been closed.
+///
+/// [`push_map`]: #method.push_map
+pub struct Select<T> {
+    /// The receivers (or `None` for the
+    /// ones that have been closed).
+    recvers: Vec<Option<Inner<T>>>,
+    /// The order in which the receivers
+    /// are polled.
+    mode: SelectMode,
+    /// The index of the receiver that will
+    /// be polled first.
+    next: usize,
+}
+
+/// A receiver whose messages are mapped
+/// using a closure.
+struct Mapped<U, F> {
+    recver: Receiver<U>,
+    map: F,
+}
+
+impl<T> Select<T> {
+    /// Creates a new `Select` polling its
+    /// receivers in a round-robin fashion.
+    pub fn new() -> Self {
+        Select::with_mode(SelectMode::RoundRobin)
+    }
+
+    /// Creates a new `Select` always polling
+    /// its receivers in the order they were
+    /// added in.
+    pub fn biased() -> Self {
+        Select::with_mode(SelectMode::Biased)
+    }
+
+    /// Creates a new `Select` polling its
+    /// receivers using the given mode.
+    pub fn with_mode(mode: SelectMode) -> Self {
+        Select {
+            recvers: vec![],
+            mode,
+            next: 0,
+        }
+    }
+
+    /// Adds a receiver to the `Select`,
+    /// returning its index.
+    pub fn push(&mut self, recver: Receiver<T>) -> usize
+    where
+        T: Send + 'static,
+    {
+        self.recvers.push(Some(Box::pin(recver)));
+        self.recvers.len() - 1
+    }
+
+    /// Adds a receiver whose messages will
+    /// be mapped using `map` to the `Select`,
+    /// returning its index.
+    pub fn push_map<U, F>(&mut self, recver: Receiver<U>, map: F) -> usize
+    where
+        U: Send + 'static,
+        F: Fn(U) -> T + Unpin + Send + 'static,
+    {
+        self.recvers.push(Some(Box::pin(Mapped { recver, map })));
+        self.recvers.len() - 1
+    }
+
+    /// Returns the mode used to poll the
+    /// receivers.
+    pub fn mode(&self) -> SelectMode {
+        self.mode
+    }
+
+    /// Returns the number of receivers that
+    /// haven't been closed yet.
+    pub fn remaining(&self) -> usize {
+        self.recvers
+            .iter()
+            .filter(|recver| recver.is_some())
+            .count()
+    }
+}
+
+impl<T> Stream for Select<T> {
+    type Item = Selected<T>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<Selected<T>>> {
+        let select = self.get_mut();
+        let len = select.recvers.len();
+
+        let start = match select.mode {
+            SelectMode::Biased => 0,
+            SelectMode::RoundRobin => select.next,
+        };
+
+        let mut done = true;
+        for i in 0..len {
+            let idx = (start + i) % len;
+
+            let recver = if let Some(recver) = &mut select.recvers[idx] {
+                recver
+            } else {
+                continue;
+            };
+
+            done = false;
+            match recver.as_mut().poll_next(ctx) {
+                Poll::Ready(Some(msg)) => {
+                    select.next = (idx + 1) % len;
+                    return Poll::Ready(Some(Selected::Msg(idx, msg)));
+                }
+                // We stop polling the closed
+                // receiver and notify that it
+                // was closed.
+                Poll::Ready(None) => {
+                    select.recvers[idx] = None;
+                    select.next = (idx + 1) % len;
+                    return Poll::Ready(Some(Selected::Closed(idx)));
+                }
+                Poll::Pending => (),
+            }
+        }
+
+        // If all the receivers have been
+        // closed, we stop the stream.
+        if done {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> FusedStream for Select<T> {
+    fn is_terminated(&self) -> bool {
+        self.recvers.iter().all(|recver| recver.is_none())
+    }
+}
+
+impl<U, F, T> Stream for Mapped<U, F>
+where
+    F: Fn(U) -> T + Unpin,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<T>> {
+        let mapped = self.get_mut();
+
+        match Pin::new(&mut mapped.recver).poll_next(ctx) {
+            Poll::Ready(Some(msg)) => Poll::Ready(Some((mapped.map)(msg))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Default for Select<T> {
+    fn default() -> Self {
+        Select::new()
+    }
+}
+
+impl<T> Unpin for Select<T> {}
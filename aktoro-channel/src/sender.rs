@@ -1,13 +1,68 @@
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::Weak;
+use std::task::Context;
+use std::task::Poll;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures_sink::Sink;
 
 use crate::channel::Channel;
 use crate::error::*;
 use crate::message::Message;
 use crate::notify::Notify;
+use crate::park;
+use crate::stats::ChannelStats;
 
 /// A channel's sender allowing to
-/// send messages over it.
-pub struct Sender<T>(Option<Arc<Channel<T>>>);
+/// send messages over it, either via
+/// the [`try_send`] and [`send`]
+/// methods or the `Sink`
+/// implementation.
+///
+/// [`try_send`]: #method.try_send
+/// [`send`]: #method.send
+pub struct Sender<T> {
+    /// The channel the sender will
+    /// send messages over.
+    channel: Option<Arc<Channel<T>>>,
+    /// A message passed to the `Sink`
+    /// implementation that couldn't be
+    /// sent yet because the channel
+    /// was full.
+    pending: Option<T>,
+}
+
+/// A weak reference to a channel, that
+/// doesn't count as one of its senders (and
+/// thus doesn't keep it open) but that can
+/// be upgraded to a [`Sender`] while other
+/// senders are still connected to it.
+///
+/// [`Sender`]: struct.Sender.html
+pub struct WeakSender<T> {
+    channel: Weak<Channel<T>>,
+}
+
+/// A future returned by [`Sender::send`]
+/// that resolves when the message has
+/// been sent over the channel.
+///
+/// [`Sender::send`]: struct.Sender.html#method.send
+pub struct Sending<T> {
+    /// The channel the message will
+    /// be sent over.
+    channel: Option<Arc<Channel<T>>>,
+    /// The message that will be sent
+    /// or `None` if it already has
+    /// been.
+    msg: Option<T>,
+    /// The message's priority.
+    prio: usize,
+}
 
 impl<T> Sender<T> {
     /// Creates a new sender from a pointer
@@ -18,7 +73,10 @@ impl<T> Sender<T> {
         // immediately after a channel's
         // creation.
         channel.counters.add_sender().expect("senders limit == 0");
-        Sender(Some(channel))
+        Sender {
+            channel: Some(channel),
+            pending: None,
+        }
     }
 
     /// Tries to send a message over the
@@ -27,13 +85,78 @@ impl<T> Sender<T> {
     /// number of messages that can be sent
     /// over it has been reached.
     pub fn try_send(&self, msg: T) -> Result<(), TrySendError<T>> {
-        if let Some(channel) = &self.0 {
+        if let Some(channel) = &self.channel {
             channel.try_send(Message::new(msg))
         } else {
             Err(TrySendError::disconnected(msg))
         }
     }
 
+    /// Tries to send a message over the
+    /// channel that will expire after `ttl`
+    /// has elapsed, returning an error if
+    /// the channel is full or the maximum
+    /// number of messages that can be sent
+    /// over it has been reached.
+    ///
+    /// If the message hasn't been received
+    /// before it expires, it is dropped (or
+    /// sent to the channel's dead letters
+    /// channel, see [`Receiver::dead_letters`]).
+    ///
+    /// [`Receiver::dead_letters`]: struct.Receiver.html#method.dead_letters
+    pub fn try_send_ttl(&self, msg: T, ttl: Duration) -> Result<(), TrySendError<T>> {
+        if let Some(channel) = &self.channel {
+            channel.try_send(Message::new(msg).with_deadline(Instant::now() + ttl))
+        } else {
+            Err(TrySendError::disconnected(msg))
+        }
+    }
+
+    /// Tries to send all the messages
+    /// yielded by `msgs` over the channel,
+    /// returning the number of messages
+    /// sent or an error containing the first
+    /// message that couldn't be sent (the
+    /// messages yielded before it have been
+    /// sent and the following ones are left
+    /// in the iterator).
+    ///
+    /// The channel's counters are updated
+    /// by batches and a receiver is only
+    /// notified once, which makes this method
+    /// faster than calling [`try_send`] for
+    /// each message.
+    ///
+    /// [`try_send`]: #method.try_send
+    pub fn try_send_many<I>(&self, msgs: I) -> Result<usize, TrySendError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if let Some(channel) = &self.channel {
+            channel.try_send_many(msgs.into_iter().map(Message::new))
+        } else if let Some(msg) = msgs.into_iter().next() {
+            Err(TrySendError::disconnected(msg))
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Tries to send a message over the
+    /// channel with the given priority
+    /// (which is only used if the channel
+    /// is prioritized), returning an error
+    /// if the channel is full or the
+    /// maximum number of messages that can
+    /// be sent over it has been reached.
+    pub fn try_send_prio(&self, msg: T, prio: usize) -> Result<(), TrySendError<T>> {
+        if let Some(channel) = &self.channel {
+            channel.try_send(Message::new(msg).with_prio(prio))
+        } else {
+            Err(TrySendError::disconnected(msg))
+        }
+    }
+
     /// Tries to send a message over the
     /// channel, returning an error if the
     /// channel is full or the maximum
@@ -45,7 +168,7 @@ impl<T> Sender<T> {
     ///
     /// [`Notify`]: struct.Notify.html
     pub fn try_send_notify(&self, msg: T) -> Result<Notify, TrySendError<T>> {
-        if let Some(channel) = &self.0 {
+        if let Some(channel) = &self.channel {
             let (msg, notify) = Message::new_notified(msg);
 
             channel.try_send(msg)?;
@@ -55,10 +178,107 @@ impl<T> Sender<T> {
         }
     }
 
+    /// Sends a message over the channel,
+    /// returning a future that resolves
+    /// once it has been sent, waiting for
+    /// the channel to have free space if
+    /// it is full, or with an error if
+    /// the maximum number of messages that
+    /// can be sent over it has been reached
+    /// or if it is closed.
+    pub fn send(&self, msg: T) -> Sending<T> {
+        self.send_prio(msg, 0)
+    }
+
+    /// Sends a message over the channel
+    /// with the given priority (which is
+    /// only used if the channel is
+    /// prioritized), like [`send`].
+    ///
+    /// [`send`]: #method.send
+    pub fn send_prio(&self, msg: T, prio: usize) -> Sending<T> {
+        Sending {
+            channel: self.channel.clone(),
+            msg: Some(msg),
+            prio,
+        }
+    }
+
+    /// Sends a message over the channel,
+    /// blocking the current thread until
+    /// it has been sent (waiting for the
+    /// channel to have free space if it is
+    /// full), or returning an error if the
+    /// maximum number of messages that can
+    /// be sent over it has been reached or
+    /// if it is closed.
+    ///
+    /// This method allows to send messages
+    /// from threads that aren't running an
+    /// executor and shouldn't be called from
+    /// asynchronous code.
+    pub fn send_blocking(&self, msg: T) -> Result<(), TrySendError<T>> {
+        self.send_blocking_prio(msg, 0)
+    }
+
+    /// Sends a message over the channel
+    /// with the given priority (which is
+    /// only used if the channel is
+    /// prioritized), like [`send_blocking`].
+    ///
+    /// [`send_blocking`]: #method.send_blocking
+    pub fn send_blocking_prio(&self, msg: T, prio: usize) -> Result<(), TrySendError<T>> {
+        let channel = if let Some(channel) = &self.channel {
+            channel
+        } else {
+            return Err(TrySendError::disconnected(msg));
+        };
+
+        let waker = park::waker();
+        let mut ctx = Context::from_waker(&waker);
+
+        let mut msg = Some(msg);
+        loop {
+            match channel.poll_send(&mut ctx, &mut msg, prio) {
+                Poll::Ready(res) => return res,
+                // We wait for a receiver to
+                // wake us up.
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// Returns a snapshot of the state of
+    /// the channel the sender is connected
+    /// to (or of a closed and empty
+    /// channel if it is disconnected).
+    pub fn stats(&self) -> ChannelStats {
+        if let Some(channel) = &self.channel {
+            channel.stats()
+        } else {
+            ChannelStats {
+                closed: true,
+                ..ChannelStats::default()
+            }
+        }
+    }
+
+    /// Returns the number of messages that
+    /// were dropped because the channel
+    /// was full (depending on its overflow
+    /// policy).
+    pub fn dropped(&self) -> usize {
+        if let Some(channel) = &self.channel {
+            channel.counters.dropped()
+        } else {
+            0
+        }
+    }
+
     /// Whether the channel the sender is
     /// connected to is closed.
     pub fn is_closed(&self) -> bool {
-        if let Some(channel) = &self.0 {
+        if let Some(channel) = &self.channel {
             channel.is_closed()
         } else {
             true
@@ -68,30 +288,63 @@ impl<T> Sender<T> {
     /// Closes the channel the sender is
     /// connected to.
     pub fn close_channel(&self) {
-        if let Some(channel) = &self.0 {
+        if let Some(channel) = &self.channel {
             channel.close()
         }
     }
 
+    /// Creates a sender that isn't connected
+    /// to any channel (sending messages with
+    /// it always fails).
+    pub fn disconnected() -> Self {
+        Sender {
+            channel: None,
+            pending: None,
+        }
+    }
+
     /// Disconnects the sender from the
     /// channel it is connected to.
     pub fn disconnect(&mut self) {
-        let channel = if let Some(channel) = self.0.take() {
+        let channel = if let Some(channel) = self.channel.take() {
             channel
         } else {
             return;
         };
 
-        channel.counters.sub_sender();
+        // If this was the last sender, we
+        // wake up the receivers so that they
+        // can notice that the channel is
+        // closed.
+        if channel.counters.sub_sender() == 0 {
+            channel.notify_recvers();
+        }
+    }
+
+    /// Creates a [`WeakSender`] connected to
+    /// the same channel as the sender.
+    ///
+    /// [`WeakSender`]: struct.WeakSender.html
+    pub fn downgrade(&self) -> WeakSender<T> {
+        WeakSender {
+            channel: self
+                .channel
+                .as_ref()
+                .map(Arc::downgrade)
+                .unwrap_or_else(Weak::new),
+        }
     }
 
     /// Tries to clone the sender, either
     /// returning a new sender connected to
     /// the same channel, or an error.
     pub fn try_clone(&self) -> Result<Self, CloneError> {
-        if let Some(channel) = &self.0 {
+        if let Some(channel) = &self.channel {
             if channel.counters.add_sender().is_ok() {
-                Ok(Sender(Some(channel.clone())))
+                Ok(Sender {
+                    channel: Some(channel.clone()),
+                    pending: None,
+                })
             } else {
                 Err(CloneError::limit())
             }
@@ -99,8 +352,119 @@ impl<T> Sender<T> {
             Err(CloneError::disconnected())
         }
     }
+
+    /// Tries to send the pending message
+    /// (if there is one).
+    fn poll_pending(&mut self, ctx: &mut Context) -> Poll<Result<(), TrySendError<T>>> {
+        if self.pending.is_none() {
+            return Poll::Ready(Ok(()));
+        }
+
+        if let Some(channel) = &self.channel {
+            channel.poll_send(ctx, &mut self.pending, 0)
+        } else {
+            Poll::Ready(Err(TrySendError::disconnected(
+                self.pending.take().unwrap(),
+            )))
+        }
+    }
+}
+
+impl<T> WeakSender<T> {
+    /// Tries to upgrade the weak sender to a
+    /// [`Sender`], returning `None` if the
+    /// channel is closed, if no other sender
+    /// is connected to it or if the maximum
+    /// number of senders has been reached.
+    ///
+    /// [`Sender`]: struct.Sender.html
+    pub fn upgrade(&self) -> Option<Sender<T>> {
+        let channel = self.channel.upgrade()?;
+
+        if channel.is_closed() || channel.counters.revive_sender().is_err() {
+            return None;
+        }
+
+        Some(Sender {
+            channel: Some(channel),
+            pending: None,
+        })
+    }
+}
+
+impl<T> Future for Sending<T> {
+    type Output = Result<(), TrySendError<T>>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Result<(), TrySendError<T>>> {
+        let sending = self.get_mut();
+
+        if let Some(channel) = &sending.channel {
+            channel.poll_send(ctx, &mut sending.msg, sending.prio)
+        } else if let Some(msg) = sending.msg.take() {
+            Poll::Ready(Err(TrySendError::disconnected(msg)))
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
 }
 
+impl<T> Sink<T> for Sender<T> {
+    type SinkError = TrySendError<T>;
+
+    fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Result<(), TrySendError<T>>> {
+        self.get_mut().poll_pending(ctx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), TrySendError<T>> {
+        let sender = self.get_mut();
+
+        // If the channel is full, we store
+        // the message to send it when it
+        // has free space.
+        match sender.try_send(msg) {
+            Err(err) => {
+                if err.is_full() && sender.pending.is_none() {
+                    sender.pending = Some(err.into_msg());
+                    Ok(())
+                } else {
+                    Err(err)
+                }
+            }
+            Ok(()) => Ok(()),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Result<(), TrySendError<T>>> {
+        self.get_mut().poll_pending(ctx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Result<(), TrySendError<T>>> {
+        let sender = self.get_mut();
+
+        // We send the pending message before
+        // disconnecting the sender.
+        match sender.poll_pending(ctx) {
+            Poll::Ready(Ok(())) => {
+                sender.disconnect();
+                Poll::Ready(Ok(()))
+            }
+            res => res,
+        }
+    }
+}
+
+impl<T> Clone for WeakSender<T> {
+    fn clone(&self) -> Self {
+        WeakSender {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> Unpin for Sender<T> {}
+
+impl<T> Unpin for Sending<T> {}
+
 impl<T> Drop for Sender<T> {
     fn drop(&mut self) {
         self.disconnect();
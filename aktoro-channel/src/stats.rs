@@ -0,0 +1,37 @@
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+/// A snapshot of a channel's state,
+/// returned by [`Sender::stats`] and
+/// [`Receiver::stats`].
+///
+/// [`Sender::stats`]: struct.Sender.html#method.stats
+/// [`Receiver::stats`]: struct.Receiver.html#method.stats
+pub struct ChannelStats {
+    /// The number of messages waiting
+    /// to be received.
+    pub len: usize,
+    /// The channel's capacity (for all
+    /// its priority levels), or `None` if
+    /// it is unbounded.
+    pub capacity: Option<usize>,
+    /// The total number of messages sent
+    /// over the channel.
+    pub sent: usize,
+    /// The total number of messages
+    /// received from the channel.
+    pub received: usize,
+    /// The number of messages that were
+    /// dropped because the channel was full
+    /// (depending on its overflow policy).
+    pub dropped: usize,
+    /// The number of messages that expired
+    /// before being received.
+    pub expired: usize,
+    /// The number of senders connected to
+    /// the channel.
+    pub senders: usize,
+    /// The number of receivers connected
+    /// to the channel.
+    pub receivers: usize,
+    /// Whether the channel is closed.
+    pub closed: bool,
+}
@@ -0,0 +1,281 @@
+//! A watch channel, allowing a sender to
+//! share a value with many receivers that
+//! get notified when it changes.
+//!
+//! Receivers only see the latest value: if
+//! the value changes several times before
+//! a receiver checks it, the receiver only
+//! gets the last one.
+
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task;
+use std::task::Context;
+use std::task::Poll;
+
+use crossbeam_utils::atomic::AtomicCell;
+use futures_core::Stream;
+
+use crate::error::*;
+
+type Waker = Arc<AtomicCell<Option<task::Waker>>>;
+
+/// A watch channel's sender, allowing to
+/// update the value shared with the
+/// receivers.
+///
+/// The channel is closed when the sender
+/// is dropped.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// A watch channel's receiver, allowing to
+/// get the latest value shared by the
+/// sender and to get notified when it
+/// changes, either via the [`try_recv`]
+/// method or the `Stream` implementation.
+///
+/// [`try_recv`]: #method.try_recv
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    /// The version of the value that the
+    /// receiver has last seen.
+    version: u64,
+    /// A reference to the space the
+    /// receiver was assigned to store
+    /// its waker.
+    waker: Waker,
+}
+
+/// The value shared by the sender and
+/// the receivers.
+struct Shared<T> {
+    /// The latest value.
+    value: Mutex<T>,
+    /// The number of times the value has
+    /// been updated.
+    version: AtomicU64,
+    /// Whether the sender has been dropped.
+    closed: AtomicBool,
+    /// The number of receivers connected
+    /// to the channel.
+    recvers: AtomicUsize,
+    /// The spaces the receivers were
+    /// assigned to store their waker (one
+    /// per receiver, so that a receiver
+    /// polled several times only stores
+    /// its latest waker).
+    wakers: Mutex<Vec<Waker>>,
+}
+
+/// Creates a new watch channel whose value
+/// is initially `init`, returning a sender
+/// and a receiver connected to it.
+///
+/// The receiver considers the initial value
+/// as already seen.
+pub fn new<T: Clone>(init: T) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        value: Mutex::new(init),
+        version: AtomicU64::new(0),
+        closed: AtomicBool::new(false),
+        recvers: AtomicUsize::new(1),
+        wakers: Mutex::new(vec![]),
+    });
+
+    let waker = shared.register();
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver {
+            shared,
+            version: 0,
+            waker,
+        },
+    )
+}
+
+impl<T: Clone> Sender<T> {
+    /// Updates the shared value and notifies
+    /// the receivers, returning the value
+    /// back if there isn't any receiver
+    /// anymore.
+    pub fn send(&self, value: T) -> Result<(), TrySendError<T>> {
+        if self.shared.recvers.load(Ordering::SeqCst) == 0 {
+            return Err(TrySendError::disconnected(value));
+        }
+
+        {
+            let mut shared = self.shared.value.lock().unwrap();
+            *shared = value;
+
+            self.shared.version.fetch_add(1, Ordering::SeqCst);
+        }
+
+        self.shared.wake();
+
+        Ok(())
+    }
+
+    /// Returns a clone of the latest value.
+    pub fn get(&self) -> T {
+        self.shared.value.lock().unwrap().clone()
+    }
+
+    /// Creates a new receiver connected to
+    /// the channel, considering the latest
+    /// value as already seen.
+    pub fn subscribe(&self) -> Receiver<T> {
+        self.shared.recvers.fetch_add(1, Ordering::SeqCst);
+
+        Receiver {
+            shared: self.shared.clone(),
+            version: self.shared.version.load(Ordering::SeqCst),
+            waker: self.shared.register(),
+        }
+    }
+
+    /// Returns the number of receivers
+    /// connected to the channel.
+    pub fn receivers(&self) -> usize {
+        self.shared.recvers.load(Ordering::SeqCst)
+    }
+}
+
+impl<T: Clone> Receiver<T> {
+    /// Returns a clone of the latest value,
+    /// marking it as seen.
+    pub fn get(&mut self) -> T {
+        let value = self.shared.value.lock().unwrap();
+        self.version = self.shared.version.load(Ordering::SeqCst);
+
+        value.clone()
+    }
+
+    /// Tries to receive the latest value if
+    /// it changed since the receiver last saw
+    /// it, returning `None` if it didn't or an
+    /// error if the channel is closed and the
+    /// latest value has already been seen.
+    pub fn try_recv(&mut self) -> Result<Option<T>, TryRecvError> {
+        // We load whether the channel is
+        // closed first, so that an update
+        // sent right before closing it
+        // isn't missed.
+        let closed = self.shared.closed.load(Ordering::SeqCst);
+
+        let value = self.shared.value.lock().unwrap();
+        let version = self.shared.version.load(Ordering::SeqCst);
+
+        if version != self.version {
+            self.version = version;
+            Ok(Some(value.clone()))
+        } else if closed {
+            Err(TryRecvError::closed())
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Whether the sender has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.shared.closed.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> Shared<T> {
+    /// Assigns a new space to store a
+    /// receiver's waker.
+    fn register(&self) -> Waker {
+        let waker = Arc::new(AtomicCell::new(None));
+        self.wakers.lock().unwrap().push(waker.clone());
+
+        waker
+    }
+
+    /// Removes the space assigned to a
+    /// receiver to store its waker.
+    fn unregister(&self, waker: &Waker) {
+        self.wakers
+            .lock()
+            .unwrap()
+            .retain(|waker_| !Arc::ptr_eq(waker_, waker));
+    }
+
+    /// Wakes up the receivers waiting for
+    /// the value to change.
+    fn wake(&self) {
+        let wakers = self
+            .wakers
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|waker| waker.swap(None))
+            .collect::<Vec<_>>();
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<T: Clone> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Option<T>> {
+        let recver = self.get_mut();
+
+        match recver.try_recv() {
+            Ok(Some(value)) => return Poll::Ready(Some(value)),
+            Err(_) => return Poll::Ready(None),
+            Ok(None) => (),
+        }
+
+        // We register the stream's waker
+        // before checking again, in case
+        // the value changed in the meantime.
+        recver.waker.store(Some(ctx.waker().clone()));
+
+        match recver.try_recv() {
+            Ok(Some(value)) => Poll::Ready(Some(value)),
+            Err(_) => Poll::Ready(None),
+            Ok(None) => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared.recvers.fetch_add(1, Ordering::SeqCst);
+
+        Receiver {
+            shared: self.shared.clone(),
+            version: self.version,
+            waker: self.shared.register(),
+        }
+    }
+}
+
+impl<T> Unpin for Receiver<T> {}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::SeqCst);
+        self.shared.wake();
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.recvers.fetch_sub(1, Ordering::SeqCst);
+        self.shared.unregister(&self.waker);
+    }
+}
@@ -0,0 +1,69 @@
+use std::pin::Pin;
+use std::task::Context;
+
+use aktoro_channel::Builder;
+use futures_core::Stream;
+
+mod common;
+
+use self::common::Counter;
+
+#[test]
+fn sends_and_receives_batches() {
+    let (sender, recver) = Builder::new().build();
+
+    assert_eq!(sender.try_send_many(0..5), Ok(5));
+
+    let mut buf = vec![];
+    assert_eq!(recver.try_recv_many(&mut buf, 3), Ok(3));
+    assert_eq!(buf, vec![0, 1, 2]);
+
+    assert_eq!(recver.try_recv_many(&mut buf, 3), Ok(2));
+    assert_eq!(buf, vec![0, 1, 2, 3, 4]);
+
+    assert_eq!(recver.try_recv_many(&mut buf, 3), Ok(0));
+
+    let stats = recver.stats();
+    assert_eq!(stats.sent, 5);
+    assert_eq!(stats.received, 5);
+}
+
+#[test]
+fn stops_at_the_first_message_that_cant_be_sent() {
+    let (sender, recver) = Builder::new().bounded(2).build();
+
+    let mut msgs = 0..5;
+    let err = sender.try_send_many(&mut msgs).unwrap_err();
+
+    assert!(err.is_full());
+    assert_eq!(err.into_msg(), 2);
+    assert_eq!(msgs.collect::<Vec<_>>(), vec![3, 4]);
+
+    let mut buf = vec![];
+    assert_eq!(recver.try_recv_many(&mut buf, 10), Ok(2));
+    assert_eq!(buf, vec![0, 1]);
+}
+
+#[test]
+fn notifies_the_receiver_once() {
+    let (sender, mut recver) = Builder::new().build::<u8>();
+    let (counter, waker) = Counter::new();
+    let mut ctx = Context::from_waker(&waker);
+
+    assert!(Pin::new(&mut recver).poll_next(&mut ctx).is_pending());
+
+    sender.try_send_many(0..3).unwrap();
+    assert_eq!(counter.get(), 1);
+}
+
+#[test]
+fn fails_once_closed_and_empty() {
+    let (sender, recver) = Builder::new().build();
+
+    sender.try_send(0).unwrap();
+    drop(sender);
+
+    let mut buf = vec![];
+    assert_eq!(recver.try_recv_many(&mut buf, 10), Ok(1));
+    assert!(recver.try_recv_many(&mut buf, 10).unwrap_err().is_closed());
+}
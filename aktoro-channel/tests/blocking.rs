@@ -0,0 +1,71 @@
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use aktoro_channel::Builder;
+
+#[test]
+fn receives_across_threads() {
+    let (sender, recver) = Builder::new().build();
+
+    let handle = thread::spawn(move || {
+        for msg in 0..3 {
+            thread::sleep(Duration::from_millis(5));
+            sender.send_blocking(msg).unwrap();
+        }
+    });
+
+    for msg in 0..3 {
+        assert_eq!(recver.recv_blocking(), Ok(msg));
+    }
+
+    handle.join().unwrap();
+
+    // The sender was dropped, so the
+    // channel is closed.
+    assert!(recver.recv_blocking().unwrap_err().is_closed());
+}
+
+#[test]
+fn waits_for_free_space() {
+    let (sender, recver) = Builder::new().bounded(1).build();
+
+    sender.send_blocking(0).unwrap();
+
+    let handle = thread::spawn(move || {
+        // This blocks until the first
+        // message has been received.
+        sender.send_blocking(1).unwrap();
+    });
+
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(recver.stats().len, 1);
+
+    assert_eq!(recver.recv_blocking(), Ok(0));
+    assert_eq!(recver.recv_blocking(), Ok(1));
+
+    handle.join().unwrap();
+}
+
+#[test]
+fn times_out_without_messages() {
+    let (sender, recver) = Builder::new().build::<()>();
+
+    let start = Instant::now();
+    assert_eq!(recver.recv_timeout(Duration::from_millis(20)), Ok(None));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    sender.try_send(()).unwrap();
+    assert_eq!(recver.recv_timeout(Duration::from_millis(20)), Ok(Some(())));
+}
+
+#[test]
+fn fails_once_closed() {
+    let (sender, recver) = Builder::new().build();
+
+    recver.close_channel();
+
+    let err = sender.send_blocking(0).unwrap_err();
+    assert!(err.is_closed());
+    assert!(recver.recv_blocking().unwrap_err().is_closed());
+}
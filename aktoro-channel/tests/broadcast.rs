@@ -0,0 +1,74 @@
+use std::pin::Pin;
+use std::task::Context;
+
+use aktoro_channel::broadcast;
+use futures_core::Stream;
+
+mod common;
+
+use self::common::Counter;
+
+#[test]
+fn every_receiver_gets_every_message() {
+    let (sender, mut first) = broadcast::new(4);
+    let mut second = sender.subscribe();
+
+    assert_eq!(sender.send(1), Ok(2));
+    assert_eq!(sender.send(2), Ok(2));
+
+    assert_eq!(first.try_recv(), Ok(Some(1)));
+    assert_eq!(first.try_recv(), Ok(Some(2)));
+    assert_eq!(second.try_recv(), Ok(Some(1)));
+    assert_eq!(second.try_recv(), Ok(Some(2)));
+    assert_eq!(second.try_recv(), Ok(None));
+}
+
+#[test]
+fn slow_receivers_lag() {
+    let (sender, mut recver) = broadcast::new(2);
+
+    for msg in 0..5 {
+        sender.send(msg).unwrap();
+    }
+
+    assert_eq!(recver.try_recv().unwrap_err().lagged_by(), Some(3));
+    assert_eq!(recver.try_recv(), Ok(Some(3)));
+    assert_eq!(recver.try_recv(), Ok(Some(4)));
+}
+
+#[test]
+fn keeps_one_waker_per_receiver() {
+    let (sender, mut recver) = broadcast::new(4);
+
+    let (old, old_waker) = Counter::new();
+    let (new, new_waker) = Counter::new();
+
+    for _ in 0..16 {
+        let mut ctx = Context::from_waker(&old_waker);
+        assert!(Pin::new(&mut recver).poll_next(&mut ctx).is_pending());
+    }
+
+    let mut ctx = Context::from_waker(&new_waker);
+    assert!(Pin::new(&mut recver).poll_next(&mut ctx).is_pending());
+
+    sender.send(1).unwrap();
+
+    assert_eq!(old.get(), 0);
+    assert_eq!(new.get(), 1);
+}
+
+#[test]
+fn dropped_sender_closes() {
+    let (sender, mut recver) = broadcast::new(4);
+    let (counter, waker) = Counter::new();
+
+    let mut ctx = Context::from_waker(&waker);
+    assert!(Pin::new(&mut recver).poll_next(&mut ctx).is_pending());
+
+    sender.send(1).unwrap();
+    drop(sender);
+
+    assert_eq!(counter.get(), 1);
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+    assert!(recver.try_recv().unwrap_err().is_closed());
+}
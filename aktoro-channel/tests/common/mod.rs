@@ -0,0 +1,34 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Waker;
+
+use futures_util::task;
+use futures_util::task::ArcWake;
+
+/// A waker counting how many times it
+/// was woken up.
+pub struct Counter(AtomicUsize);
+
+impl Counter {
+    /// Creates a new counter, returning it
+    /// along with a waker incrementing it.
+    pub fn new() -> (Arc<Counter>, Waker) {
+        let counter = Arc::new(Counter(AtomicUsize::new(0)));
+        let waker = task::waker(counter.clone());
+
+        (counter, waker)
+    }
+
+    /// Returns how many times the waker
+    /// was woken up.
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl ArcWake for Counter {
+    fn wake_by_ref(counter: &Arc<Self>) {
+        counter.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,40 @@
+use std::thread;
+use std::time::Duration;
+
+use aktoro_channel::Builder;
+
+#[test]
+fn skips_expired_messages() {
+    let (sender, recver) = Builder::new().build();
+    let (dead_sender, dead) = Builder::new().build();
+    recver.dead_letters(dead_sender);
+
+    sender.try_send_ttl(0, Duration::from_millis(1)).unwrap();
+    sender.try_send_ttl(1, Duration::from_secs(60)).unwrap();
+    sender.try_send(2).unwrap();
+
+    thread::sleep(Duration::from_millis(10));
+
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+    assert_eq!(recver.try_recv(), Ok(Some(2)));
+    assert_eq!(recver.try_recv(), Ok(None));
+
+    assert_eq!(recver.stats().expired, 1);
+    assert_eq!(dead.try_recv(), Ok(Some(0)));
+}
+
+#[test]
+fn expired_messages_free_space() {
+    let (sender, recver) = Builder::new().bounded(1).build();
+
+    sender.try_send_ttl(0, Duration::from_millis(1)).unwrap();
+    thread::sleep(Duration::from_millis(10));
+
+    // The expired message is only removed
+    // once the receiver tries to get it.
+    assert!(sender.try_send(1).unwrap_err().is_full());
+    assert_eq!(recver.try_recv(), Ok(None));
+
+    sender.try_send(1).unwrap();
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+}
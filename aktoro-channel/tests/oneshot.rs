@@ -0,0 +1,80 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::thread;
+
+use aktoro_channel::error::Canceled;
+use aktoro_channel::oneshot;
+use futures_executor::block_on;
+
+mod common;
+
+use self::common::Counter;
+
+#[test]
+fn sends_a_value() {
+    let (sender, mut recver) = oneshot::new();
+
+    assert_eq!(recver.try_recv(), Ok(None));
+    assert!(sender.send(42).is_ok());
+    assert_eq!(recver.try_recv(), Ok(Some(42)));
+
+    // The value can only be received once.
+    assert_eq!(recver.try_recv(), Err(Canceled));
+}
+
+#[test]
+fn resolves_across_threads() {
+    let (sender, recver) = oneshot::new();
+
+    let handle = thread::spawn(move || sender.send("value").unwrap());
+
+    assert_eq!(block_on(recver), Ok("value"));
+    handle.join().unwrap();
+}
+
+#[test]
+fn dropped_sender_cancels() {
+    let (sender, mut recver) = oneshot::new::<()>();
+    let (counter, waker) = Counter::new();
+
+    let mut ctx = Context::from_waker(&waker);
+    assert!(Pin::new(&mut recver).poll(&mut ctx).is_pending());
+
+    drop(sender);
+
+    assert_eq!(counter.get(), 1);
+    assert_eq!(block_on(recver), Err(Canceled));
+}
+
+#[test]
+fn closed_receiver_refuses_values() {
+    let (sender, mut recver) = oneshot::new();
+
+    assert!(!sender.is_canceled());
+    recver.close();
+    assert!(sender.is_canceled());
+
+    assert_eq!(sender.send(42), Err(42));
+    assert_eq!(block_on(recver), Err(Canceled));
+}
+
+#[test]
+fn dropped_receiver_refuses_values() {
+    let (sender, recver) = oneshot::new();
+
+    drop(recver);
+
+    assert!(sender.is_canceled());
+    assert_eq!(sender.send(42), Err(42));
+}
+
+#[test]
+fn value_sent_before_close_is_received() {
+    let (sender, mut recver) = oneshot::new();
+
+    sender.send(42).unwrap();
+    recver.close();
+
+    assert_eq!(block_on(recver), Ok(42));
+}
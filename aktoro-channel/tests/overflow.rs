@@ -0,0 +1,146 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aktoro_channel::Builder;
+use aktoro_channel::Overflow;
+
+#[test]
+fn dropped_newest_messages_are_sent_to_dropped_letters() {
+    let (sender, recver) = Builder::new()
+        .bounded(2)
+        .overflow(Overflow::DropNewest)
+        .build();
+    let (dropped_sender, dropped) = Builder::new().build();
+    recver.dropped_letters(dropped_sender);
+
+    for msg in 0..4 {
+        sender.try_send(msg).unwrap();
+    }
+
+    assert_eq!(recver.dropped(), 2);
+    assert_eq!(dropped.try_recv(), Ok(Some(2)));
+    assert_eq!(dropped.try_recv(), Ok(Some(3)));
+    assert_eq!(dropped.try_recv(), Ok(None));
+
+    assert_eq!(recver.try_recv(), Ok(Some(0)));
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+}
+
+#[test]
+fn dropped_messages_are_passed_to_the_sink() {
+    let (sender, recver) = Builder::new()
+        .bounded(2)
+        .overflow(Overflow::DropNewest)
+        .build();
+
+    let dropped = Arc::new(Mutex::new(vec![]));
+    let sink = dropped.clone();
+    recver.on_dropped(move |msg| sink.lock().unwrap().push(msg));
+
+    assert_eq!(sender.try_send_many(0..4), Ok(4));
+
+    // The dropped messages aren't counted
+    // as sent.
+    assert_eq!(*dropped.lock().unwrap(), vec![2, 3]);
+    assert_eq!(recver.stats().sent, 2);
+    assert_eq!(recver.stats().dropped, 2);
+}
+
+#[test]
+fn dropped_oldest_messages_are_sent_to_dropped_letters() {
+    let (sender, recver) = Builder::new()
+        .bounded(2)
+        .overflow(Overflow::DropOldest)
+        .build();
+    let (dropped_sender, dropped) = Builder::new().build();
+    recver.dropped_letters(dropped_sender);
+
+    for msg in 0..4 {
+        sender.try_send(msg).unwrap();
+    }
+
+    assert_eq!(recver.dropped(), 2);
+    assert_eq!(dropped.try_recv(), Ok(Some(0)));
+    assert_eq!(dropped.try_recv(), Ok(Some(1)));
+
+    assert_eq!(recver.try_recv(), Ok(Some(2)));
+    assert_eq!(recver.try_recv(), Ok(Some(3)));
+}
+
+#[test]
+fn dropped_oldest_messages_dont_count_against_the_limit() {
+    let (sender, recver) = Builder::new()
+        .bounded(2)
+        .limited_msgs(3)
+        .overflow(Overflow::DropOldest)
+        .build();
+
+    // We fill the queue and evict its oldest
+    // message.
+    sender.try_send(0).unwrap();
+    sender.try_send(1).unwrap();
+    sender.try_send(2).unwrap();
+    assert_eq!(recver.dropped(), 1);
+
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+    assert_eq!(recver.try_recv(), Ok(Some(2)));
+
+    // Only the two delivered messages count
+    // against the limit.
+    sender.try_send(3).unwrap();
+    assert!(sender.try_send(4).unwrap_err().is_limit());
+
+    assert_eq!(recver.try_recv(), Ok(Some(3)));
+}
+
+#[test]
+fn dropped_newest_messages_dont_count_against_the_limit() {
+    let (sender, recver) = Builder::new()
+        .bounded(1)
+        .limited_msgs(2)
+        .overflow(Overflow::DropNewest)
+        .build();
+
+    sender.try_send(0).unwrap();
+    sender.try_send(1).unwrap();
+    assert_eq!(recver.dropped(), 1);
+
+    assert_eq!(recver.try_recv(), Ok(Some(0)));
+
+    sender.try_send(2).unwrap();
+    assert!(sender.try_send(3).unwrap_err().is_limit());
+}
+
+#[test]
+fn rejects_new_messages_by_default() {
+    let (sender, recver) = Builder::new().bounded(2).build();
+
+    sender.try_send(0).unwrap();
+    sender.try_send(1).unwrap();
+
+    let err = sender.try_send(2).unwrap_err();
+    assert!(err.is_full());
+    assert_eq!(err.into_msg(), 2);
+    assert_eq!(recver.dropped(), 0);
+
+    assert_eq!(recver.try_recv(), Ok(Some(0)));
+    sender.try_send(3).unwrap();
+}
+
+#[test]
+fn latest_only_keeps_the_last_message() {
+    let (sender, recver) = Builder::new().bounded(4).overflow(Overflow::Latest).build();
+    let (dropped_sender, dropped) = Builder::new().build();
+    recver.dropped_letters(dropped_sender);
+
+    for msg in 0..3 {
+        sender.try_send(msg).unwrap();
+    }
+
+    assert_eq!(recver.dropped(), 2);
+    assert_eq!(dropped.try_recv(), Ok(Some(0)));
+    assert_eq!(dropped.try_recv(), Ok(Some(1)));
+
+    assert_eq!(recver.try_recv(), Ok(Some(2)));
+    assert_eq!(recver.try_recv(), Ok(None));
+}
@@ -0,0 +1,50 @@
+use aktoro_channel::Builder;
+
+#[test]
+fn receives_higher_priorities_first() {
+    let (sender, recver) = Builder::new().priorities(3).build();
+
+    sender.try_send_prio("low", 0).unwrap();
+    sender.try_send_prio("high", 2).unwrap();
+    sender.try_send_prio("medium", 1).unwrap();
+    sender.try_send_prio("high again", 2).unwrap();
+
+    assert_eq!(recver.try_recv(), Ok(Some("high")));
+    assert_eq!(recver.try_recv(), Ok(Some("high again")));
+    assert_eq!(recver.try_recv(), Ok(Some("medium")));
+    assert_eq!(recver.try_recv(), Ok(Some("low")));
+    assert_eq!(recver.try_recv(), Ok(None));
+}
+
+#[test]
+fn caps_priorities_to_the_highest_level() {
+    let (sender, recver) = Builder::new().priorities(2).build();
+
+    sender.try_send_prio("low", 0).unwrap();
+    sender.try_send_prio("capped", 42).unwrap();
+
+    assert_eq!(recver.try_recv(), Ok(Some("capped")));
+    assert_eq!(recver.try_recv(), Ok(Some("low")));
+}
+
+#[test]
+fn bounds_each_level() {
+    let (sender, recver) = Builder::new().bounded(1).priorities(2).build();
+
+    sender.try_send_prio(0, 0).unwrap();
+    sender.try_send_prio(1, 1).unwrap();
+    assert!(sender.try_send_prio(2, 1).unwrap_err().is_full());
+
+    assert_eq!(recver.stats().capacity, Some(2));
+}
+
+#[test]
+fn ignores_priorities_when_not_prioritized() {
+    let (sender, recver) = Builder::new().build();
+
+    sender.try_send_prio(0, 0).unwrap();
+    sender.try_send_prio(1, 1).unwrap();
+
+    assert_eq!(recver.try_recv(), Ok(Some(0)));
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+}
@@ -0,0 +1,121 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use aktoro_channel::Builder;
+use aktoro_channel::Select;
+use aktoro_channel::SelectMode;
+use aktoro_channel::Selected;
+use futures_core::Stream;
+use futures_executor::block_on_stream;
+
+mod common;
+
+use self::common::Counter;
+
+#[test]
+fn polls_receivers_in_turn() {
+    let (sender1, recver1) = Builder::new().build();
+    let (sender2, recver2) = Builder::new().build();
+
+    let mut select = Select::new();
+    assert_eq!(select.mode(), SelectMode::RoundRobin);
+    assert_eq!(select.push(recver1), 0);
+    assert_eq!(select.push(recver2), 1);
+
+    for msg in 0..2 {
+        sender1.try_send(msg).unwrap();
+        sender2.try_send(msg + 10).unwrap();
+    }
+
+    drop(sender1);
+    drop(sender2);
+
+    let selected = block_on_stream(select).collect::<Vec<_>>();
+    assert_eq!(
+        selected,
+        vec![
+            Selected::Msg(0, 0),
+            Selected::Msg(1, 10),
+            Selected::Msg(0, 1),
+            Selected::Msg(1, 11),
+            Selected::Closed(0),
+            Selected::Closed(1),
+        ]
+    );
+}
+
+#[test]
+fn biased_polls_the_first_receivers_first() {
+    let (sender1, recver1) = Builder::new().build();
+    let (sender2, recver2) = Builder::new().build();
+
+    let mut select = Select::biased();
+    select.push(recver1);
+    select.push(recver2);
+
+    sender2.try_send(10).unwrap();
+    sender1.try_send(0).unwrap();
+    sender1.try_send(1).unwrap();
+
+    let mut selected = block_on_stream(select);
+    assert_eq!(selected.next(), Some(Selected::Msg(0, 0)));
+    assert_eq!(selected.next(), Some(Selected::Msg(0, 1)));
+    assert_eq!(selected.next(), Some(Selected::Msg(1, 10)));
+}
+
+#[derive(Eq, PartialEq, Debug)]
+enum Either {
+    Number(u8),
+    Text(&'static str),
+}
+
+#[test]
+fn maps_messages_of_different_types() {
+    let (numbers, recver1) = Builder::new().build();
+    let (texts, recver2) = Builder::new().build();
+
+    let mut select = Select::new();
+    select.push_map(recver1, Either::Number);
+    select.push_map(recver2, Either::Text);
+
+    numbers.try_send(42).unwrap();
+    texts.try_send("text").unwrap();
+
+    let mut selected = block_on_stream(select);
+    assert_eq!(selected.next(), Some(Selected::Msg(0, Either::Number(42))));
+    assert_eq!(
+        selected.next(),
+        Some(Selected::Msg(1, Either::Text("text")))
+    );
+}
+
+#[test]
+fn wakes_up_when_any_receiver_gets_a_message() {
+    let (sender1, recver1) = Builder::new().build::<u8>();
+    let (sender2, recver2) = Builder::new().build();
+
+    let mut select = Select::new();
+    select.push(recver1);
+    select.push(recver2);
+
+    let (counter, waker) = Counter::new();
+    let mut ctx = Context::from_waker(&waker);
+
+    assert!(Pin::new(&mut select).poll_next(&mut ctx).is_pending());
+
+    sender2.try_send(1).unwrap();
+    assert_eq!(counter.get(), 1);
+
+    assert_eq!(
+        Pin::new(&mut select).poll_next(&mut ctx),
+        Poll::Ready(Some(Selected::Msg(1, 1)))
+    );
+
+    drop(sender1);
+    assert_eq!(
+        Pin::new(&mut select).poll_next(&mut ctx),
+        Poll::Ready(Some(Selected::Closed(0)))
+    );
+    assert_eq!(select.remaining(), 1);
+}
@@ -0,0 +1,70 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use aktoro_channel::Builder;
+use futures_executor::block_on;
+use futures_sink::Sink;
+
+mod common;
+
+use self::common::Counter;
+
+#[test]
+fn waits_for_free_space() {
+    let (sender, recver) = Builder::new().bounded(1).build();
+    let (counter, waker) = Counter::new();
+    let mut ctx = Context::from_waker(&waker);
+
+    sender.try_send(0).unwrap();
+
+    let mut sending = sender.send(1);
+    assert!(Pin::new(&mut sending).poll(&mut ctx).is_pending());
+
+    assert_eq!(recver.try_recv(), Ok(Some(0)));
+    assert_eq!(counter.get(), 1);
+
+    assert!(block_on(sending).is_ok());
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+}
+
+#[test]
+fn fails_once_closed() {
+    let (sender, recver) = Builder::new().bounded(1).build();
+    let (_, waker) = Counter::new();
+    let mut ctx = Context::from_waker(&waker);
+
+    sender.try_send(0).unwrap();
+
+    let mut sending = sender.send(1);
+    assert!(Pin::new(&mut sending).poll(&mut ctx).is_pending());
+
+    recver.close_channel();
+
+    let err = block_on(sending).unwrap_err();
+    assert!(err.is_closed());
+    assert_eq!(err.into_msg(), 1);
+}
+
+#[test]
+fn sink_keeps_a_pending_message() {
+    let (mut sender, recver) = Builder::new().bounded(1).build();
+    let (counter, waker) = Counter::new();
+    let mut ctx = Context::from_waker(&waker);
+
+    let mut sink = Pin::new(&mut sender);
+    assert_eq!(sink.as_mut().poll_ready(&mut ctx), Poll::Ready(Ok(())));
+    sink.as_mut().start_send(0).unwrap();
+
+    // The channel is full, so the message
+    // is kept until it has free space.
+    sink.as_mut().start_send(1).unwrap();
+    assert!(sink.as_mut().poll_flush(&mut ctx).is_pending());
+
+    assert_eq!(recver.try_recv(), Ok(Some(0)));
+    assert_eq!(counter.get(), 1);
+
+    assert_eq!(sink.as_mut().poll_close(&mut ctx), Poll::Ready(Ok(())));
+    assert_eq!(recver.try_recv(), Ok(Some(1)));
+}
@@ -0,0 +1,68 @@
+use aktoro_channel::Builder;
+use aktoro_channel::ChannelStats;
+use aktoro_channel::Overflow;
+
+#[test]
+fn tracks_the_channel_state() {
+    let (sender, recver) = Builder::new()
+        .bounded(2)
+        .overflow(Overflow::DropNewest)
+        .build();
+
+    assert_eq!(
+        recver.stats(),
+        ChannelStats {
+            capacity: Some(2),
+            senders: 1,
+            receivers: 1,
+            ..ChannelStats::default()
+        }
+    );
+
+    for msg in 0..3 {
+        sender.try_send(msg).unwrap();
+    }
+
+    recver.try_recv().unwrap();
+
+    let clone = sender.try_clone().unwrap();
+    let stats = sender.stats();
+
+    // The dropped message is only counted
+    // as dropped.
+    assert_eq!(stats.len, 1);
+    assert_eq!(stats.sent, 2);
+    assert_eq!(stats.received, 1);
+    assert_eq!(stats.dropped, 1);
+    assert_eq!(stats.senders, 2);
+    assert!(!stats.closed);
+
+    // The sender and the receiver see the
+    // same channel.
+    assert_eq!(stats, recver.stats());
+
+    drop(clone);
+    drop(sender);
+
+    let stats = recver.stats();
+    assert_eq!(stats.senders, 0);
+    assert!(stats.closed);
+}
+
+#[test]
+fn unbounded_channels_have_no_capacity() {
+    let (sender, _recver) = Builder::new().build::<()>();
+
+    assert_eq!(sender.stats().capacity, None);
+}
+
+#[test]
+fn disconnected_senders_see_a_closed_channel() {
+    let (mut sender, _recver) = Builder::new().build::<()>();
+    sender.try_send(()).unwrap();
+    sender.disconnect();
+
+    let stats = sender.stats();
+    assert_eq!(stats.len, 0);
+    assert!(stats.closed);
+}
@@ -0,0 +1,120 @@
+use std::pin::Pin;
+use std::task::Context;
+
+use aktoro_channel::watch;
+use futures_core::Stream;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+mod common;
+
+use self::common::Counter;
+
+#[test]
+fn receives_latest_value() {
+    let (sender, mut recver) = watch::new(0);
+
+    // The initial value is considered as
+    // already seen.
+    assert_eq!(recver.try_recv(), Ok(None));
+    assert_eq!(recver.get(), 0);
+
+    sender.send(1).unwrap();
+    sender.send(2).unwrap();
+
+    assert_eq!(recver.try_recv(), Ok(Some(2)));
+    assert_eq!(recver.try_recv(), Ok(None));
+    assert_eq!(sender.get(), 2);
+}
+
+#[test]
+fn notifies_all_receivers() {
+    let (sender, mut first) = watch::new(0);
+    let mut second = first.clone();
+    let mut third = sender.subscribe();
+
+    assert_eq!(sender.receivers(), 3);
+
+    sender.send(1).unwrap();
+
+    assert_eq!(first.try_recv(), Ok(Some(1)));
+    assert_eq!(second.try_recv(), Ok(Some(1)));
+    assert_eq!(third.try_recv(), Ok(Some(1)));
+
+    // A new receiver doesn't see the values
+    // sent before its creation.
+    let mut fourth = sender.subscribe();
+    assert_eq!(fourth.try_recv(), Ok(None));
+
+    drop(first);
+    drop(second);
+    assert_eq!(sender.receivers(), 2);
+}
+
+#[test]
+fn dropped_sender_closes() {
+    let (sender, mut recver) = watch::new(0);
+
+    sender.send(1).unwrap();
+    drop(sender);
+
+    assert!(recver.is_closed());
+
+    // The last value is still received
+    // before the stream ends.
+    assert_eq!(block_on(recver.next()), Some(1));
+    assert_eq!(block_on(recver.next()), None);
+    assert!(recver.try_recv().unwrap_err().is_closed());
+}
+
+#[test]
+fn dropped_sender_wakes_receivers() {
+    let (sender, mut first) = watch::new(0);
+    let mut second = first.clone();
+
+    let (counter, waker) = Counter::new();
+    let mut ctx = Context::from_waker(&waker);
+
+    assert!(Pin::new(&mut first).poll_next(&mut ctx).is_pending());
+    assert!(Pin::new(&mut second).poll_next(&mut ctx).is_pending());
+
+    drop(sender);
+
+    assert_eq!(counter.get(), 2);
+}
+
+#[test]
+fn refuses_values_without_receivers() {
+    let (sender, recver) = watch::new(0);
+
+    drop(recver);
+
+    assert!(sender.send(1).unwrap_err().is_disconnected());
+}
+
+#[test]
+fn keeps_one_waker_per_receiver() {
+    let (sender, mut recver) = watch::new(0);
+
+    let (old, old_waker) = Counter::new();
+    let (new, new_waker) = Counter::new();
+
+    // Polling the receiver several times
+    // only keeps its latest waker.
+    for _ in 0..16 {
+        let mut ctx = Context::from_waker(&old_waker);
+        assert!(Pin::new(&mut recver).poll_next(&mut ctx).is_pending());
+    }
+
+    let mut ctx = Context::from_waker(&new_waker);
+    assert!(Pin::new(&mut recver).poll_next(&mut ctx).is_pending());
+
+    sender.send(1).unwrap();
+
+    assert_eq!(old.get(), 0);
+    assert_eq!(new.get(), 1);
+
+    // The waker is only used once.
+    sender.send(2).unwrap();
+    assert_eq!(new.get(), 1);
+}
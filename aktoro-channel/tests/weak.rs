@@ -0,0 +1,37 @@
+use aktoro_channel::Builder;
+
+#[test]
+fn upgrades_while_a_sender_is_connected() {
+    let (sender, recver) = Builder::new().build();
+    let weak = sender.downgrade();
+
+    let upgraded = weak.upgrade().unwrap();
+    upgraded.try_send(0).unwrap();
+    assert_eq!(recver.try_recv(), Ok(Some(0)));
+
+    // Weak senders aren't counted as
+    // senders.
+    drop(upgraded);
+    assert_eq!(recver.stats().senders, 1);
+
+    drop(sender);
+    assert!(weak.upgrade().is_none());
+    assert!(recver.try_recv().unwrap_err().is_closed());
+}
+
+#[test]
+fn doesnt_upgrade_once_closed() {
+    let (sender, recver) = Builder::new().build::<()>();
+    let weak = sender.downgrade();
+
+    recver.close_channel();
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn disconnected_senders_refuse_messages() {
+    let sender = aktoro_channel::Sender::disconnected();
+
+    assert!(sender.try_send(0).unwrap_err().is_disconnected());
+    assert!(sender.downgrade().upgrade().is_none());
+}
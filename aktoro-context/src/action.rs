@@ -40,12 +40,18 @@ where
 
     fn handle(&mut self, actor: &mut A, ctx: &mut A::Context) -> Result<(), A::Error> {
         // If the action hasn't already been handled,
-        // we do so and return the result.
+        // we do so and return the result (notifying
+        // the sender if the handler failed).
         if let Some(action) = self.action.take() {
-            self.resp
-                .take()
-                .unwrap()
-                .respond(actor.handle(action, ctx)?);
+            let resp = self.resp.take().unwrap();
+
+            match actor.handle(action, ctx) {
+                Ok(out) => resp.respond(out),
+                Err(err) => {
+                    resp.failed();
+                    return Err(err);
+                }
+            }
         }
 
         Ok(())
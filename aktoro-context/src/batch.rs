@@ -0,0 +1,60 @@
+use std::pin::Pin;
+use std::task;
+use std::task::Poll;
+
+use aktoro_channel as channel;
+use futures_core::Stream;
+
+/// A stream yielding the messages received
+/// by a channel's receiver by batches of at
+/// most `max` messages, used by
+/// [`Context::subscribe_batch`].
+///
+/// [`Context::subscribe_batch`]: struct.Context.html#method.subscribe_batch
+pub struct Batched<M> {
+    recver: channel::Receiver<M>,
+    /// The maximum number of messages in
+    /// a batch.
+    max: usize,
+}
+
+impl<M> Batched<M> {
+    /// Creates a new stream yielding the
+    /// messages received by `recver` by
+    /// batches of at most `max` messages.
+    pub fn new(recver: channel::Receiver<M>, max: usize) -> Self {
+        Batched {
+            recver,
+            max: max.max(1),
+        }
+    }
+}
+
+impl<M> Stream for Batched<M> {
+    type Item = Vec<M>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<Vec<M>>> {
+        let batched = self.get_mut();
+
+        // We wait for a first message (which
+        // registers the stream's waker if none
+        // is available)...
+        let msg = match Pin::new(&mut batched.recver).poll_next(ctx) {
+            Poll::Ready(Some(msg)) => msg,
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => return Poll::Pending,
+        };
+
+        // ...and then receive as many of the
+        // available ones as allowed.
+        let mut batch = Vec::with_capacity(batched.max);
+        batch.push(msg);
+
+        batched
+            .recver
+            .try_recv_many(&mut batch, batched.max - 1)
+            .ok();
+
+        Poll::Ready(Some(batch))
+    }
+}
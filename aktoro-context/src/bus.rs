@@ -0,0 +1,173 @@
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task;
+use std::task::Poll;
+
+use aktoro_channel::broadcast;
+use aktoro_raw as raw;
+use futures_core::Stream;
+
+/// The default number of messages kept by
+/// each of an event bus' topics for its slow
+/// subscribers.
+const DEFAULT_CAP: usize = 64;
+
+#[derive(Clone)]
+/// An implementation of [`raw::EventBus`] based
+/// on broadcast channels (one per topic).
+///
+/// Each topic keeps the last `cap` messages
+/// published on it; a subscriber that is too
+/// slow to receive them misses the overwritten
+/// ones (see [`Topic::lagged`]).
+///
+/// ## Note
+///
+/// The bus isn't an actor but a handle shared
+/// by the contexts: subscribing has to return
+/// the topic's stream right away and
+/// publishing would otherwise go through the
+/// bus' mailbox before being broadcast, while
+/// the broadcast channels already allow
+/// publishing and subscribing concurrently.
+///
+/// [`raw::EventBus`]: https://docs.rs/aktoro-raw/*/aktoro_raw/trait.EventBus.html
+/// [`Topic::lagged`]: struct.Topic.html#method.lagged
+pub struct EventBus {
+    /// A map matching the type of a topic's
+    /// messages with its broadcast sender.
+    topics: Arc<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>>,
+    /// The number of messages kept by each
+    /// topic.
+    cap: usize,
+}
+
+/// A stream yielding the messages published
+/// on one of an [`EventBus`]'s topics.
+///
+/// [`EventBus`]: struct.EventBus.html
+pub struct Topic<M> {
+    recver: broadcast::Receiver<M>,
+    /// The number of messages the subscriber
+    /// missed because it was too slow.
+    lagged: u64,
+}
+
+impl EventBus {
+    /// Creates a new event bus whose topics
+    /// keep the last `cap` messages published
+    /// on them.
+    pub fn new(cap: usize) -> Self {
+        EventBus {
+            topics: Arc::new(Mutex::new(HashMap::new())),
+            cap,
+        }
+    }
+
+    /// Subscribes to the messages of type `M`
+    /// published on the bus after the call.
+    pub fn topic<M>(&self) -> Topic<M>
+    where
+        M: Clone + Send + 'static,
+    {
+        let mut topics = self.topics.lock().unwrap();
+
+        let recver = if let Some(sender) = topics
+            .get(&TypeId::of::<M>())
+            .and_then(|sender| sender.downcast_ref::<broadcast::Sender<M>>())
+        {
+            sender.subscribe()
+        } else {
+            let (sender, recver) = broadcast::new(self.cap);
+            topics.insert(TypeId::of::<M>(), Box::new(sender));
+
+            recver
+        };
+
+        Topic { recver, lagged: 0 }
+    }
+
+    /// Returns the number of subscribers of
+    /// the topic of messages of type `M`.
+    pub fn subscribers<M>(&self) -> usize
+    where
+        M: Clone + Send + 'static,
+    {
+        self.topics
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<M>())
+            .and_then(|sender| sender.downcast_ref::<broadcast::Sender<M>>())
+            .map(|sender| sender.receivers())
+            .unwrap_or(0)
+    }
+}
+
+impl<M> Topic<M> {
+    /// Returns the number of messages the
+    /// subscriber missed because it was too
+    /// slow to receive them.
+    pub fn lagged(&self) -> u64 {
+        self.lagged
+    }
+}
+
+impl raw::EventBus for EventBus {
+    fn subscribe<M>(&self) -> raw::Topic<M>
+    where
+        M: Clone + Send + 'static,
+    {
+        Box::pin(self.topic::<M>())
+    }
+
+    fn publish<M>(&self, msg: M) -> usize
+    where
+        M: Clone + Send + 'static,
+    {
+        let topics = self.topics.lock().unwrap();
+
+        if let Some(sender) = topics
+            .get(&TypeId::of::<M>())
+            .and_then(|sender| sender.downcast_ref::<broadcast::Sender<M>>())
+        {
+            sender.send(msg).unwrap_or(0)
+        } else {
+            0
+        }
+    }
+}
+
+impl<M> Stream for Topic<M>
+where
+    M: Clone,
+{
+    type Item = M;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<M>> {
+        let topic = self.get_mut();
+
+        loop {
+            match Pin::new(&mut topic.recver).poll_next(ctx) {
+                Poll::Ready(Some(Ok(msg))) => return Poll::Ready(Some(msg)),
+                Poll::Ready(Some(Err(err))) => {
+                    // We skip the messages the
+                    // subscriber missed.
+                    let missed = err.lagged_by().unwrap_or(0);
+                    topic.lagged += missed;
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        EventBus::new(DEFAULT_CAP)
+    }
+}
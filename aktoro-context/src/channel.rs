@@ -1,6 +1,12 @@
+use std::any;
+use std::any::Any;
+use std::ops::Deref;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::task;
 use std::task::Poll;
+use std::time::Duration;
 
 use aktoro_channel as channel;
 use aktoro_channel::error::TrySendError;
@@ -8,34 +14,118 @@ use aktoro_raw as raw;
 use futures_core::Stream;
 use futures_util::FutureExt;
 
+use crate::mailbox::MailboxConfig;
 use crate::message::Message;
 
+type Mailbox<A> = Box<dyn raw::Message<Actor = A>>;
+
 /// An actor's message channel sender, used by
 /// [`Context`].
 ///
 /// [`Context`]: struct.Context.html
-pub struct Sender<A: raw::Actor>(channel::Sender<Box<dyn raw::Message<Actor = A>>>);
+pub struct Sender<A: raw::Actor> {
+    sender: Handle<A>,
+    /// Where the messages that couldn't be
+    /// sent are reported.
+    dead_letters: DeadLetters,
+}
+
+/// A weak reference to an actor's message
+/// channel, that can be upgraded to a
+/// [`Sender`].
+///
+/// [`Sender`]: struct.Sender.html
+pub struct WeakSender<A: raw::Actor> {
+    sender: channel::WeakSender<Mailbox<A>>,
+    dead_letters: DeadLetters,
+}
+
+/// The handle that a [`Sender`] holds to an
+/// actor's message channel.
+///
+/// [`Sender`]: struct.Sender.html
+enum Handle<A: raw::Actor> {
+    Strong(channel::Sender<Mailbox<A>>),
+    /// Used by the actor's own context when
+    /// the actor should stop once it isn't
+    /// referenced anymore (the handle is then
+    /// upgraded every time it is used).
+    Weak(channel::WeakSender<Mailbox<A>>),
+}
 
 /// An actor's message channel receiver, used
 /// by [`Context`].
 ///
 /// [`Context`]: struct.Context.html
-pub struct Receiver<A: raw::Actor>(channel::Receiver<Box<dyn raw::Message<Actor = A>>>);
+pub struct Receiver<A: raw::Actor> {
+    recver: channel::Receiver<Mailbox<A>>,
+    /// Where the messages that were left in
+    /// the channel when it was closed are
+    /// reported.
+    dead_letters: DeadLetters,
+    /// Boxes a message so that it can be
+    /// passed in a dead letter.
+    boxed: fn(Mailbox<A>) -> Box<dyn Any + Send>,
+}
+
+#[derive(Clone)]
+/// The dead letters sink of an actor, shared
+/// by its message channel's senders and
+/// receiver.
+struct DeadLetters {
+    /// The actor's identifier.
+    actor_id: u64,
+    sink: Arc<Mutex<Option<raw::DeadLetterSink>>>,
+}
+
+/// A sender returned by [`Handle::get`].
+///
+/// [`Handle::get`]: enum.Handle.html#method.get
+enum Upgraded<'s, A: raw::Actor> {
+    Borrowed(&'s channel::Sender<Mailbox<A>>),
+    Owned(channel::Sender<Mailbox<A>>),
+}
 
 /// Creates a new message channel for the
-/// specified actor type, returning a sender
-/// and receiver connected to it.
-pub(crate) fn new<A: raw::Actor>() -> (Sender<A>, Receiver<A>) {
-    // TODO: maybe allow the channel's configuration
-    // to be specified.
-    let (sender, recver) = channel::Builder::new()
-        .unbounded()
-        .unlimited_msgs()
-        .unlimited_senders()
-        .unlimited_receivers()
-        .build();
+/// specified actor type using the provided
+/// configuration, returning a sender and
+/// receiver connected to it.
+pub(crate) fn new<A>(actor_id: u64, config: &MailboxConfig) -> (Sender<A>, Receiver<A>)
+where
+    A: raw::Actor + 'static,
+{
+    let (sender, recver) = config.builder().build();
+
+    let dead_letters = DeadLetters {
+        actor_id,
+        sink: Arc::new(Mutex::new(None)),
+    };
+    let boxed: fn(Mailbox<A>) -> Box<dyn Any + Send> = |msg| Box::new(msg);
+
+    // We report the expired and dropped
+    // messages as soon as the channel
+    // gets rid of them.
+    let expired = dead_letters.clone();
+    recver.on_expired(move |msg| {
+        expired.undelivered(raw::DeadLetterReason::Expired, msg, boxed);
+    });
 
-    (Sender(sender), Receiver(recver))
+    let dropped = dead_letters.clone();
+    recver.on_dropped(move |msg| {
+        dropped.undelivered(raw::DeadLetterReason::Full, msg, boxed);
+    });
+
+    (
+        Sender {
+            sender: Handle::Strong(sender),
+            dead_letters: dead_letters.clone(),
+        },
+        Receiver {
+            recver,
+            dead_letters,
+            boxed,
+        },
+    )
 }
 
 impl<A> raw::Sender<A> for Sender<A>
@@ -44,6 +134,8 @@ where
 {
     type Receiver = Receiver<A>;
 
+    type Weak = WeakSender<A>;
+
     type Error = TrySendError<Box<dyn raw::Message<Actor = A>>>;
 
     fn try_send<M>(&mut self, msg: M) -> raw::SenderRes<A::Output, Self::Error>
@@ -51,11 +143,245 @@ where
         A: raw::Handler<M>,
         M: Send + 'static,
     {
+        let prio = A::priority(&msg);
+        self.try_send_prio(msg, prio)
+    }
+
+    fn try_send_prio<M>(&mut self, msg: M, prio: usize) -> raw::SenderRes<A::Output, Self::Error>
+    where
+        A: raw::Handler<M>,
+        M: Send + 'static,
+    {
+        let (msg, recv) = Message::new(msg);
+
+        self.sender
+            .get()
+            .try_send_prio(Box::new(msg), prio)
+            .map_err(|err| self.dead_letters.failed::<M, _>(err))?;
+
+        Ok(recv.boxed())
+    }
+
+    fn try_send_ttl<M>(&mut self, msg: M, ttl: Duration) -> raw::SenderRes<A::Output, Self::Error>
+    where
+        A: raw::Handler<M>,
+        M: Send + 'static,
+    {
+        let (msg, recv) = Message::new(msg);
+
+        self.sender
+            .get()
+            .try_send_ttl(Box::new(msg), ttl)
+            .map_err(|err| self.dead_letters.failed::<M, _>(err))?;
+
+        Ok(recv.boxed())
+    }
+
+    fn send<M>(&mut self, msg: M) -> raw::SenderFut<A::Output, Self::Error>
+    where
+        A: raw::Handler<M>,
+        M: Send + 'static,
+    {
+        let prio = A::priority(&msg);
+
+        let (msg, recv) = Message::new(msg);
+        let msg: Mailbox<A> = Box::new(msg);
+
+        let dead_letters = self.dead_letters.clone();
+        self.sender
+            .get()
+            .send_prio(msg, prio)
+            .map(move |res| {
+                res.map_err(|err| dead_letters.failed::<M, _>(err))?;
+                Ok(recv.boxed())
+            })
+            .boxed()
+    }
+
+    fn send_blocking<M>(&mut self, msg: M) -> raw::SenderRes<A::Output, Self::Error>
+    where
+        A: raw::Handler<M>,
+        M: Send + 'static,
+    {
+        let prio = A::priority(&msg);
         let (msg, recv) = Message::new(msg);
 
-        self.0.try_send(Box::new(msg))?;
+        self.sender
+            .get()
+            .send_blocking_prio(Box::new(msg), prio)
+            .map_err(|err| self.dead_letters.failed::<M, _>(err))?;
+
+        Ok(recv.boxed())
+    }
+
+    fn stats(&self) -> raw::MailboxStats {
+        let stats = self.stats();
+
+        raw::MailboxStats {
+            len: stats.len,
+            capacity: stats.capacity,
+            sent: stats.sent,
+            received: stats.received,
+            dropped: stats.dropped,
+            expired: stats.expired,
+            senders: stats.senders,
+            closed: stats.closed,
+        }
+    }
+
+    fn downgrade(&self) -> WeakSender<A> {
+        WeakSender {
+            sender: match &self.sender {
+                Handle::Strong(sender) => sender.downgrade(),
+                Handle::Weak(sender) => sender.clone(),
+            },
+            dead_letters: self.dead_letters.clone(),
+        }
+    }
+
+    fn upgrade(weak: &WeakSender<A>) -> Option<Self> {
+        Some(Sender {
+            sender: Handle::Strong(weak.sender.upgrade()?),
+            dead_letters: weak.dead_letters.clone(),
+        })
+    }
+}
+
+impl<A: raw::Actor> Sender<A> {
+    /// Returns the number of messages that
+    /// were dropped because the actor's
+    /// message channel was full (depending
+    /// on its overflow policy).
+    pub fn dropped(&self) -> usize {
+        self.sender.get().dropped()
+    }
+
+    /// Returns a snapshot of the state of the
+    /// actor's message channel.
+    pub fn stats(&self) -> channel::ChannelStats {
+        self.sender.get().stats()
+    }
+
+    /// Makes the sender only hold a weak
+    /// reference to the actor's message
+    /// channel, so that it doesn't keep it
+    /// open.
+    pub(crate) fn weaken(&mut self) {
+        if let Handle::Strong(sender) = &self.sender {
+            self.sender = Handle::Weak(sender.downgrade());
+        }
+    }
+
+    /// Sets the sink that the messages that
+    /// couldn't be delivered to the actor are
+    /// reported to.
+    pub(crate) fn set_dead_letters(&self, sink: raw::DeadLetterSink) {
+        *self.dead_letters.sink.lock().unwrap() = Some(sink);
+    }
+
+    /// Returns the sink that the messages that
+    /// couldn't be delivered to the actor are
+    /// reported to (if any).
+    pub(crate) fn dead_letters(&self) -> Option<raw::DeadLetterSink> {
+        self.dead_letters.sink.lock().unwrap().clone()
+    }
+}
+
+impl<A: raw::Actor> Handle<A> {
+    /// Returns a sender connected to the
+    /// actor's message channel, upgrading the
+    /// handle if it is weak (the returned
+    /// sender is disconnected if this fails).
+    fn get(&self) -> Upgraded<A> {
+        match self {
+            Handle::Strong(sender) => Upgraded::Borrowed(sender),
+            Handle::Weak(sender) => Upgraded::Owned(
+                sender
+                    .upgrade()
+                    .unwrap_or_else(channel::Sender::disconnected),
+            ),
+        }
+    }
+}
+
+impl<'s, A: raw::Actor> Deref for Upgraded<'s, A> {
+    type Target = channel::Sender<Mailbox<A>>;
 
-        Ok(recv.map(Ok).boxed())
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Upgraded::Borrowed(sender) => sender,
+            Upgraded::Owned(sender) => sender,
+        }
+    }
+}
+
+impl DeadLetters {
+    /// Reports a message that couldn't be
+    /// delivered to the actor.
+    fn report(
+        &self,
+        reason: raw::DeadLetterReason,
+        type_name: &'static str,
+        msg: Option<Box<dyn Any + Send>>,
+    ) {
+        if let Some(sink) = &*self.sink.lock().unwrap() {
+            sink.send(raw::DeadLetter::new(self.actor_id, reason, type_name, msg));
+        }
+    }
+
+    /// Reports a message of type `M` that
+    /// couldn't be sent to the actor (the
+    /// message itself is handed back to the
+    /// sender in the error), returning the
+    /// error.
+    fn failed<M, T>(&self, err: TrySendError<T>) -> TrySendError<T> {
+        let reason = if err.is_full() {
+            raw::DeadLetterReason::Full
+        } else if err.is_limit() {
+            raw::DeadLetterReason::Limit
+        } else if err.is_closed() {
+            raw::DeadLetterReason::Closed
+        } else {
+            raw::DeadLetterReason::Disconnected
+        };
+
+        self.report(reason, any::type_name::<M>(), None);
+
+        err
+    }
+
+    /// Reports a message that was sent to
+    /// the actor but won't be delivered,
+    /// using `boxed` to pass it in the
+    /// dead letter.
+    fn undelivered<A: raw::Actor>(
+        &self,
+        reason: raw::DeadLetterReason,
+        mut msg: Mailbox<A>,
+        boxed: fn(Mailbox<A>) -> Box<dyn Any + Send>,
+    ) {
+        // We notify the sender that the
+        // message won't be handled, since
+        // the dead letter could be kept
+        // around for a while.
+        msg.undelivered();
+
+        let type_name = msg.type_name();
+        self.report(reason, type_name, Some(boxed(msg)));
+    }
+}
+
+impl<A: raw::Actor> Receiver<A> {
+    /// Closes the channel and drops the messages
+    /// that haven't been received yet, notifying
+    /// whatever is waiting for them to be handled
+    /// that the actor is dead.
+    pub(crate) fn close(&mut self) {
+        self.recver.close_channel();
+        while let Ok(Some(msg)) = self.recver.try_recv() {
+            self.dead_letters
+                .undelivered(raw::DeadLetterReason::Dead, msg, self.boxed);
+        }
     }
 }
 
@@ -68,7 +394,7 @@ where
     type Item = Box<dyn raw::Message<Actor = A>>;
 
     fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<Self::Item>> {
-        Pin::new(&mut self.get_mut().0).poll_next(ctx)
+        Pin::new(&mut self.get_mut().recver).poll_next(ctx)
     }
 }
 
@@ -77,6 +403,33 @@ where
     A: raw::Actor,
 {
     fn clone(&self) -> Self {
-        Sender(self.0.try_clone().unwrap())
+        let sender = match &self.sender {
+            // We never limit the senders of the
+            // actors' channels, so this only fails
+            // if the sender is disconnected.
+            Handle::Strong(sender) => sender
+                .try_clone()
+                .unwrap_or_else(|_| channel::Sender::disconnected()),
+            Handle::Weak(sender) => sender
+                .upgrade()
+                .unwrap_or_else(channel::Sender::disconnected),
+        };
+
+        Sender {
+            sender: Handle::Strong(sender),
+            dead_letters: self.dead_letters.clone(),
+        }
+    }
+}
+
+impl<A> Clone for WeakSender<A>
+where
+    A: raw::Actor,
+{
+    fn clone(&self) -> Self {
+        WeakSender {
+            sender: self.sender.clone(),
+            dead_letters: self.dead_letters.clone(),
+        }
     }
 }
@@ -1,19 +1,27 @@
+use std::any::Any;
 use std::collections::VecDeque;
 use std::future::Future;
 use std::pin::Pin;
 use std::task;
 use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
 
 use aktoro_channel::error::TrySendError;
+use aktoro_channel::oneshot;
+use aktoro_channel::Builder;
 use aktoro_channel::Notify;
 use aktoro_raw as raw;
+use aktoro_raw::Timer as RawTimer;
 use aktoro_raw::Updater as RawUpdater;
 use aktoro_raw::Wait as RawWait;
 use futures_core::Stream;
 use futures_io as io;
 use futures_io::AsyncRead;
 use futures_io::AsyncWrite;
+use futures_util::FutureExt;
 
+use crate::batch::Batched;
 use crate::channel;
 use crate::channel::Receiver;
 use crate::channel::Sender;
@@ -21,7 +29,10 @@ use crate::control;
 use crate::control::Controlled;
 use crate::control::Controller;
 use crate::event::Event;
+use crate::mailbox::MailboxConfig;
+use crate::message::AsyncMessage;
 use crate::message::AsyncMessageFut;
+use crate::message::AsyncMessageFutFilterMap;
 use crate::message::AsyncMessageFutMap;
 use crate::message::AsyncMessageStream;
 use crate::message::AsyncReadStream;
@@ -31,17 +42,35 @@ use crate::update::Update;
 use crate::update::Updated;
 use crate::update::Updater;
 
-/// The configuration that is used by [`Context`].
+/// The configuration that is used by [`Context`],
+/// allowing to configure the actor's message,
+/// control and update channels (e.g. to make them
+/// bounded).
 ///
-/// ## Note
-///
-/// This is only used when spawning sub-actors and
-/// shoudln't be used elsewhere.
+/// [`Context`]: struct.Context.html
 pub struct ContextConfig {
     /// Whether the context should wait to get
     /// notified before starting to handle
     /// messages, events, etc.
     ready: Option<Notify>,
+    /// The configuration of the actor's
+    /// message channel.
+    messages: MailboxConfig,
+    /// The configuration of the actor's
+    /// control channel.
+    actions: MailboxConfig,
+    /// The configuration of the actor's
+    /// update channel, or `None` if it
+    /// should be a watch channel.
+    updates: Option<MailboxConfig>,
+    /// Whether the actor should stop once
+    /// it isn't referenced anymore.
+    unreferenced: bool,
+    /// The maximum number of actions, events
+    /// and messages handled by the actor each
+    /// time it is polled (if it overrides the
+    /// runtime's).
+    budget: Option<usize>,
 }
 
 /// An actor context using the [`aktoro-channel`] crate.
@@ -82,11 +111,56 @@ pub struct Context<A: raw::Actor, R: raw::Runtime> {
     /// An eventual inner runtime that the context
     /// can use to run/spawn sub-actors.
     rt: Option<R>,
+    /// The spawner that the inner runtime
+    /// should spawn the sub-actors on (if it
+    /// shouldn't use its default one).
+    spawner: Option<raw::Spawner>,
+    /// The spawner that the closures passed to
+    /// `spawn_blocking` are run on (and that is
+    /// passed to the inner runtime).
+    blocking: Option<raw::BlockingSpawner>,
+    /// The runtime's timer, used to send messages
+    /// to the actor after a delay or at a fixed
+    /// interval (and passed to the inner runtime).
+    timer: Option<R::Timer>,
+    /// The supervision state of the actor, used to
+    /// decide which sub-actors to restart when one
+    /// of them fails.
+    supervisor: raw::Supervisor,
+    /// The table used by the actor to watch
+    /// other actors (and to be watched),
+    /// shared with the runtime.
+    monitors: raw::Monitors,
+    /// The registry used by the actor to
+    /// look up (and register) other actors,
+    /// shared with the runtime.
+    registry: raw::Registry,
+    /// A function creating a message from a
+    /// `Down` notification, set when the actor
+    /// starts monitoring an actor.
+    down: Option<fn(raw::Down<R::Error>) -> Box<dyn raw::Message<Actor = A>>>,
+    /// A receiver notified when an actor that
+    /// the actor watches dies, along with
+    /// whether it was linked to it.
+    downs: aktoro_channel::Receiver<(raw::Down<Box<dyn Any + Send + Sync>>, bool)>,
+    /// The identifier of the linked actor that
+    /// failed, if the actor should fail too
+    /// (once it handled its `Down` message).
+    failing: Option<u64>,
     /// A list of contexts that should be notified
     /// when all blocking futures have been handled.
     to_notify: Vec<Notify>,
     /// A list of the actor's unhandled events.
     events: VecDeque<Box<dyn raw::Event<Actor = A>>>,
+    /// The maximum number of actions, events
+    /// and messages handled by the actor each
+    /// time it is polled (if it overrides the
+    /// runtime's).
+    budget: Option<usize>,
+    /// Whether the context should only hold
+    /// a weak reference to the actor's message
+    /// channel once it starts being polled.
+    weaken: bool,
     /// An actor's message channel sender.
     sender: Sender<A>,
     /// An actor's message channel receiver.
@@ -108,12 +182,21 @@ where
     type Sender = Sender<A>;
     type Updater = Updater<A>;
 
+    type RuntimeError = RT::Error;
+
+    type Timer = RT::Timer;
+
     fn new(actor_id: u64, config: ContextConfig) -> Context<A, RT> {
         // We create the actor's control, message and
         // update channels.
-        let (ctrler, ctrled) = control::new();
-        let (sender, recver) = channel::new();
-        let (updter, updted) = update::new();
+        let (ctrler, ctrled) = control::new(&config.actions);
+        let (sender, recver) = channel::new(actor_id, &config.messages);
+        let (updter, updted) = update::new(config.updates.as_ref());
+
+        // We add the actor to its own table until
+        // the runtime provides the shared one.
+        let monitors = raw::Monitors::new();
+        let downs = watching(actor_id, &monitors);
 
         Context {
             actor_id,
@@ -127,8 +210,19 @@ where
             streams: vec![],
             reads: vec![],
             rt: None,
+            spawner: None,
+            blocking: None,
+            timer: None,
+            supervisor: raw::Supervisor::default(),
+            monitors,
+            registry: raw::Registry::new(),
+            down: None,
+            downs,
+            failing: None,
             to_notify: vec![],
             events: VecDeque::new(),
+            budget: config.budget,
+            weaken: config.unreferenced,
             sender,
             recver,
             updter,
@@ -193,6 +287,59 @@ where
         &mut self.updter
     }
 
+    fn set_dead_letters(&mut self, sink: raw::DeadLetterSink) {
+        if let Some(rt) = &mut self.rt {
+            rt.set_dead_letters(sink.clone());
+        }
+
+        self.sender.set_dead_letters(sink);
+    }
+
+    fn set_spawner(&mut self, spawner: raw::Spawner) {
+        if let Some(rt) = &mut self.rt {
+            rt.set_spawner(spawner.clone());
+        }
+
+        self.spawner = Some(spawner);
+    }
+
+    fn set_blocking_spawner(&mut self, spawner: raw::BlockingSpawner) {
+        if let Some(rt) = &mut self.rt {
+            rt.set_blocking_spawner(spawner.clone());
+        }
+
+        self.blocking = Some(spawner);
+    }
+
+    fn set_monitors(&mut self, monitors: raw::Monitors) {
+        if let Some(rt) = &mut self.rt {
+            rt.set_monitors(monitors.clone());
+        }
+
+        self.downs = watching(self.actor_id, &monitors);
+        self.monitors = monitors;
+    }
+
+    fn set_registry(&mut self, registry: raw::Registry) {
+        if let Some(rt) = &mut self.rt {
+            rt.set_registry(registry.clone());
+        }
+
+        self.registry = registry;
+    }
+
+    fn set_timer(&mut self, timer: RT::Timer) {
+        if let Some(rt) = &mut self.rt {
+            rt.set_timer(timer.clone());
+        }
+
+        self.timer = Some(timer);
+    }
+
+    fn budget(&self) -> Option<usize> {
+        self.budget
+    }
+
     fn actors(&self) -> Vec<u64> {
         if let Some(rt) = &self.rt {
             rt.actors()
@@ -203,27 +350,176 @@ where
 
     fn spawn<S, C>(&mut self, actor: S) -> Option<raw::Spawned<S>>
     where
-        S: raw::Actor<Context = C> + 'static,
-        C: raw::Context<S, Config = ContextConfig>,
+        S: raw::Actor<Context = C> + Send + 'static,
+        C: raw::Context<S, Config = ContextConfig, Timer = RT::Timer> + Send,
     {
-        let rt = if let Some(rt) = &mut self.rt {
-            rt
-        } else {
-            self.rt = Some(RT::default());
-            self.rt.as_mut().unwrap()
-        };
+        self.spawn_with(actor, ContextConfig::default())
+    }
 
-        let mut config = ContextConfig::default();
+    fn spawn_with<S, C>(&mut self, actor: S, config: ContextConfig) -> Option<raw::Spawned<S>>
+    where
+        S: raw::Actor<Context = C> + Send + 'static,
+        C: raw::Context<S, Config = ContextConfig, Timer = RT::Timer> + Send,
+    {
+        let (notify, config) = config.notified();
 
-        let (notify, ready) = Notify::new();
-        config.ready = Some(ready);
+        let spawned = self.rt().spawn_with(actor, config)?;
 
-        if let Some(spawned) = rt.spawn_with(actor, config) {
-            self.to_notify.push(notify);
-            Some(spawned)
-        } else {
-            None
+        self.to_notify.push(notify);
+        self.supervisor.supervise(spawned.actor_id(), false);
+
+        Some(spawned)
+    }
+
+    fn spawn_restartable<S, C, F>(&mut self, factory: F) -> Option<raw::Spawned<S>>
+    where
+        S: raw::Actor<Context = C> + Send + 'static,
+        C: raw::Context<S, Config = ContextConfig, Timer = RT::Timer> + Send,
+        F: raw::Factory<S>,
+    {
+        self.spawn_restartable_with(factory, ContextConfig::default())
+    }
+
+    fn spawn_restartable_with<S, C, F>(
+        &mut self,
+        factory: F,
+        config: ContextConfig,
+    ) -> Option<raw::Spawned<S>>
+    where
+        S: raw::Actor<Context = C> + Send + 'static,
+        C: raw::Context<S, Config = ContextConfig, Timer = RT::Timer> + Send,
+        F: raw::Factory<S>,
+    {
+        let (notify, config) = config.notified();
+
+        let spawned = self.rt().spawn_restartable_with(factory, config)?;
+
+        self.to_notify.push(notify);
+        self.supervisor.supervise(spawned.actor_id(), true);
+
+        Some(spawned)
+    }
+
+    fn set_strategy(&mut self, strategy: raw::Strategy) {
+        self.supervisor.set_strategy(strategy);
+    }
+
+    fn set_intensity(&mut self, intensity: raw::Intensity) {
+        self.supervisor.set_intensity(intensity);
+    }
+
+    fn reset(&mut self) {
+        // We stop the sub-actors...
+        if let Some(mut rt) = self.rt.take() {
+            rt.stop();
         }
+
+        self.supervisor.clear();
+        self.monitors.unwatch_all(self.actor_id);
+
+        // ...and drop everything that the previous
+        // actor asked the context to handle.
+        self.update = false;
+        self.b_futs.clear();
+        self.futs.clear();
+        self.streams.clear();
+        self.reads.clear();
+        self.to_notify.clear();
+        self.events.clear();
+        while let Ok(Some(_)) = self.downs.try_recv() {}
+        self.failing = None;
+    }
+
+    fn monitor(&mut self, id: u64) -> bool
+    where
+        A: raw::Handler<raw::Down<RT::Error>, Output = ()>,
+    {
+        self.watch(id, false)
+    }
+
+    fn link(&mut self, id: u64) -> bool
+    where
+        A: raw::Handler<raw::Down<RT::Error>, Output = ()>,
+    {
+        self.watch(id, true)
+    }
+
+    fn demonitor(&mut self, id: u64) {
+        self.monitors.unwatch(self.actor_id, id);
+    }
+
+    fn register<S>(
+        &mut self,
+        name: &str,
+        spawned: &raw::Spawned<S>,
+    ) -> Result<(), raw::RegistryError>
+    where
+        S: raw::Actor + 'static,
+    {
+        // We know that the actor is running if
+        // it is in the table shared by the
+        // runtime's actors.
+        if !self.monitors.contains(spawned.actor_id()) {
+            return Err(raw::RegistryError::unknown(name.to_string()));
+        }
+
+        self.registry.register(name, spawned)
+    }
+
+    fn lookup<S>(&self, name: &str) -> Option<raw::Spawned<S>>
+    where
+        S: raw::Actor + 'static,
+    {
+        self.registry.lookup(name)
+    }
+
+    fn unregister(&mut self, name: &str) -> Option<u64> {
+        self.registry.unregister(name)
+    }
+
+    fn send_later<M>(&mut self, msg: M, delay: Duration) -> raw::Cancellable<raw::Delayed<M>>
+    where
+        A: raw::Handler<M, Output = ()>,
+        M: Send + 'static,
+    {
+        let delayed = raw::Delayed::new(self.timer().delay(delay), msg);
+        let (cancellable, inner) = raw::Cancellable::new(Box::pin(delayed));
+
+        self.futs.push(Box::pin(AsyncMessageFutMap::new(inner, |msg: M| msg)));
+
+        cancellable
+    }
+
+    fn run_at<M>(&mut self, msg: M, at: Instant) -> raw::Cancellable<raw::Delayed<M>>
+    where
+        A: raw::Handler<M, Output = ()>,
+        M: Send + 'static,
+    {
+        let delayed = raw::Delayed::new(self.timer().delay_until(at), msg);
+        let (cancellable, inner) = raw::Cancellable::new(Box::pin(delayed));
+
+        self.futs.push(Box::pin(AsyncMessageFutMap::new(inner, |msg: M| msg)));
+
+        cancellable
+    }
+
+    fn run_interval<F, M>(
+        &mut self,
+        interval: Duration,
+        make: F,
+    ) -> raw::Cancellable<raw::Repeated<F>>
+    where
+        F: Fn() -> M + Unpin + Send + Sync + 'static,
+        A: raw::Handler<M, Output = ()>,
+        M: Send + 'static,
+    {
+        let repeated = raw::Repeated::new(self.timer().interval(interval), make);
+        let (cancellable, inner) = raw::Cancellable::new(Box::pin(repeated));
+
+        self.streams
+            .push(Box::pin(AsyncMessageStream::new(inner, |msg: M| msg)));
+
+        cancellable
     }
 
     fn wait<F, M, O, T>(&mut self, fut: Pin<Box<F>>, map: M) -> raw::Cancellable<F>
@@ -280,6 +576,32 @@ where
         cancellable
     }
 
+    fn spawn_blocking<F, M, O, T>(&mut self, f: F, map: M) -> raw::Cancellable<raw::Blocking<O>>
+    where
+        F: FnOnce() -> O + Send + 'static,
+        M: Fn(O) -> T + Unpin + Send + Sync + 'static,
+        A: raw::Handler<T, Output = ()>,
+        O: Send + 'static,
+        T: Send + 'static,
+    {
+        let (sender, recver) = oneshot::new();
+        let run = move || {
+            let _ = sender.send(f());
+        };
+
+        self.blocking_spawner().spawn(run);
+
+        // The sender is dropped without sending
+        // anything if the closure panics, in which
+        // case nothing is passed to the actor.
+        let blocking: raw::Blocking<O> = Box::pin(recver.map(Result::ok));
+        let (cancellable, inner) = raw::Cancellable::new(Box::pin(blocking));
+
+        self.futs.push(Box::pin(AsyncMessageFutFilterMap::new(inner, map)));
+
+        cancellable
+    }
+
     fn subscribe<S, M, I, T>(&mut self, stream: Pin<Box<S>>, map: M) -> raw::Cancellable<S>
     where
         S: Stream<Item = I> + Unpin + Send + 'static,
@@ -368,7 +690,7 @@ where
 
 impl<A, R> Stream for Context<A, R>
 where
-    A: raw::Actor,
+    A: raw::Actor + 'static,
     R: raw::Runtime,
 {
     type Item = raw::Work<A>;
@@ -377,6 +699,15 @@ where
         let context = self.get_mut();
         let mut ret = None;
 
+        // If the actor should stop once it isn't
+        // referenced anymore, we stop keeping its
+        // message channel open (its `Spawned` has
+        // already been created at this point).
+        if context.weaken {
+            context.weaken = false;
+            context.sender.weaken();
+        }
+
         // If the context hasn't been marked as being
         // ready yet, we try to see if it should be now.
         if let Some(ready) = context.ready.as_mut() {
@@ -452,6 +783,40 @@ where
             return Poll::Ready(Some(raw::Work::Event(event)));
         }
 
+        // If the actor handled the `Down` message
+        // sent when an actor it was linked to
+        // failed, we make it fail too.
+        if let Some(id) = context.failing.take() {
+            return Poll::Ready(Some(raw::Work::Fail(raw::SupervisionError::linked(id))));
+        }
+
+        // If actors that the actor watches died,
+        // we ask the runtime to make the actor
+        // handle a `Down` message if it can (and
+        // to make it fail after doing so if it
+        // was linked to an actor that failed).
+        while let Poll::Ready(Some((down, link))) = Pin::new(&mut context.downs).poll_next(ctx) {
+            let id = down.id();
+            if link && down.is_failure() {
+                context.failing = Some(id);
+            }
+
+            if let Some(make) = context.down {
+                // The reason is the error returned
+                // by the runtime, boxed.
+                let reason = down
+                    .into_reason()
+                    .and_then(|reason| (reason as Box<dyn Any + Send>).downcast().ok())
+                    .map(|reason| *reason);
+
+                return Poll::Ready(Some(raw::Work::Message(make(raw::Down::new(id, reason)))));
+            }
+
+            if let Some(id) = context.failing.take() {
+                return Poll::Ready(Some(raw::Work::Fail(raw::SupervisionError::linked(id))));
+            }
+        }
+
         // If a message has been received from the actor's
         // message channel, we ask the runtime to make
         // the runtime handle it.
@@ -466,6 +831,7 @@ where
         // We poll the inner runtime if there is one.
         if let Some(rt) = context.rt.take() {
             let mut wait = rt.wait();
+            let mut failed = vec![];
 
             // We poll until there is either...
             loop {
@@ -475,13 +841,48 @@ where
                 }
 
                 // ...the runtime is waiting for them
-                // to yield.
-                if let Poll::Pending = Pin::new(&mut wait).poll_next(ctx) {
-                    break;
+                // to yield (saving the sub-actors that
+                // stopped or failed in the meantime).
+                match Pin::new(&mut wait).poll_next(ctx) {
+                    Poll::Ready(Some(Ok(id))) => context.supervisor.remove(id),
+                    Poll::Ready(Some(Err((id, _)))) => failed.push(id),
+                    Poll::Ready(None) | Poll::Pending => break,
                 }
             }
 
-            context.rt = Some(wait.into_runtime());
+            let mut rt = wait.into_runtime();
+
+            // We ask the supervisor what to do with
+            // the sub-actors that failed (the actors
+            // watching the ones that died have been
+            // notified by the runtime).
+            for id in failed {
+                match context.supervisor.failed(id) {
+                    // We restart the sub-actors that
+                    // need to be...
+                    raw::Directive::Restart(ids) => {
+                        for id in ids {
+                            rt.restart(id);
+                        }
+                    }
+                    // ...or stop all of them and make
+                    // the actor fail if too many
+                    // restarts happened (so that its
+                    // own supervisor is notified).
+                    raw::Directive::Escalate => {
+                        rt.stop();
+                        context.supervisor.clear();
+
+                        context.rt = Some(rt);
+
+                        return Poll::Ready(Some(raw::Work::Fail(
+                            raw::SupervisionError::escalated(id),
+                        )));
+                    }
+                }
+            }
+
+            context.rt = Some(rt);
         }
 
         // We poll all the futures that the context
@@ -558,9 +959,212 @@ where
     }
 }
 
+impl ContextConfig {
+    /// Creates a new config with unbounded
+    /// channels without any limit.
+    pub fn new() -> Self {
+        ContextConfig::default()
+    }
+
+    /// Sets the configuration of the actor's
+    /// message channel.
+    pub fn messages(mut self, config: MailboxConfig) -> Self {
+        self.messages = config;
+        self
+    }
+
+    /// Sets the configuration of the actor's
+    /// control channel.
+    pub fn actions(mut self, config: MailboxConfig) -> Self {
+        self.actions = config;
+        self
+    }
+
+    /// Sets the configuration of the actor's
+    /// update channel.
+    ///
+    /// By default, the update channel is a
+    /// watch channel only keeping the actor's
+    /// latest status. Once configured, it
+    /// passes every status update instead.
+    pub fn updates(mut self, config: MailboxConfig) -> Self {
+        self.updates = Some(config);
+        self
+    }
+
+    /// Makes the actor stop once all the strong
+    /// handles to its message channel (e.g. its
+    /// `Spawned` and its clones) have been
+    /// dropped, instead of running until it is
+    /// stopped or killed.
+    pub fn stop_when_unreferenced(mut self) -> Self {
+        self.unreferenced = true;
+        self
+    }
+
+    /// Sets the maximum number of actions,
+    /// events and messages that the actor
+    /// handles each time it is polled before
+    /// yielding to the other tasks (overriding
+    /// the runtime's).
+    pub fn budget(mut self, budget: usize) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Makes the config usable for a
+    /// sub-actor's context, which will wait
+    /// to get notified before starting to
+    /// handle messages, events, etc.
+    fn notified(mut self) -> (Notify, ContextConfig) {
+        let (notify, ready) = Notify::new();
+        self.ready = Some(ready);
+
+        (notify, self)
+    }
+}
+
+impl<A, R> Context<A, R>
+where
+    A: raw::Actor + 'static,
+    R: raw::Runtime,
+{
+    /// Forwards the messages received by
+    /// `recver` to the actor by batches of at
+    /// most `max` messages, which are handled
+    /// by its [`BatchHandler`] implementation
+    /// in one call.
+    ///
+    /// The execution can be cancelled using
+    /// the returned [`Cancellable`].
+    ///
+    /// [`BatchHandler`]: https://docs.rs/aktoro-raw/*/aktoro_raw/trait.BatchHandler.html
+    /// [`Cancellable`]: https://docs.rs/aktoro-raw/*/aktoro_raw/struct.Cancellable.html
+    pub fn subscribe_batch<M>(
+        &mut self,
+        recver: aktoro_channel::Receiver<M>,
+        max: usize,
+    ) -> raw::Cancellable<Batched<M>>
+    where
+        A: raw::BatchHandler<M>,
+        M: Send + 'static,
+    {
+        raw::Context::subscribe(self, Box::pin(Batched::new(recver, max)), raw::Batch)
+    }
+
+    /// Starts monitoring (or being linked to)
+    /// the actor with the given identifier,
+    /// returning whether it is running.
+    fn watch(&mut self, id: u64, link: bool) -> bool
+    where
+        A: raw::Handler<raw::Down<R::Error>, Output = ()>,
+    {
+        if self.down.is_none() {
+            self.down = Some(|down| Box::new(AsyncMessage::new(down)));
+        }
+
+        self.monitors.watch(self.actor_id, id, link)
+    }
+
+    /// Returns the context's inner runtime,
+    /// creating it if it doesn't exist yet.
+    fn rt(&mut self) -> &mut R {
+        if self.rt.is_none() {
+            let mut rt = R::default();
+
+            // The sub-actors report their dead
+            // letters to the same sink as the
+            // actor.
+            if let Some(sink) = self.sender.dead_letters() {
+                rt.set_dead_letters(sink);
+            }
+
+            // The sub-actors are spawned on the
+            // same executor as the actor.
+            if let Some(spawner) = &self.spawner {
+                rt.set_spawner(spawner.clone());
+            }
+
+            // They also share its blocking pool.
+            if let Some(blocking) = &self.blocking {
+                rt.set_blocking_spawner(blocking.clone());
+            }
+
+            // They can watch (and be watched by)
+            // the same actors as the actor.
+            rt.set_monitors(self.monitors.clone());
+
+            // And look up the same actors.
+            rt.set_registry(self.registry.clone());
+
+            // They use the same timer.
+            if let Some(timer) = &self.timer {
+                rt.set_timer(timer.clone());
+            }
+
+            self.rt = Some(rt);
+        }
+
+        self.rt.as_mut().unwrap()
+    }
+
+    /// Returns the spawner that the blocking
+    /// closures are run on, getting the inner
+    /// runtime's if the runtime didn't give
+    /// us one.
+    fn blocking_spawner(&mut self) -> &raw::BlockingSpawner {
+        if self.blocking.is_none() {
+            self.blocking = Some(self.rt().blocking_spawner());
+        }
+
+        self.blocking.as_ref().unwrap()
+    }
+
+    /// Returns the runtime's timer, getting
+    /// it from the inner runtime if the context
+    /// wasn't given one (because it wasn't
+    /// spawned by a runtime).
+    fn timer(&mut self) -> &R::Timer {
+        if self.timer.is_none() {
+            self.timer = Some(self.rt().timer());
+        }
+
+        self.timer.as_ref().unwrap()
+    }
+}
+
+/// Adds the actor with the given identifier
+/// to `monitors`, returning a receiver that
+/// is notified when one of the actors it
+/// watches dies.
+fn watching(
+    actor_id: u64,
+    monitors: &raw::Monitors,
+) -> aktoro_channel::Receiver<(raw::Down<Box<dyn Any + Send + Sync>>, bool)> {
+    let (sender, recver) = Builder::new()
+        .unbounded()
+        .unlimited_msgs()
+        .unlimited_senders()
+        .unlimited_receivers()
+        .build();
+
+    monitors.register(actor_id, move |down, link| {
+        sender.try_send((down, link)).ok();
+    });
+
+    recver
+}
+
 impl Default for ContextConfig {
     fn default() -> Self {
-        ContextConfig { ready: None }
+        ContextConfig {
+            ready: None,
+            messages: MailboxConfig::default(),
+            actions: MailboxConfig::default(),
+            updates: None,
+            unreferenced: false,
+            budget: None,
+        }
     }
 }
 
@@ -573,5 +1177,11 @@ where
         if let Some(rt) = &mut self.rt {
             rt.stop();
         }
+
+        // We close the actor's control and message
+        // channels, dropping the actions and messages
+        // that won't be handled.
+        self.ctrled.close();
+        self.recver.close();
     }
 }
@@ -9,6 +9,7 @@ use futures_core::Stream;
 use futures_util::FutureExt;
 
 use crate::action::Action;
+use crate::mailbox::MailboxConfig;
 
 /// An actor's control channel sender, used
 /// by [`Context`].
@@ -16,6 +17,13 @@ use crate::action::Action;
 /// [`Context`]: struct.Context.html
 pub struct Controller<A: raw::Actor>(channel::Sender<Box<dyn raw::Action<Actor = A>>>);
 
+/// A weak reference to an actor's control
+/// channel, that can be upgraded to a
+/// [`Controller`].
+///
+/// [`Controller`]: struct.Controller.html
+pub struct WeakController<A: raw::Actor>(channel::WeakSender<Box<dyn raw::Action<Actor = A>>>);
+
 /// An actor's control channel receiver,
 /// used by [`Context`].
 ///
@@ -23,17 +31,11 @@ pub struct Controller<A: raw::Actor>(channel::Sender<Box<dyn raw::Action<Actor =
 pub struct Controlled<A: raw::Actor>(channel::Receiver<Box<dyn raw::Action<Actor = A>>>);
 
 /// Creates a new control channel for the
-/// specified actor type, returning a sender
-/// and receiver connected to it.
-pub(crate) fn new<A: raw::Actor>() -> (Controller<A>, Controlled<A>) {
-    // TODO: maybe allow the channel's configuration
-    // to be specified.
-    let (sender, recver) = channel::Builder::new()
-        .unbounded()
-        .unlimited_msgs()
-        .unlimited_senders()
-        .unlimited_receivers()
-        .build();
+/// specified actor type using the provided
+/// configuration, returning a sender and
+/// receiver connected to it.
+pub(crate) fn new<A: raw::Actor>(config: &MailboxConfig) -> (Controller<A>, Controlled<A>) {
+    let (sender, recver) = config.builder().build();
 
     (Controller(sender), Controlled(recver))
 }
@@ -46,6 +48,8 @@ where
 
     type Error = TrySendError<Box<dyn raw::Action<Actor = A>>>;
 
+    type Weak = WeakController<A>;
+
     fn try_send<D>(&mut self, action: D) -> raw::ControllerRes<A::Output, Self::Error>
     where
         A: raw::ActionHandler<D>,
@@ -55,7 +59,26 @@ where
 
         self.0.try_send(Box::new(action))?;
 
-        Ok(recv.map(Ok).boxed())
+        Ok(recv.boxed())
+    }
+
+    fn downgrade(&self) -> WeakController<A> {
+        WeakController(self.0.downgrade())
+    }
+
+    fn upgrade(weak: &WeakController<A>) -> Option<Self> {
+        Some(Controller(weak.0.upgrade()?))
+    }
+}
+
+impl<A: raw::Actor> Controlled<A> {
+    /// Closes the channel and drops the actions
+    /// that haven't been received yet, notifying
+    /// whatever is waiting for them to be handled
+    /// that the actor is dead.
+    pub(crate) fn close(&mut self) {
+        self.0.close_channel();
+        while let Ok(Some(_)) = self.0.try_recv() {}
     }
 }
 
@@ -80,6 +103,19 @@ where
     A: raw::Actor,
 {
     fn clone(&self) -> Self {
-        Controller(self.0.try_clone().unwrap())
+        Controller(
+            self.0
+                .try_clone()
+                .unwrap_or_else(|_| channel::Sender::disconnected()),
+        )
+    }
+}
+
+impl<A> Clone for WeakController<A>
+where
+    A: raw::Actor,
+{
+    fn clone(&self) -> Self {
+        WeakController(self.0.clone())
     }
 }
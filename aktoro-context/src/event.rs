@@ -10,7 +10,7 @@ where
     E: Send + 'static,
 {
     event: Option<E>,
-    _act: PhantomData<A>,
+    _act: PhantomData<fn() -> A>,
 }
 
 impl<A, E> Event<A, E>
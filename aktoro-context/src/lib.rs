@@ -1,14 +1,24 @@
 mod action;
+mod batch;
+mod bus;
 mod channel;
 mod context;
 mod control;
 mod event;
+mod mailbox;
 mod message;
 mod respond;
 mod update;
 
+pub use crate::batch::Batched;
+pub use crate::bus::EventBus;
+pub use crate::bus::Topic;
 pub use crate::channel::Receiver;
 pub use crate::channel::Sender;
 pub use crate::context::Context;
+pub use crate::context::ContextConfig;
 pub use crate::control::Controlled;
 pub use crate::control::Controller;
+pub use crate::mailbox::MailboxConfig;
+
+pub use aktoro_channel::Overflow;
@@ -0,0 +1,150 @@
+use aktoro_channel as channel;
+use aktoro_channel::Overflow;
+
+#[derive(Clone, Copy, Debug)]
+/// The configuration of one of an actor's
+/// channels (its message, control or
+/// update channel), used by
+/// [`ContextConfig`].
+///
+/// The default configuration creates
+/// unbounded channels without any limit.
+///
+/// [`ContextConfig`]: struct.ContextConfig.html
+pub struct MailboxConfig {
+    /// The capacity of the channel, or
+    /// `None` if it should be unbounded.
+    cap: Option<usize>,
+    /// The policy used when a message is
+    /// sent while the channel is bounded
+    /// and full.
+    overflow: Overflow,
+    /// The number of priority levels of
+    /// the channel.
+    prios: usize,
+    /// The limit of messages that can be
+    /// sent over the channel, or `None` if
+    /// no limit should be set.
+    msgs: Option<usize>,
+}
+
+impl MailboxConfig {
+    /// Creates a new configuration for an
+    /// unbounded channel without any limit.
+    pub fn new() -> Self {
+        MailboxConfig::default()
+    }
+
+    /// Sets the channel to be created as
+    /// bounded with `cap` as its buffer
+    /// capacity.
+    pub fn bounded(mut self, cap: usize) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    /// Sets the channel to be created as
+    /// unbounded.
+    pub fn unbounded(mut self) -> Self {
+        self.cap = None;
+        self
+    }
+
+    /// Sets the policy used when a message
+    /// is sent while the channel is bounded
+    /// and full.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the number of priority levels
+    /// of the channel, messages with a higher
+    /// priority being handled first (actions
+    /// and updates are always sent with the
+    /// lowest priority).
+    pub fn priorities(mut self, levels: usize) -> Self {
+        self.prios = levels.max(1);
+        self
+    }
+
+    /// Sets the maximum number of messages
+    /// that the channel will be able to
+    /// pass (after which the actor will
+    /// stop).
+    pub fn limited_msgs(mut self, limit: usize) -> Self {
+        self.msgs = Some(limit);
+        self
+    }
+
+    /// Allows an infinite number of
+    /// messages to be sent over the
+    /// channel.
+    pub fn unlimited_msgs(mut self) -> Self {
+        self.msgs = None;
+        self
+    }
+
+    /// Returns the channel's capacity, or
+    /// `None` if it is unbounded.
+    pub fn cap(&self) -> Option<usize> {
+        self.cap
+    }
+
+    /// Returns the policy used when a
+    /// message is sent while the channel is
+    /// bounded and full.
+    pub fn overflow_policy(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Returns the number of priority levels
+    /// of the channel.
+    pub fn prios(&self) -> usize {
+        self.prios
+    }
+
+    /// Returns the maximum number of
+    /// messages that the channel will be
+    /// able to pass.
+    pub fn msgs(&self) -> Option<usize> {
+        self.msgs
+    }
+
+    /// Creates a channel builder using the
+    /// configuration.
+    pub(crate) fn builder(&self) -> channel::Builder {
+        let mut builder = channel::Builder::new()
+            .overflow(self.overflow)
+            .priorities(self.prios)
+            .unlimited_receivers();
+
+        builder = if let Some(cap) = self.cap {
+            builder.bounded(cap)
+        } else {
+            builder.unbounded()
+        };
+
+        // We never limit the number of senders,
+        // as the context and every `Spawned`
+        // linked to the actor hold one.
+        builder = builder.unlimited_senders();
+
+        if let Some(limit) = self.msgs {
+            builder.limited_msgs(limit)
+        } else {
+            builder.unlimited_msgs()
+        }
+    }
+}
+
+impl Default for MailboxConfig {
+    fn default() -> Self {
+        MailboxConfig {
+            cap: None,
+            overflow: Overflow::default(),
+            prios: 1,
+            msgs: None,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::any;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
@@ -29,7 +30,7 @@ where
     M: Send,
 {
     msg: Option<M>,
-    _act: PhantomData<A>,
+    _act: PhantomData<fn() -> A>,
 }
 
 pub(crate) struct AsyncMessageFut<A, F, O>
@@ -39,7 +40,7 @@ where
     O: Send,
 {
     inner: raw::CancellableInner<F>,
-    _act: PhantomData<A>,
+    _act: PhantomData<fn() -> A>,
 }
 
 pub(crate) struct AsyncMessageFutMap<A, F, M, O, T>
@@ -52,7 +53,20 @@ where
 {
     inner: raw::CancellableInner<F>,
     map: M,
-    _act: PhantomData<A>,
+    _act: PhantomData<fn() -> A>,
+}
+
+pub(crate) struct AsyncMessageFutFilterMap<A, F, M, O, T>
+where
+    A: raw::Handler<T, Output = ()>,
+    F: Future<Output = Option<O>> + Unpin + Send,
+    M: Fn(O) -> T + Send,
+    O: Send,
+    T: Send,
+{
+    inner: raw::CancellableInner<F>,
+    map: M,
+    _act: PhantomData<fn() -> A>,
 }
 
 pub(crate) struct AsyncMessageStream<A, S, M, I, T>
@@ -65,7 +79,7 @@ where
 {
     inner: raw::CancellableInner<S>,
     map: M,
-    _act: PhantomData<A>,
+    _act: PhantomData<fn() -> A>,
 }
 
 pub(crate) struct AsyncReadStream<A, R, M, N, T, E>
@@ -82,7 +96,7 @@ where
     inner: raw::CancellableInner<R>,
     map: M,
     map_err: N,
-    _act: PhantomData<A>,
+    _act: PhantomData<fn() -> A>,
 }
 
 pub(crate) struct AsyncWriteFut<A, W, M, N, T, E>
@@ -98,7 +112,7 @@ where
     map: M,
     map_err: N,
     inner: raw::CancellableInner<W>,
-    _act: PhantomData<A>,
+    _act: PhantomData<fn() -> A>,
 }
 
 impl<A, M> Message<A, M>
@@ -163,6 +177,23 @@ where
     }
 }
 
+impl<A, F, M, O, T> AsyncMessageFutFilterMap<A, F, M, O, T>
+where
+    A: raw::Handler<T, Output = ()>,
+    F: Future<Output = Option<O>> + Unpin + Send,
+    M: Fn(O) -> T + Unpin + Send,
+    O: Send,
+    T: Send,
+{
+    pub(crate) fn new(inner: raw::CancellableInner<F>, map: M) -> Self {
+        AsyncMessageFutFilterMap {
+            inner,
+            map,
+            _act: PhantomData,
+        }
+    }
+}
+
 impl<A, S, M, I, T> AsyncMessageStream<A, S, M, I, T>
 where
     A: raw::Handler<T, Output = ()>,
@@ -230,13 +261,32 @@ where
 
     fn handle(&mut self, actor: &mut A, ctx: &mut A::Context) -> Result<(), A::Error> {
         // If the message hasn't already been handled,
-        // we do so and return the result.
+        // we do so and return the result (notifying
+        // the sender if the handler failed).
         if let Some(msg) = self.msg.take() {
-            self.resp.take().unwrap().respond(actor.handle(msg, ctx)?);
+            let resp = self.resp.take().unwrap();
+
+            match actor.handle(msg, ctx) {
+                Ok(out) => resp.respond(out),
+                Err(err) => {
+                    resp.failed();
+                    return Err(err);
+                }
+            }
         }
 
         Ok(())
     }
+
+    fn type_name(&self) -> &'static str {
+        any::type_name::<M>()
+    }
+
+    fn undelivered(&mut self) {
+        if let Some(resp) = self.resp.take() {
+            resp.dead();
+        }
+    }
 }
 
 impl<A, M> raw::Message for AsyncMessage<A, M>
@@ -325,6 +375,48 @@ where
     }
 }
 
+impl<A, F, M, O, T> raw::AsyncMessageFut for AsyncMessageFutFilterMap<A, F, M, O, T>
+where
+    A: raw::Handler<T, Output = ()> + 'static,
+    F: Future<Output = Option<O>> + Unpin + Send,
+    M: Fn(O) -> T + Unpin + Send,
+    O: Send + 'static,
+    T: Send + 'static,
+{
+    type Actor = A;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        ctx: &mut task::Context,
+    ) -> Poll<raw::AsyncMessageRet<Self::Actor>> {
+        let fut = self.get_mut();
+        let mut inner = if let Some(inner) = fut.inner.get() {
+            inner
+        } else {
+            return Poll::Ready(None);
+        };
+
+        match Pin::new(&mut inner).poll(ctx) {
+            // We only pass a message to the actor
+            // if the future yielded an output.
+            Poll::Ready(Some(output)) => {
+                let msg = (fut.map)(output);
+
+                fut.inner.done();
+                Poll::Ready(Some(Box::new(AsyncMessage::new(msg))))
+            }
+            Poll::Ready(None) => {
+                fut.inner.done();
+                Poll::Ready(None)
+            }
+            Poll::Pending => {
+                fut.inner.set(inner);
+                Poll::Pending
+            }
+        }
+    }
+}
+
 impl<A, S, M, I, T> raw::AsyncMessageStream for AsyncMessageStream<A, S, M, I, T>
 where
     A: raw::Handler<T, Output = ()> + 'static,
@@ -1,68 +1,82 @@
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
 use std::task;
 use std::task::Poll;
-use std::task::Waker;
 
-use crossbeam_utils::atomic::AtomicCell;
+use aktoro_channel::oneshot;
+use aktoro_raw as raw;
 
 /// A sender or receiver, with the sender
-/// being able to store an output once and
-/// to notify the receiver, and the receiver
-/// being able to register a `Waker` to be
-/// waked up after the output is available.
-pub(crate) struct Respond<O>(Arc<RespondInner<O>>);
+/// being able to send an output once to
+/// the receiver, and the receiver being
+/// a future resolving with it.
+///
+/// If the sender is dropped without sending
+/// an output, the receiver is notified that
+/// the actor died.
+pub(crate) struct Respond<O>(RespondInner<O>);
 
-/// The inner "channel" of `Respond`.
-struct RespondInner<O> {
-    /// The output that the sender has sent
-    /// or `None` if no output has been
-    /// sent yet, or if the receiver has
-    /// already read it.
-    out: AtomicCell<Option<O>>,
-    /// A pointer to an optional waker that
-    /// will be notified when the output
-    /// is available.
-    waker: AtomicCell<Option<Waker>>,
+/// Either the sender or the receiver of
+/// the inner oneshot channel.
+enum RespondInner<O> {
+    Sender(oneshot::Sender<Result<O, raw::AskError>>),
+    Receiver(oneshot::Receiver<Result<O, raw::AskError>>),
 }
 
 impl<O> Respond<O> {
     /// Creates a new channel, returning a
     /// sender and a receiver.
     pub(crate) fn new() -> (Self, Self) {
-        let inner = Arc::new(RespondInner {
-            out: AtomicCell::new(None),
-            waker: AtomicCell::new(None),
-        });
+        let (sender, recver) = oneshot::new();
 
-        (Respond(inner.clone()), Respond(inner))
+        (
+            Respond(RespondInner::Sender(sender)),
+            Respond(RespondInner::Receiver(recver)),
+        )
     }
 
-    /// Stores the output and eventually
-    /// notifies the receiver.
+    /// Sends the output to the receiver.
     pub(crate) fn respond(self, out: O) {
-        self.0.out.store(Some(out));
+        self.send(Ok(out));
+    }
+
+    /// Notifies the receiver that the
+    /// handler returned an error.
+    pub(crate) fn failed(self) {
+        self.send(Err(raw::AskError::failed()));
+    }
+
+    /// Notifies the receiver that the
+    /// actor won't handle the message or
+    /// action.
+    pub(crate) fn dead(self) {
+        self.send(Err(raw::AskError::dead()));
+    }
 
-        if let Some(waker) = self.0.waker.swap(None) {
-            waker.wake();
+    /// Sends the result to the receiver
+    /// (this does nothing if the receiver
+    /// has been dropped).
+    fn send(self, res: Result<O, raw::AskError>) {
+        if let RespondInner::Sender(sender) = self.0 {
+            sender.send(res).ok();
         }
     }
 }
 
 impl<O> Future for Respond<O> {
-    type Output = O;
+    type Output = Result<O, raw::AskError>;
 
-    fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<O> {
-        // If the output is available, we
-        // complete the future with it.
-        if let Some(out) = self.0.out.swap(None) {
-            Poll::Ready(out)
-        // Otherwise, we store the future's
-        // waker.
-        } else {
-            self.0.waker.store(Some(ctx.waker().clone()));
-            Poll::Pending
+    fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Result<O, raw::AskError>> {
+        match &mut self.get_mut().0 {
+            // If the sender was dropped without
+            // sending an output, we notify that
+            // the actor died.
+            RespondInner::Receiver(recver) => match Pin::new(recver).poll(ctx) {
+                Poll::Ready(Ok(res)) => Poll::Ready(res),
+                Poll::Ready(Err(_)) => Poll::Ready(Err(raw::AskError::dead())),
+                Poll::Pending => Poll::Pending,
+            },
+            RespondInner::Sender(_) => Poll::Pending,
         }
     }
 }
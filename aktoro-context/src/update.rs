@@ -4,9 +4,12 @@ use std::task::Poll;
 
 use aktoro_channel as channel;
 use aktoro_channel::error::TrySendError;
+use aktoro_channel::watch;
 use aktoro_raw as raw;
 use futures_core::Stream;
 
+use crate::mailbox::MailboxConfig;
+
 /// A wrapper around an actor's status,
 /// containing its identifier.
 pub struct Update<A>
@@ -23,28 +26,56 @@ where
 /// [`Context`].
 ///
 /// [`Context`]: struct.Context.html
-pub struct Updater<A: raw::Actor>(channel::Sender<Update<A>>);
+pub struct Updater<A: raw::Actor>(Sender<A>);
 
 /// An actor's update channel receiver, used
 /// by [`Context`].
 ///
+/// Unless the update channel was configured,
+/// it is a watch channel and only the actor's
+/// latest status is yielded when it changed
+/// several times since the receiver last
+/// checked it.
+///
 /// [`Context`]: struct.Context.html
-pub struct Updated<A: raw::Actor>(channel::Receiver<Update<A>>);
+pub struct Updated<A: raw::Actor>(Receiver<A>);
+
+/// The sender of either a watch channel or
+/// a configured channel.
+enum Sender<A: raw::Actor> {
+    Watch(watch::Sender<Update<A>>),
+    Channel(channel::Sender<Update<A>>),
+}
+
+/// The receiver of either a watch channel
+/// or a configured channel.
+enum Receiver<A: raw::Actor> {
+    Watch(watch::Receiver<Update<A>>),
+    Channel(channel::Receiver<Update<A>>),
+}
 
-/// Creates a new control channel for the
+/// Creates a new update channel for the
 /// specified actor type, returning a sender
 /// and receiver connected to it.
-pub(crate) fn new<A: raw::Actor>() -> (Updater<A>, Updated<A>) {
-    // TODO: maybe allow the channel's configuration
-    // to be specified.
-    let (sender, recver) = channel::Builder::new()
-        .unbounded()
-        .unlimited_msgs()
-        .unlimited_senders()
-        .unlimited_receivers()
-        .build();
-
-    (Updater(sender), Updated(recver))
+///
+/// The channel is a watch channel if no
+/// configuration is provided.
+pub(crate) fn new<A: raw::Actor>(config: Option<&MailboxConfig>) -> (Updater<A>, Updated<A>) {
+    if let Some(config) = config {
+        let (sender, recver) = config.builder().build();
+
+        (
+            Updater(Sender::Channel(sender)),
+            Updated(Receiver::Channel(recver)),
+        )
+    } else {
+        let (sender, recver) = watch::new(Update::default());
+
+        (
+            Updater(Sender::Watch(sender)),
+            Updated(Receiver::Watch(recver)),
+        )
+    }
 }
 
 impl<A> Update<A>
@@ -140,7 +171,10 @@ where
     type Error = TrySendError<Update<A>>;
 
     fn try_send(&mut self, update: Update<A>) -> Result<(), Self::Error> {
-        self.0.try_send(update)
+        match &self.0 {
+            Sender::Watch(sender) => sender.send(update),
+            Sender::Channel(sender) => sender.try_send(update),
+        }
     }
 }
 
@@ -153,7 +187,10 @@ where
     type Item = Update<A>;
 
     fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<Update<A>>> {
-        Pin::new(&mut self.get_mut().0).poll_next(ctx)
+        match &mut self.get_mut().0 {
+            Receiver::Watch(recver) => Pin::new(recver).poll_next(ctx),
+            Receiver::Channel(recver) => Pin::new(recver).poll_next(ctx),
+        }
     }
 }
 
@@ -2,12 +2,20 @@ use std::error;
 
 use crate::context::Context;
 
-pub trait Actor: Unpin + Send + Sized {
+pub trait Actor: Unpin + Sized {
     type Context: Context<Self>;
 
     type Status: Status + Unpin;
 
-    type Error: error::Error + Send + 'static;
+    /// The error returned by the actor's
+    /// handlers.
+    ///
+    /// It must be `Sync` (and not only `Send`)
+    /// because the runtime shares it between
+    /// the actors monitoring the actor that
+    /// returned it and exposes it as the
+    /// source of its own error.
+    type Error: error::Error + Send + Sync + 'static;
 
     #[allow(unused)]
     /// Called when the actor's context has been created
@@ -0,0 +1,148 @@
+use std::error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::pin::Pin;
+use std::task;
+use std::task::Poll;
+
+use futures_core::future::BoxFuture;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+/// An error returned by the future
+/// waiting for an actor to handle a
+/// message or an action.
+pub struct AskError {
+    kind: AskErrorKind,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum AskErrorKind {
+    /// The actor died before handling
+    /// the message or action.
+    Dead,
+    /// The actor's handler returned an
+    /// error.
+    Failed,
+    /// The timeout elapsed before the
+    /// actor handled the message or
+    /// action.
+    Timeout,
+}
+
+/// A future returned by [`Spawned::ask`]
+/// and [`Spawned::ask_action`] that
+/// resolves with the output of the
+/// handler or an error if the actor died,
+/// the handler failed or the timeout
+/// elapsed.
+///
+/// [`Spawned::ask`]: struct.Spawned.html#method.ask
+/// [`Spawned::ask_action`]: struct.Spawned.html#method.ask_action
+pub struct Ask<'a, O> {
+    /// The future resolving with the
+    /// handler's output.
+    resp: BoxFuture<'a, Result<O, AskError>>,
+    /// The future resolving when the
+    /// timeout has elapsed.
+    timeout: Pin<Box<dyn Future<Output = ()> + Send + 'a>>,
+}
+
+impl AskError {
+    /// Creates a new "actor dead" error.
+    pub fn dead() -> Self {
+        AskError {
+            kind: AskErrorKind::Dead,
+        }
+    }
+
+    /// Creates a new "handler failed"
+    /// error.
+    pub fn failed() -> Self {
+        AskError {
+            kind: AskErrorKind::Failed,
+        }
+    }
+
+    /// Creates a new "timeout elapsed"
+    /// error.
+    pub fn timeout() -> Self {
+        AskError {
+            kind: AskErrorKind::Timeout,
+        }
+    }
+
+    /// Whether the error occured because
+    /// the actor died.
+    pub fn is_dead(&self) -> bool {
+        self.kind == AskErrorKind::Dead
+    }
+
+    /// Whether the error occured because
+    /// the actor's handler returned an
+    /// error.
+    pub fn is_failed(&self) -> bool {
+        self.kind == AskErrorKind::Failed
+    }
+
+    /// Whether the error occured because
+    /// the timeout elapsed.
+    pub fn is_timeout(&self) -> bool {
+        self.kind == AskErrorKind::Timeout
+    }
+
+    /// Returns a reference to the error's
+    /// kind.
+    pub fn kind(&self) -> &AskErrorKind {
+        &self.kind
+    }
+}
+
+impl<'a, O> Ask<'a, O> {
+    /// Creates a new future that will resolve
+    /// with the output of `resp`, or with an
+    /// error if `timeout` resolves first.
+    pub fn new<T>(resp: BoxFuture<'a, Result<O, AskError>>, timeout: T) -> Self
+    where
+        T: Future<Output = ()> + Send + 'a,
+    {
+        Ask {
+            resp,
+            timeout: Box::pin(timeout),
+        }
+    }
+}
+
+impl<'a, O> Future for Ask<'a, O> {
+    type Output = Result<O, AskError>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Result<O, AskError>> {
+        let ask = self.get_mut();
+
+        // If the handler's output (or an error)
+        // is available, we return it...
+        if let Poll::Ready(res) = ask.resp.as_mut().poll(ctx) {
+            return Poll::Ready(res);
+        }
+
+        // ...or an error if the timeout has
+        // elapsed.
+        match ask.timeout.as_mut().poll(ctx) {
+            Poll::Ready(()) => Poll::Ready(Err(AskError::timeout())),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl error::Error for AskError {}
+
+impl Display for AskError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            AskErrorKind::Dead => write!(fmt, "ask failed because actor dead",),
+            AskErrorKind::Failed => write!(fmt, "ask failed because handler failed",),
+            AskErrorKind::Timeout => write!(fmt, "ask failed because timeout elapsed",),
+        }
+    }
+}
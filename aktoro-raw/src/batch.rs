@@ -0,0 +1,35 @@
+use crate::actor::Actor;
+use crate::message::Handler;
+
+/// A message containing a batch of
+/// messages of type `M`, handled by actors
+/// implementing [`BatchHandler`].
+///
+/// [`BatchHandler`]: trait.BatchHandler.html
+pub struct Batch<M>(pub Vec<M>);
+
+pub trait BatchHandler<M: Send>: Actor {
+    /// Handles a batch of messages in one
+    /// call.
+    fn handle_batch(&mut self, msgs: Vec<M>, ctx: &mut Self::Context) -> Result<(), Self::Error>;
+}
+
+impl<M> Batch<M> {
+    /// Returns the batched messages,
+    /// consuming the batch.
+    pub fn into_inner(self) -> Vec<M> {
+        self.0
+    }
+}
+
+impl<A, M> Handler<Batch<M>> for A
+where
+    A: BatchHandler<M>,
+    M: Send,
+{
+    type Output = ();
+
+    fn handle(&mut self, batch: Batch<M>, ctx: &mut Self::Context) -> Result<(), Self::Error> {
+        self.handle_batch(batch.0, ctx)
+    }
+}
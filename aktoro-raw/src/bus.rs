@@ -0,0 +1,36 @@
+use std::pin::Pin;
+
+use futures_core::Stream;
+
+/// A stream yielding the messages of type
+/// `M` published on an event bus, returned
+/// by [`EventBus::subscribe`].
+///
+/// [`EventBus::subscribe`]: trait.EventBus.html#tymethod.subscribe
+pub type Topic<M> = Pin<Box<dyn Stream<Item = M> + Send>>;
+
+/// An event bus allowing actors to publish
+/// messages without knowing who will receive
+/// them, and to subscribe to all the messages
+/// of a given type (its "topic") published on
+/// it.
+///
+/// An actor can subscribe to a topic through
+/// its context using [`Context::subscribe_topic`].
+///
+/// [`Context::subscribe_topic`]: trait.Context.html#method.subscribe_topic
+pub trait EventBus: Clone + Send + Sync + 'static {
+    /// Subscribes to the messages of type `M`
+    /// published on the bus after the call,
+    /// returning a stream yielding them.
+    fn subscribe<M>(&self) -> Topic<M>
+    where
+        M: Clone + Send + 'static;
+
+    /// Publishes a message on the bus,
+    /// returning the number of subscribers
+    /// that will receive it.
+    fn publish<M>(&self, msg: M) -> usize
+    where
+        M: Clone + Send + 'static;
+}
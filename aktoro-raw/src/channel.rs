@@ -1,9 +1,11 @@
 use std::error;
+use std::time::Duration;
 
 use futures_core::future::BoxFuture;
 use futures_core::Stream;
 
 use crate::actor::Actor;
+use crate::ask::AskError;
 use crate::message::Handler;
 use crate::message::Message;
 
@@ -11,22 +13,136 @@ use crate::message::Message;
 /// method.
 ///
 /// `Ok` contains a future resolving with the result
-/// returned by the message handler.
+/// returned by the message handler, or an error if
+/// the actor died or the handler failed.
 ///
 /// [`Sender::try_send`]: trait.Sender.html#method.try_send
-pub type SenderRes<'s, O, E> = Result<BoxFuture<'s, Result<O, E>>, E>;
+pub type SenderRes<'s, O, E> = Result<BoxFuture<'s, Result<O, AskError>>, E>;
+
+/// The future returned by the [`Sender::send`]
+/// method, resolving with the same result as
+/// [`Sender::try_send`] once the message has
+/// been sent.
+///
+/// [`Sender::send`]: trait.Sender.html#method.send
+/// [`Sender::try_send`]: trait.Sender.html#method.try_send
+pub type SenderFut<'s, O, E> = BoxFuture<'s, SenderRes<'s, O, E>>;
+
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+/// A snapshot of the state of an actor's
+/// message channel (its "mailbox"), returned
+/// by [`Sender::stats`].
+///
+/// [`Sender::stats`]: trait.Sender.html#tymethod.stats
+pub struct MailboxStats {
+    /// The number of messages waiting to be
+    /// handled by the actor.
+    pub len: usize,
+    /// The mailbox's capacity, or `None` if
+    /// it is unbounded.
+    pub capacity: Option<usize>,
+    /// The total number of messages sent to
+    /// the actor.
+    pub sent: usize,
+    /// The total number of messages received
+    /// by the actor's context.
+    pub received: usize,
+    /// The number of messages that were
+    /// dropped because the mailbox was full.
+    pub dropped: usize,
+    /// The number of messages that expired
+    /// before being handled.
+    pub expired: usize,
+    /// The number of senders connected to
+    /// the mailbox.
+    pub senders: usize,
+    /// Whether the mailbox is closed.
+    pub closed: bool,
+}
 
 pub trait Sender<A: Actor>: Unpin + Clone + Send {
     type Receiver: Receiver<A>;
 
     type Error: error::Error + Send + 'static;
 
+    /// A weak reference to the actor's message
+    /// channel, that doesn't keep it open but
+    /// can be upgraded to a sender while other
+    /// senders are connected to it.
+    type Weak: Clone + Send;
+
     /// Tries to send a message to be handled by the
-    /// actor.
+    /// actor, with the priority returned by
+    /// [`Handler::priority`].
+    ///
+    /// [`Handler::priority`]: trait.Handler.html#method.priority
     fn try_send<M>(&mut self, msg: M) -> SenderRes<A::Output, Self::Error>
     where
         A: Handler<M>,
         M: Send + 'static;
+
+    /// Tries to send a message to be handled by the
+    /// actor with the given priority (overriding
+    /// the one returned by [`Handler::priority`]).
+    ///
+    /// [`Handler::priority`]: trait.Handler.html#method.priority
+    fn try_send_prio<M>(&mut self, msg: M, prio: usize) -> SenderRes<A::Output, Self::Error>
+    where
+        A: Handler<M>,
+        M: Send + 'static;
+
+    /// Tries to send a message to be handled by the
+    /// actor (with the lowest priority) that will
+    /// expire after `ttl` has elapsed, in which
+    /// case it won't be handled and the returned
+    /// future will resolve with an error.
+    fn try_send_ttl<M>(&mut self, msg: M, ttl: Duration) -> SenderRes<A::Output, Self::Error>
+    where
+        A: Handler<M>,
+        M: Send + 'static;
+
+    /// Sends a message to be handled by the
+    /// actor (with the priority returned by
+    /// [`Handler::priority`]), waiting for the
+    /// actor's message channel to have free
+    /// space if it is bounded and full.
+    ///
+    /// [`Handler::priority`]: trait.Handler.html#method.priority
+    fn send<M>(&mut self, msg: M) -> SenderFut<A::Output, Self::Error>
+    where
+        A: Handler<M>,
+        M: Send + 'static;
+
+    /// Sends a message to be handled by the
+    /// actor (with the priority returned by
+    /// [`Handler::priority`]), blocking the
+    /// current thread until the actor's message
+    /// channel has free space if it is bounded
+    /// and full.
+    ///
+    /// This method shouldn't be called from
+    /// asynchronous code.
+    ///
+    /// [`Handler::priority`]: trait.Handler.html#method.priority
+    fn send_blocking<M>(&mut self, msg: M) -> SenderRes<A::Output, Self::Error>
+    where
+        A: Handler<M>,
+        M: Send + 'static;
+
+    /// Returns a snapshot of the state of the
+    /// actor's message channel.
+    fn stats(&self) -> MailboxStats;
+
+    /// Creates a weak reference to the actor's
+    /// message channel.
+    fn downgrade(&self) -> Self::Weak;
+
+    /// Tries to upgrade a weak reference to
+    /// the actor's message channel to a new
+    /// sender, returning `None` if the channel
+    /// is closed or if no other sender is
+    /// connected to it.
+    fn upgrade(weak: &Self::Weak) -> Option<Self>;
 }
 
 pub trait Receiver<A: Actor>: Stream<Item = Box<dyn Message<Actor = A>>> + Unpin + Send {}
@@ -1,3 +1,4 @@
+use std::error;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::AtomicBool;
@@ -6,6 +7,8 @@ use std::sync::Arc;
 use std::task;
 use std::task::Poll;
 use std::task::Waker;
+use std::time::Duration;
+use std::time::Instant;
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures_core::Stream;
@@ -15,13 +18,31 @@ use futures_io::AsyncWrite;
 
 use crate::action::Action;
 use crate::actor::Actor;
+use crate::bus::EventBus;
+use crate::bus::Topic;
+use crate::channel::MailboxStats;
 use crate::channel::Sender;
 use crate::control::Controller;
+use crate::dead_letter::DeadLetterSink;
 use crate::event::Event;
 use crate::event::EventHandler;
+use crate::executor::Blocking;
+use crate::executor::BlockingSpawner;
+use crate::executor::Spawner;
 use crate::message::Handler;
 use crate::message::Message;
+use crate::monitor::Down;
+use crate::monitor::Monitors;
+use crate::registry::Registry;
+use crate::registry::RegistryError;
 use crate::spawned::Spawned;
+use crate::supervisor::Factory;
+use crate::supervisor::Intensity;
+use crate::supervisor::Strategy;
+use crate::supervisor::SupervisionError;
+use crate::timer::Delayed;
+use crate::timer::Repeated;
+use crate::timer::Timer;
 use crate::update::Updater;
 
 /// A wrapper around a future/stream/reader
@@ -72,13 +93,25 @@ pub struct Cancelling<C> {
     waker: Arc<AtomicCell<Option<Waker>>>,
 }
 
-pub trait Context<A: Actor>: Stream<Item = Work<A>> + Unpin + Send + Sized {
+pub trait Context<A: Actor>: Stream<Item = Work<A>> + Unpin + Sized {
     type Config: Default;
 
     type Controller: Controller<A>;
     type Sender: Sender<A>;
     type Updater: Updater<A>;
 
+    /// The type of error returned when one of
+    /// the actor's sub-actors fails, and passed
+    /// to the actor in [`Down`] messages.
+    ///
+    /// [`Down`]: struct.Down.html
+    type RuntimeError: error::Error + Send + 'static;
+
+    /// The type of timer used to send messages
+    /// to the actor after a delay or at a fixed
+    /// interval, supplied by the runtime.
+    type Timer: Timer;
+
     /// Creates a new context with the provided
     /// config and an identifier for the actor.
     fn new(actor_id: u64, config: Self::Config) -> Self;
@@ -111,6 +144,12 @@ pub trait Context<A: Actor>: Stream<Item = Work<A>> + Unpin + Send + Sized {
     /// Gets the actor's message channel sender.
     fn sender(&self) -> &Self::Sender;
 
+    /// Returns a snapshot of the state of the
+    /// actor's message channel.
+    fn mailbox_stats(&self) -> MailboxStats {
+        self.sender().stats()
+    }
+
     /// Tries to get a mutable reference to the
     /// actor's update channel sender.
     fn updated_ref(&mut self) -> Option<&mut <Self::Updater as Updater<A>>::Updated>;
@@ -131,6 +170,51 @@ pub trait Context<A: Actor>: Stream<Item = Work<A>> + Unpin + Send + Sized {
     /// update channel receiver.
     fn updater(&mut self) -> &mut Self::Updater;
 
+    /// Sets the sink that the context will
+    /// report the messages that couldn't be
+    /// delivered to the actor (or to its
+    /// sub-actors) to.
+    fn set_dead_letters(&mut self, sink: DeadLetterSink);
+
+    /// Sets the spawner that the context's inner
+    /// runtime will spawn the actor's sub-actors
+    /// on.
+    fn set_spawner(&mut self, spawner: Spawner);
+
+    /// Sets the spawner that the context will
+    /// run the closures passed to
+    /// [`spawn_blocking`] on (it is also passed
+    /// to the context's inner runtime).
+    ///
+    /// [`spawn_blocking`]: #tymethod.spawn_blocking
+    fn set_blocking_spawner(&mut self, spawner: BlockingSpawner);
+
+    /// Sets the table that the actor uses to
+    /// watch other actors and to be watched
+    /// (it is also passed to the context's
+    /// inner runtime).
+    fn set_monitors(&mut self, monitors: Monitors);
+
+    /// Sets the registry that the actor uses to
+    /// look up (and register) other actors (it
+    /// is also passed to the context's inner
+    /// runtime).
+    fn set_registry(&mut self, registry: Registry);
+
+    /// Sets the timer that the context uses to
+    /// schedule messages to the actor (it is also
+    /// passed to the context's inner runtime).
+    fn set_timer(&mut self, timer: Self::Timer);
+
+    /// Returns the maximum number of actions,
+    /// events and messages that the actor should
+    /// handle each time it is polled before
+    /// yielding to the other tasks, if it should
+    /// override the runtime's.
+    fn budget(&self) -> Option<usize> {
+        None
+    }
+
     /// Returns a list of the context's inner
     /// runtime's actors' identifier.
     fn actors(&self) -> Vec<u64>;
@@ -144,8 +228,185 @@ pub trait Context<A: Actor>: Stream<Item = Work<A>> + Unpin + Send + Sized {
     /// same configuration structure as this context.
     fn spawn<S, C>(&mut self, actor: S) -> Option<Spawned<S>>
     where
-        S: Actor<Context = C> + 'static,
-        C: Context<S, Config = Self::Config>;
+        S: Actor<Context = C> + Send + 'static,
+        C: Context<S, Config = Self::Config, Timer = Self::Timer> + Send;
+
+    /// Spawns a sub-actor on the context's inner
+    /// runtime, passing its context the provided
+    /// config.
+    fn spawn_with<S, C>(&mut self, actor: S, config: Self::Config) -> Option<Spawned<S>>
+    where
+        S: Actor<Context = C> + Send + 'static,
+        C: Context<S, Config = Self::Config, Timer = Self::Timer> + Send;
+
+    /// Sends a message to the actor after `delay`
+    /// has elapsed.
+    ///
+    /// The execution can be cancelled using the
+    /// returned [`Cancellable`]. Cancelling the
+    /// execution, if it isn't done, will return
+    /// a [`Delayed`] holding the message.
+    ///
+    /// [`Cancellable`]: struct.Cancellable.html
+    /// [`Delayed`]: struct.Delayed.html
+    fn send_later<M>(&mut self, msg: M, delay: Duration) -> Cancellable<Delayed<M>>
+    where
+        A: Handler<M, Output = ()>,
+        M: Send + 'static;
+
+    /// Sends a message to the actor when `at`
+    /// is reached.
+    ///
+    /// The execution can be cancelled using the
+    /// returned [`Cancellable`]. Cancelling the
+    /// execution, if it isn't done, will return
+    /// a [`Delayed`] holding the message.
+    ///
+    /// [`Cancellable`]: struct.Cancellable.html
+    /// [`Delayed`]: struct.Delayed.html
+    fn run_at<M>(&mut self, msg: M, at: Instant) -> Cancellable<Delayed<M>>
+    where
+        A: Handler<M, Output = ()>,
+        M: Send + 'static;
+
+    /// Sends the message returned by `make` to
+    /// the actor every time `interval` has
+    /// elapsed.
+    ///
+    /// The execution can be cancelled using the
+    /// returned [`Cancellable`]. Cancelling the
+    /// execution will return a [`Repeated`]
+    /// holding `make`.
+    ///
+    /// [`Cancellable`]: struct.Cancellable.html
+    /// [`Repeated`]: struct.Repeated.html
+    fn run_interval<F, M>(&mut self, interval: Duration, make: F) -> Cancellable<Repeated<F>>
+    where
+        F: Fn() -> M + Unpin + Send + Sync + 'static,
+        A: Handler<M, Output = ()>,
+        M: Send + 'static;
+
+    /// Spawns a sub-actor created by `factory` on
+    /// the context's inner runtime, which will be
+    /// restarted depending on the context's
+    /// supervision strategy and intensity when it
+    /// fails.
+    ///
+    /// ## Note
+    ///
+    /// The new actor must have a context with the
+    /// same configuration structure as this context.
+    fn spawn_restartable<S, C, F>(&mut self, factory: F) -> Option<Spawned<S>>
+    where
+        S: Actor<Context = C> + Send + 'static,
+        C: Context<S, Config = Self::Config, Timer = Self::Timer> + Send,
+        F: Factory<S>;
+
+    /// Spawns a sub-actor created by `factory` on
+    /// the context's inner runtime (like
+    /// [`spawn_restartable`]), passing its
+    /// context the provided config.
+    ///
+    /// [`spawn_restartable`]: #method.spawn_restartable
+    fn spawn_restartable_with<S, C, F>(
+        &mut self,
+        factory: F,
+        config: Self::Config,
+    ) -> Option<Spawned<S>>
+    where
+        S: Actor<Context = C> + Send + 'static,
+        C: Context<S, Config = Self::Config, Timer = Self::Timer> + Send,
+        F: Factory<S>;
+
+    /// Sets the strategy used to decide which
+    /// sub-actors to restart when one of them
+    /// fails.
+    fn set_strategy(&mut self, strategy: Strategy);
+
+    /// Sets the maximum number of sub-actors
+    /// restarts allowed in a period of time,
+    /// after which all the sub-actors will be
+    /// stopped and the actor will fail (with a
+    /// [`SupervisionError`]).
+    ///
+    /// [`SupervisionError`]: struct.SupervisionError.html
+    fn set_intensity(&mut self, intensity: Intensity);
+
+    /// Resets the context's state (stopping its
+    /// sub-actors and dropping the futures,
+    /// streams, etc. it was handling) before the
+    /// actor is restarted.
+    fn reset(&mut self);
+
+    /// Starts monitoring the actor with the given
+    /// identifier (which can be any actor sharing
+    /// the context's [`Monitors`] table, not only
+    /// a sub-actor), making the actor handle a
+    /// [`Down`] message when it dies, and
+    /// returning whether the actor is running.
+    ///
+    /// ## Note
+    ///
+    /// An actor that fails and gets restarted
+    /// by its supervisor isn't considered as
+    /// dead.
+    ///
+    /// [`Monitors`]: struct.Monitors.html
+    /// [`Down`]: struct.Down.html
+    fn monitor(&mut self, id: u64) -> bool
+    where
+        A: Handler<Down<Self::RuntimeError>, Output = ()>;
+
+    /// Links the actor with the actor with the
+    /// given identifier, making the actor handle
+    /// a [`Down`] message when it dies (like
+    /// [`monitor`]) and returning whether the
+    /// actor is running.
+    ///
+    /// Links are bidirectional: when one of the
+    /// linked actors fails, the other one fails
+    /// too (with a [`SupervisionError`]), after
+    /// having handled a `Down` message if it
+    /// monitors (or is linked to) actors itself.
+    ///
+    /// [`Down`]: struct.Down.html
+    /// [`monitor`]: #method.monitor
+    /// [`SupervisionError`]: struct.SupervisionError.html
+    fn link(&mut self, id: u64) -> bool
+    where
+        A: Handler<Down<Self::RuntimeError>, Output = ()>;
+
+    /// Stops monitoring (or being linked to) the
+    /// actor with the given identifier.
+    fn demonitor(&mut self, id: u64);
+
+    /// Registers the actor linked to `spawned`
+    /// under `name` in the context's registry
+    /// (shared with the runtime), returning an
+    /// error if another actor is already
+    /// registered under it or if the actor isn't
+    /// running.
+    ///
+    /// The actor is automatically unregistered
+    /// when it dies.
+    fn register<S>(&mut self, name: &str, spawned: &Spawned<S>) -> Result<(), RegistryError>
+    where
+        S: Actor + 'static;
+
+    /// Returns a [`Spawned`] for the actor
+    /// registered under `name` in the context's
+    /// registry, or [`None`] if there is no such
+    /// actor or if it isn't of type `S`.
+    ///
+    /// [`Spawned`]: struct.Spawned.html
+    fn lookup<S>(&self, name: &str) -> Option<Spawned<S>>
+    where
+        S: Actor + 'static;
+
+    /// Unregisters the actor registered under
+    /// `name` in the context's registry, returning
+    /// its identifier.
+    fn unregister(&mut self, name: &str) -> Option<u64>;
 
     /// Waits for a future to yield before mapping it
     /// to a message and passing it to the actor.
@@ -215,6 +476,35 @@ pub trait Context<A: Actor>: Stream<Item = Work<A>> + Unpin + Send + Sized {
         F: Future<Output = O> + Unpin + Send + 'static,
         O: Send + 'static;
 
+    /// Runs a closure on the runtime's blocking
+    /// pool (see [`set_blocking_spawner`]) before
+    /// mapping its output to a message and
+    /// passing it to the actor.
+    ///
+    /// Unlike [`blocking_wait`], the context
+    /// keeps handling messages, events, streams,
+    /// etc. while the closure is running, and
+    /// the thread that the actor is running on
+    /// isn't stalled by it.
+    ///
+    /// The execution can be cancelled using the
+    /// returned [`Cancellable`]. Cancelling it
+    /// only prevents the output from being
+    /// passed to the actor (the closure keeps
+    /// running). Nothing is passed to the actor
+    /// if the closure panics.
+    ///
+    /// [`set_blocking_spawner`]: #tymethod.set_blocking_spawner
+    /// [`blocking_wait`]: #tymethod.blocking_wait
+    /// [`Cancellable`]: struct.Cancellable.html
+    fn spawn_blocking<F, M, O, T>(&mut self, f: F, map: M) -> Cancellable<Blocking<O>>
+    where
+        F: FnOnce() -> O + Send + 'static,
+        M: Fn(O) -> T + Unpin + Send + Sync + 'static,
+        A: Handler<T, Output = ()>,
+        O: Send + 'static,
+        T: Send + 'static;
+
     /// Forwards the items yielded by a stream to
     /// the actor after mapping them to a message.
     ///
@@ -232,6 +522,23 @@ pub trait Context<A: Actor>: Stream<Item = Work<A>> + Unpin + Send + Sized {
         I: Send + 'static,
         T: Send + 'static;
 
+    /// Subscribes to the messages of type `M`
+    /// published on `bus`, forwarding them to
+    /// the actor.
+    ///
+    /// The subscription can be cancelled using
+    /// the returned [`Cancellable`].
+    ///
+    /// [`Cancellable`]: struct.Cancellable.html
+    fn subscribe_topic<B, M>(&mut self, bus: &B) -> Cancellable<Topic<M>>
+    where
+        B: EventBus,
+        A: Handler<M, Output = ()>,
+        M: Clone + Send + 'static,
+    {
+        self.subscribe(Box::pin(bus.subscribe::<M>()), |msg| msg)
+    }
+
     /// Forwards the received data to the actor
     /// after either mapping it or a returned
     /// error to a message.
@@ -330,6 +637,13 @@ pub enum Work<A: Actor> {
     /// Indicates that the actor's status has
     /// changed.
     Update,
+
+    /// Indicates that the actor should fail
+    /// with the given error (because its
+    /// supervisor escalated the failure of one
+    /// of its children or because an actor it
+    /// was linked to failed).
+    Fail(SupervisionError),
 }
 
 impl<C> Cancellable<C> {
@@ -6,27 +6,46 @@ use futures_core::Stream;
 use crate::action::Action;
 use crate::action::ActionHandler;
 use crate::actor::Actor;
+use crate::ask::AskError;
 
 /// The result returned by the [`Controller::try_send`]
 /// method.
 ///
 /// `Ok` contains a future resolving with the result
-/// returned by the action handler.
+/// returned by the action handler, or an error if
+/// the actor died or the handler failed.
 ///
 /// [`Controller::try_send`]: trait.Controller.html#method.try_send
-pub type ControllerRes<'c, O, E> = Result<BoxFuture<'c, Result<O, E>>, E>;
+pub type ControllerRes<'c, O, E> = Result<BoxFuture<'c, Result<O, AskError>>, E>;
 
 pub trait Controller<A: Actor>: Unpin + Clone + Send {
     type Controlled: Controlled<A>;
 
     type Error: error::Error + Send + 'static;
 
+    /// A weak reference to the actor's control
+    /// channel, that doesn't keep it open but
+    /// can be upgraded to a controller while
+    /// other controllers are connected to it.
+    type Weak: Clone + Send;
+
     /// Tries to send an action to be handled by the
     /// actor.
     fn try_send<D>(&mut self, action: D) -> ControllerRes<A::Output, Self::Error>
     where
         A: ActionHandler<D>,
         D: Send + 'static;
+
+    /// Creates a weak reference to the actor's
+    /// control channel.
+    fn downgrade(&self) -> Self::Weak;
+
+    /// Tries to upgrade a weak reference to
+    /// the actor's control channel to a new
+    /// controller, returning `None` if the
+    /// channel is closed or if no other
+    /// controller is connected to it.
+    fn upgrade(weak: &Self::Weak) -> Option<Self>;
 }
 
 pub trait Controlled<A: Actor>: Stream<Item = Box<dyn Action<Actor = A>>> + Unpin + Send {}
@@ -0,0 +1,122 @@
+use std::any::Any;
+use std::sync::Arc;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+/// The reason why a message couldn't be
+/// delivered to an actor.
+pub enum DeadLetterReason {
+    /// The actor's message channel was
+    /// full (the message was either rejected
+    /// or dropped, depending on the channel's
+    /// overflow policy).
+    Full,
+    /// The maximum number of messages that
+    /// can be sent to the actor has been
+    /// reached.
+    Limit,
+    /// The actor's message channel was
+    /// closed.
+    Closed,
+    /// The sender was disconnected from
+    /// the actor's message channel.
+    Disconnected,
+    /// The message expired before being
+    /// handled.
+    Expired,
+    /// The actor died before handling the
+    /// message.
+    Dead,
+}
+
+/// A message that couldn't be delivered to
+/// an actor, along with why it couldn't be.
+pub struct DeadLetter {
+    /// The identifier of the actor the
+    /// message was sent to.
+    target: u64,
+    /// Why the message couldn't be
+    /// delivered.
+    reason: DeadLetterReason,
+    /// The name of the message's type.
+    type_name: &'static str,
+    /// The message itself if it wasn't
+    /// handed back to its sender.
+    msg: Option<Box<dyn Any + Send>>,
+}
+
+#[derive(Clone)]
+/// A handle used by contexts and runtimes
+/// to report the messages that couldn't be
+/// delivered (see [`Context::set_dead_letters`]).
+///
+/// [`Context::set_dead_letters`]: trait.Context.html#tymethod.set_dead_letters
+pub struct DeadLetterSink(Arc<dyn Fn(DeadLetter) + Send + Sync>);
+
+impl DeadLetter {
+    /// Creates a new dead letter for a
+    /// message of type `type_name` that
+    /// couldn't be delivered to the actor
+    /// with the given identifier.
+    pub fn new(
+        target: u64,
+        reason: DeadLetterReason,
+        type_name: &'static str,
+        msg: Option<Box<dyn Any + Send>>,
+    ) -> Self {
+        DeadLetter {
+            target,
+            reason,
+            type_name,
+            msg,
+        }
+    }
+
+    /// Returns the identifier of the actor
+    /// the message was sent to.
+    pub fn target(&self) -> u64 {
+        self.target
+    }
+
+    /// Returns why the message couldn't be
+    /// delivered.
+    pub fn reason(&self) -> DeadLetterReason {
+        self.reason
+    }
+
+    /// Returns the name of the message's
+    /// type.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns a reference to the message
+    /// if it wasn't handed back to its
+    /// sender (in which case it is a boxed
+    /// `Box<dyn Message<Actor = A>>`).
+    pub fn msg(&self) -> Option<&(dyn Any + Send)> {
+        self.msg.as_ref().map(|msg| &**msg)
+    }
+
+    /// Returns the message if it wasn't
+    /// handed back to its sender, consuming
+    /// the dead letter.
+    pub fn into_msg(self) -> Option<Box<dyn Any + Send>> {
+        self.msg
+    }
+}
+
+impl DeadLetterSink {
+    /// Creates a new sink passing the dead
+    /// letters to `sink`.
+    pub fn new<F>(sink: F) -> Self
+    where
+        F: Fn(DeadLetter) + Send + Sync + 'static,
+    {
+        DeadLetterSink(Arc::new(sink))
+    }
+
+    /// Reports a dead letter.
+    pub fn send(&self, letter: DeadLetter) {
+        (self.0)(letter)
+    }
+}
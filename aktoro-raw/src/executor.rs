@@ -0,0 +1,109 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures_core::future::BoxFuture;
+
+/// A future resolving with the output of a
+/// closure run on a blocking pool (see
+/// [`Context::spawn_blocking`]), or with
+/// `None` if it panicked.
+///
+/// [`Context::spawn_blocking`]: trait.Context.html#tymethod.spawn_blocking
+pub type Blocking<O> = Pin<Box<dyn Future<Output = Option<O>> + Send>>;
+
+/// An executor that runtimes can spawn their
+/// actors (and the other futures they need
+/// to run in the background) on.
+///
+/// It is implemented for closures taking the
+/// futures to spawn, allowing to use any
+/// executor.
+pub trait Executor: Send + Sync + 'static {
+    /// Spawns a future that will be polled
+    /// in the background until it resolves.
+    fn spawn(&self, fut: BoxFuture<'static, ()>);
+}
+
+/// A pool of threads that runtimes can run
+/// the blocking closures (e.g. synchronous
+/// I/O or CPU-bound work) spawned by their
+/// actors on, so that they don't stall the
+/// threads of their executor.
+///
+/// It is implemented for closures taking the
+/// closures to run, allowing to use any
+/// thread pool.
+pub trait BlockingExecutor: Send + Sync + 'static {
+    /// Runs a closure on one of the pool's
+    /// threads.
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>);
+}
+
+#[derive(Clone)]
+/// A handle to an [`Executor`], that can be
+/// passed to runtimes and contexts (see
+/// [`Runtime::set_spawner`]).
+///
+/// [`Executor`]: trait.Executor.html
+/// [`Runtime::set_spawner`]: trait.Runtime.html#tymethod.set_spawner
+pub struct Spawner(Arc<dyn Executor>);
+
+impl Spawner {
+    /// Creates a new spawner spawning the
+    /// futures on `executor`.
+    pub fn new<E: Executor>(executor: E) -> Self {
+        Spawner(Arc::new(executor))
+    }
+
+    /// Spawns a future on the executor.
+    pub fn spawn<F>(&self, fut: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.0.spawn(Box::pin(fut))
+    }
+}
+
+#[derive(Clone)]
+/// A handle to a [`BlockingExecutor`], that
+/// can be passed to runtimes and contexts (see
+/// [`Runtime::set_blocking_spawner`]).
+///
+/// [`BlockingExecutor`]: trait.BlockingExecutor.html
+/// [`Runtime::set_blocking_spawner`]: trait.Runtime.html#tymethod.set_blocking_spawner
+pub struct BlockingSpawner(Arc<dyn BlockingExecutor>);
+
+impl BlockingSpawner {
+    /// Creates a new spawner running the
+    /// closures on `executor`.
+    pub fn new<E: BlockingExecutor>(executor: E) -> Self {
+        BlockingSpawner(Arc::new(executor))
+    }
+
+    /// Runs a closure on the executor.
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.0.spawn_blocking(Box::new(f))
+    }
+}
+
+impl<F> Executor for F
+where
+    F: Fn(BoxFuture<'static, ()>) + Send + Sync + 'static,
+{
+    fn spawn(&self, fut: BoxFuture<'static, ()>) {
+        self(fut)
+    }
+}
+
+impl<F> BlockingExecutor for F
+where
+    F: Fn(Box<dyn FnOnce() + Send>) + Send + Sync + 'static,
+{
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) {
+        self(f)
+    }
+}
@@ -1,27 +1,47 @@
 mod action;
 mod actor;
+mod ask;
+mod batch;
+mod bus;
 mod channel;
 mod context;
 mod control;
+mod dead_letter;
 mod event;
+mod executor;
 mod message;
+mod monitor;
 mod net;
+mod recipient;
+mod registry;
 mod runtime;
 mod spawned;
+mod supervisor;
 mod tcp;
+mod timer;
 mod udp;
 mod update;
 
 pub use crate::action::*;
 pub use crate::actor::*;
+pub use crate::ask::*;
+pub use crate::batch::*;
+pub use crate::bus::*;
 pub use crate::channel::*;
 pub use crate::context::*;
 pub use crate::control::*;
+pub use crate::dead_letter::*;
 pub use crate::event::*;
+pub use crate::executor::*;
 pub use crate::message::*;
+pub use crate::monitor::*;
 pub use crate::net::*;
+pub use crate::recipient::*;
+pub use crate::registry::*;
 pub use crate::runtime::*;
 pub use crate::spawned::*;
+pub use crate::supervisor::*;
 pub use crate::tcp::*;
+pub use crate::timer::*;
 pub use crate::udp::*;
 pub use crate::update::*;
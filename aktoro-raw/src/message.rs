@@ -14,6 +14,19 @@ pub trait Message: Send {
         actor: &mut Self::Actor,
         ctx: &mut <Self::Actor as Actor>::Context,
     ) -> Result<(), <Self::Actor as Actor>::Error>;
+
+    /// Returns the name of the type of the
+    /// wrapped message (used to report dead
+    /// letters).
+    fn type_name(&self) -> &'static str {
+        "<unknown>"
+    }
+
+    /// Notifies whoever is waiting for the
+    /// message to be handled that it won't
+    /// be (called before it is reported as
+    /// a dead letter).
+    fn undelivered(&mut self) {}
 }
 
 pub trait AsyncMessageFut: Send {
@@ -46,6 +59,16 @@ pub trait Handler<M: Send>: Actor {
     /// Handles the message, returning a result
     /// eventually containing the message's output.
     fn handle(&mut self, msg: M, ctx: &mut Self::Context) -> Result<Self::Output, Self::Error>;
+
+    #[allow(unused)]
+    /// Returns the priority with which the message
+    /// should be sent to the actor (only used if
+    /// the actor's message channel is prioritized,
+    /// messages with a higher priority being handled
+    /// first).
+    fn priority(msg: &M) -> usize {
+        0
+    }
 }
 
 impl<A: Actor> Handler<()> for A {
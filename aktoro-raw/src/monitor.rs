@@ -0,0 +1,236 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// A message sent to an actor monitoring
+/// (or linked to) another actor when the
+/// latter died.
+///
+/// `E` is the type of the error returned
+/// by the runtime when an actor fails (see
+/// [`Context::monitor`]).
+///
+/// [`Context::monitor`]: trait.Context.html#method.monitor
+pub struct Down<E> {
+    /// The identifier of the actor that
+    /// died.
+    id: u64,
+    /// The error that made the actor die
+    /// or `None` if it stopped.
+    reason: Option<E>,
+}
+
+/// The function notifying an actor that an
+/// actor it watches died (with the error
+/// returned by the runtime, boxed, if it
+/// failed) and whether it is linked to it.
+type Notify = Arc<dyn Fn(Down<Box<dyn Any + Send + Sync>>, bool) + Send + Sync>;
+
+#[derive(Clone, Default)]
+/// A table of the actors watching (monitoring
+/// or being linked to) other actors, shared
+/// by a runtime, its actors' contexts and
+/// their inner runtimes (see
+/// [`Runtime::set_monitors`]), which allows
+/// actors to watch any other actor of the
+/// tree.
+///
+/// The runtimes notify it when one of their
+/// actors dies, and it notifies the actors
+/// watching it.
+///
+/// [`Runtime::set_monitors`]: trait.Runtime.html#tymethod.set_monitors
+pub struct Monitors(Arc<Mutex<Table>>);
+
+#[derive(Default)]
+struct Table {
+    /// A map matching the identifier of the
+    /// running actors with the function used
+    /// to notify them.
+    actors: HashMap<u64, Notify>,
+    /// A map matching an actor's identifier
+    /// with the identifiers of the actors
+    /// watching it and whether they are
+    /// linked to it.
+    watchers: HashMap<u64, Vec<(u64, bool)>>,
+}
+
+impl<E> Down<E> {
+    /// Creates a new message notifying
+    /// that the actor with the given
+    /// identifier died, eventually because
+    /// of an error.
+    pub fn new(id: u64, reason: Option<E>) -> Self {
+        Down { id, reason }
+    }
+
+    /// Returns the identifier of the actor
+    /// that died.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns a reference to the error
+    /// that made the actor die or `None`
+    /// if it stopped.
+    pub fn reason(&self) -> Option<&E> {
+        self.reason.as_ref()
+    }
+
+    /// Returns the error that made the
+    /// actor die or `None` if it stopped,
+    /// consuming the message.
+    pub fn into_reason(self) -> Option<E> {
+        self.reason
+    }
+
+    /// Whether the actor died because it
+    /// failed.
+    pub fn is_failure(&self) -> bool {
+        self.reason.is_some()
+    }
+}
+
+impl Monitors {
+    /// Creates a new empty table.
+    pub fn new() -> Self {
+        Monitors::default()
+    }
+
+    /// Adds a running actor to the table,
+    /// allowing it to watch other actors and
+    /// to be watched, `notify` being called
+    /// when one of the actors it watches dies.
+    pub fn register<F>(&self, id: u64, notify: F)
+    where
+        F: Fn(Down<Box<dyn Any + Send + Sync>>, bool) + Send + Sync + 'static,
+    {
+        let mut table = self.0.lock().unwrap();
+        table.actors.insert(id, Arc::new(notify));
+    }
+
+    /// Whether the actor with the given
+    /// identifier is running (and can thus
+    /// be watched).
+    pub fn contains(&self, id: u64) -> bool {
+        self.0.lock().unwrap().actors.contains_key(&id)
+    }
+
+    /// Makes the actor `watcher` monitor the
+    /// actor `id` (or link both actors if
+    /// `link` is `true`), returning whether
+    /// both actors are running.
+    pub fn watch(&self, watcher: u64, id: u64, link: bool) -> bool {
+        let mut table = self.0.lock().unwrap();
+        if !table.actors.contains_key(&watcher) || !table.actors.contains_key(&id) {
+            return false;
+        }
+
+        table.add(id, watcher, link);
+
+        // Links are bidirectional.
+        if link {
+            table.add(watcher, id, true);
+        }
+
+        true
+    }
+
+    /// Makes the actor `watcher` stop
+    /// monitoring (or being linked to) the
+    /// actor `id`.
+    pub fn unwatch(&self, watcher: u64, id: u64) {
+        let mut table = self.0.lock().unwrap();
+        let link = table.remove(id, watcher);
+
+        // We also remove the link in the
+        // other direction.
+        if link {
+            table.remove(watcher, id);
+        }
+    }
+
+    /// Makes the actor `watcher` stop
+    /// monitoring (and being linked to) all
+    /// the actors it watches (e.g. because it
+    /// is being restarted).
+    pub fn unwatch_all(&self, watcher: u64) {
+        let mut table = self.0.lock().unwrap();
+
+        for watchers in table.watchers.values_mut() {
+            watchers.retain(|watching| watching.0 != watcher);
+        }
+
+        if let Some(watchers) = table.watchers.get_mut(&watcher) {
+            watchers.retain(|watching| !watching.1);
+        }
+    }
+
+    /// Removes the actor with the given
+    /// identifier from the table (because it
+    /// died), notifying the actors that were
+    /// watching it with the reasons returned
+    /// by `reason` (`None` if it stopped).
+    pub fn down<F>(&self, id: u64, reason: F)
+    where
+        F: Fn() -> Option<Box<dyn Any + Send + Sync>>,
+    {
+        let to_notify = {
+            let mut table = self.0.lock().unwrap();
+            table.actors.remove(&id);
+
+            for watchers in table.watchers.values_mut() {
+                watchers.retain(|watching| watching.0 != id);
+            }
+
+            let watchers = table.watchers.remove(&id).unwrap_or_default();
+            watchers
+                .into_iter()
+                .filter_map(|(watcher, link)| {
+                    table
+                        .actors
+                        .get(&watcher)
+                        .map(|notify| (notify.clone(), link))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // We notify the watchers after having
+        // released the lock, as they might
+        // want to use the table.
+        for (notify, link) in to_notify {
+            notify(Down::new(id, reason()), link);
+        }
+    }
+}
+
+impl Table {
+    /// Makes `watcher` watch `id`, linking
+    /// it if `link` is `true`.
+    fn add(&mut self, id: u64, watcher: u64, link: bool) {
+        let watchers = self.watchers.entry(id).or_default();
+
+        if let Some(watching) = watchers.iter_mut().find(|watching| watching.0 == watcher) {
+            watching.1 |= link;
+        } else {
+            watchers.push((watcher, link));
+        }
+    }
+
+    /// Makes `watcher` stop watching `id`,
+    /// returning whether it was linked to it.
+    fn remove(&mut self, id: u64, watcher: u64) -> bool {
+        let watchers = if let Some(watchers) = self.watchers.get_mut(&id) {
+            watchers
+        } else {
+            return false;
+        };
+
+        if let Some(pos) = watchers.iter().position(|watching| watching.0 == watcher) {
+            watchers.remove(pos).1
+        } else {
+            false
+        }
+    }
+}
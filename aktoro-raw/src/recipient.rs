@@ -0,0 +1,136 @@
+use std::error;
+use std::future::Future;
+use std::marker::PhantomData;
+
+use crate::ask::Ask;
+use crate::channel::Sender;
+use crate::channel::SenderRes;
+use crate::message::Handler;
+
+/// The error returned by [`Recipient::try_send`]
+/// and [`Recipient::ask`] (the error returned
+/// by the actor's message channel, boxed).
+///
+/// [`Recipient::try_send`]: struct.Recipient.html#method.try_send
+/// [`Recipient::ask`]: struct.Recipient.html#method.ask
+pub type RecipientError = Box<dyn error::Error + Send>;
+
+/// A type-erased message channel sender,
+/// allowing to send messages of type `M` to
+/// an actor whose handler outputs `O`
+/// without knowing the actor's type.
+///
+/// It can be created from any [`Spawned`]
+/// (see [`Spawned::recipient`]) or message
+/// channel sender.
+///
+/// [`Spawned`]: struct.Spawned.html
+/// [`Spawned::recipient`]: struct.Spawned.html#method.recipient
+pub struct Recipient<M, O = ()> {
+    /// The actor's identifier.
+    actor_id: u64,
+    /// The actor's message channel's
+    /// sender.
+    sender: Box<dyn RecipientSender<M, O>>,
+}
+
+/// A message channel sender for messages of
+/// type `M`, used by [`Recipient`] to erase
+/// the actor's type.
+///
+/// [`Recipient`]: struct.Recipient.html
+trait RecipientSender<M, O>: Send {
+    /// Tries to send a message to be handled
+    /// by the actor.
+    fn try_send(&mut self, msg: M) -> SenderRes<O, RecipientError>;
+
+    /// Clones the sender into a new box.
+    fn boxed_clone(&self) -> Box<dyn RecipientSender<M, O>>;
+}
+
+/// A wrapper around an actor's message
+/// channel sender, implementing
+/// `RecipientSender` for all the messages
+/// that the actor can handle.
+struct Wrapper<A, S> {
+    sender: S,
+    _act: PhantomData<fn() -> A>,
+}
+
+impl<M, O> Recipient<M, O>
+where
+    M: Send + 'static,
+{
+    /// Creates a new recipient from the
+    /// message channel sender of the actor
+    /// with the given identifier.
+    pub fn new<A, S>(actor_id: u64, sender: S) -> Self
+    where
+        A: Handler<M, Output = O> + 'static,
+        S: Sender<A> + 'static,
+    {
+        Recipient {
+            actor_id,
+            sender: Box::new(Wrapper {
+                sender,
+                _act: PhantomData,
+            }),
+        }
+    }
+
+    /// Returns the actor's identifier.
+    pub fn actor_id(&self) -> u64 {
+        self.actor_id
+    }
+
+    /// Tries to send a message over the actor's
+    /// message channel, returning a future
+    /// resolving with the result returned by the
+    /// message handler.
+    pub fn try_send(&mut self, msg: M) -> SenderRes<O, RecipientError> {
+        self.sender.try_send(msg)
+    }
+
+    /// Tries to send a message over the actor's
+    /// message channel, returning a future
+    /// resolving with the result returned by the
+    /// message handler, or an error if the actor
+    /// died, the handler failed or `timeout`
+    /// resolved first.
+    pub fn ask<T>(&mut self, msg: M, timeout: T) -> Result<Ask<O>, RecipientError>
+    where
+        T: Future<Output = ()> + Send + 'static,
+    {
+        Ok(Ask::new(self.sender.try_send(msg)?, timeout))
+    }
+}
+
+impl<A, S, M> RecipientSender<M, A::Output> for Wrapper<A, S>
+where
+    A: Handler<M> + 'static,
+    S: Sender<A> + 'static,
+    M: Send + 'static,
+{
+    fn try_send(&mut self, msg: M) -> SenderRes<A::Output, RecipientError> {
+        match self.sender.try_send(msg) {
+            Ok(recv) => Ok(recv),
+            Err(err) => Err(Box::new(err)),
+        }
+    }
+
+    fn boxed_clone(&self) -> Box<dyn RecipientSender<M, A::Output>> {
+        Box::new(Wrapper {
+            sender: self.sender.clone(),
+            _act: PhantomData,
+        })
+    }
+}
+
+impl<M, O> Clone for Recipient<M, O> {
+    fn clone(&self) -> Self {
+        Recipient {
+            actor_id: self.actor_id,
+            sender: self.sender.boxed_clone(),
+        }
+    }
+}
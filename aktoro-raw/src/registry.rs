@@ -0,0 +1,198 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::actor::Actor;
+use crate::spawned::Spawned;
+use crate::spawned::WeakSpawned;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+/// An error returned when registering an
+/// actor under a name failed.
+pub struct RegistryError {
+    /// The name under which the actor
+    /// should have been registered.
+    name: String,
+    kind: RegistryErrorKind,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum RegistryErrorKind {
+    /// Another actor is already registered
+    /// under the name (its identifier is
+    /// provided).
+    Taken(u64),
+    /// The actor isn't running on the
+    /// runtime (or context) it should have
+    /// been registered on.
+    Unknown,
+}
+
+#[derive(Clone, Default)]
+/// A map matching names with the actors
+/// registered under them, allowing to
+/// look them up to get a [`Spawned`],
+/// shared by a runtime, its actors'
+/// contexts and their inner runtimes (see
+/// [`Runtime::set_registry`]).
+///
+/// It only holds weak references to the
+/// actors, so that registering an actor
+/// doesn't keep it alive.
+///
+/// [`Spawned`]: struct.Spawned.html
+/// [`Runtime::set_registry`]: trait.Runtime.html#tymethod.set_registry
+pub struct Registry(Arc<Mutex<Names>>);
+
+#[derive(Default)]
+struct Names {
+    /// A map matching a name with the
+    /// identifier of the actor registered
+    /// under it and a boxed `WeakSpawned`
+    /// referencing it.
+    names: HashMap<String, (u64, Box<dyn Any + Send>)>,
+}
+
+impl RegistryError {
+    /// Creates a new "name already taken"
+    /// error.
+    pub fn taken(name: String, id: u64) -> Self {
+        RegistryError {
+            name,
+            kind: RegistryErrorKind::Taken(id),
+        }
+    }
+
+    /// Creates a new "unknown actor" error.
+    pub fn unknown(name: String) -> Self {
+        RegistryError {
+            name,
+            kind: RegistryErrorKind::Unknown,
+        }
+    }
+
+    /// Returns the name under which the
+    /// actor should have been registered.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the error occured because
+    /// another actor is already registered
+    /// under the name.
+    pub fn is_taken(&self) -> bool {
+        if let RegistryErrorKind::Taken(_) = self.kind {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the error occured because
+    /// the actor isn't running.
+    pub fn is_unknown(&self) -> bool {
+        self.kind == RegistryErrorKind::Unknown
+    }
+
+    /// Returns a reference to the error's
+    /// kind.
+    pub fn kind(&self) -> &RegistryErrorKind {
+        &self.kind
+    }
+}
+
+impl Registry {
+    /// Creates a new empty registry.
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Registers the actor linked to
+    /// `spawned` under `name`, returning an
+    /// error if another actor is already
+    /// registered under it.
+    pub fn register<A>(&self, name: &str, spawned: &Spawned<A>) -> Result<(), RegistryError>
+    where
+        A: Actor + 'static,
+    {
+        let mut names = self.0.lock().unwrap();
+        if let Some((id, _)) = names.names.get(name) {
+            return Err(RegistryError::taken(name.to_string(), *id));
+        }
+
+        names.names.insert(
+            name.to_string(),
+            (spawned.actor_id(), Box::new(spawned.downgrade())),
+        );
+
+        Ok(())
+    }
+
+    /// Returns a [`Spawned`] for the actor
+    /// registered under `name`, or `None` if
+    /// there is no such actor, if it isn't
+    /// of type `A` or if it stopped.
+    ///
+    /// [`Spawned`]: struct.Spawned.html
+    pub fn lookup<A>(&self, name: &str) -> Option<Spawned<A>>
+    where
+        A: Actor + 'static,
+    {
+        let names = self.0.lock().unwrap();
+        names
+            .names
+            .get(name)
+            .and_then(|(_, weak)| weak.downcast_ref::<WeakSpawned<A>>())
+            .and_then(WeakSpawned::upgrade)
+    }
+
+    /// Returns the identifier of the actor
+    /// registered under `name`.
+    pub fn lookup_id(&self, name: &str) -> Option<u64> {
+        self.0.lock().unwrap().names.get(name).map(|(id, _)| *id)
+    }
+
+    /// Unregisters the actor registered
+    /// under `name`, returning its identifier.
+    pub fn unregister(&self, name: &str) -> Option<u64> {
+        self.0.lock().unwrap().names.remove(name).map(|(id, _)| id)
+    }
+
+    /// Unregisters the actor with the given
+    /// identifier from all the names it was
+    /// registered under (e.g. because it
+    /// died).
+    pub fn remove(&self, id: u64) {
+        let mut names = self.0.lock().unwrap();
+        names.names.retain(|_, actor| actor.0 != id);
+    }
+
+    /// Unregisters all the actors.
+    pub fn clear(&self) {
+        self.0.lock().unwrap().names.clear();
+    }
+}
+
+impl error::Error for RegistryError {}
+
+impl Display for RegistryError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            RegistryErrorKind::Taken(id) => write!(
+                fmt,
+                "registering failed because name `{}` already taken by actor {}",
+                self.name, id,
+            ),
+            RegistryErrorKind::Unknown => write!(
+                fmt,
+                "registering under name `{}` failed because actor unknown",
+                self.name,
+            ),
+        }
+    }
+}
@@ -4,8 +4,16 @@ use futures_core::Stream;
 
 use crate::actor::Actor;
 use crate::context::Context;
+use crate::dead_letter::DeadLetterSink;
+use crate::executor::BlockingSpawner;
+use crate::executor::Spawner;
+use crate::monitor::Monitors;
 use crate::net::NetworkManager;
+use crate::registry::Registry;
+use crate::registry::RegistryError;
 use crate::spawned::Spawned;
+use crate::supervisor::Factory;
+use crate::timer::Timer;
 
 pub trait Wait<R: Runtime>: Stream<Item = Result<u64, (u64, R::Error)>> + Unpin + Send {
     /// Returns a reference to the runtime.
@@ -25,6 +33,12 @@ pub trait Runtime: Default + Unpin + Send {
     /// runtime implementation).
     type NetworkManager: NetworkManager;
 
+    /// The type that is allowing actors to
+    /// create timers compatible with the
+    /// runtime (e.g. to send messages after
+    /// a delay or at a fixed interval).
+    type Timer: Timer;
+
     /// The type that is allowing the runtime to
     /// be polled after calling [`wait`].
     ///
@@ -47,7 +61,8 @@ pub trait Runtime: Default + Unpin + Send {
     /// [`Actor::starting`]: trait.Actor.html#method.starting
     fn spawn<A>(&mut self, actor: A) -> Option<Spawned<A>>
     where
-        A: Actor + 'static;
+        A: Actor + Send + 'static,
+        A::Context: Context<A, Timer = Self::Timer> + Send;
 
     /// Spawns a new actor on the runtime,
     /// passing its context the provided config
@@ -60,8 +75,140 @@ pub trait Runtime: Default + Unpin + Send {
     /// [`Actor::starting`]: trait.Actor.html#method.starting
     fn spawn_with<A, C>(&mut self, actor: A, config: C::Config) -> Option<Spawned<A>>
     where
-        A: Actor<Context = C> + 'static,
-        C: Context<A>;
+        A: Actor<Context = C> + Send + 'static,
+        C: Context<A, Timer = Self::Timer> + Send;
+
+    /// Spawns a new actor created by `factory`
+    /// on the runtime, returning
+    /// [`Some(Spawned<A>)`] if it succeeded or
+    /// [`None`] if it failed or if the actor
+    /// stopped itself when [`Actor::starting`]
+    /// was called.
+    ///
+    /// When the actor fails, instead of dying,
+    /// it waits to be restarted (see [`restart`])
+    /// or killed.
+    ///
+    /// [`Some(Spawned<A>)`]: sturct.Spawned.html
+    /// [`Actor::starting`]: trait.Actor.html#method.starting
+    /// [`restart`]: #method.restart
+    fn spawn_restartable<A, F>(&mut self, factory: F) -> Option<Spawned<A>>
+    where
+        A: Actor + Send + 'static,
+        A::Context: Context<A, Timer = Self::Timer> + Send,
+        F: Factory<A>;
+
+    /// Spawns a new actor created by `factory`
+    /// on the runtime, passing its context the
+    /// provided config and returning
+    /// [`Some(Spawned<A>)`] if it succeeded or
+    /// [`None`] if it failed or if the actor
+    /// stopped itself when [`Actor::starting`]
+    /// was called.
+    ///
+    /// When the actor fails, instead of dying,
+    /// it waits to be restarted (see [`restart`])
+    /// or killed.
+    ///
+    /// [`Some(Spawned<A>)`]: sturct.Spawned.html
+    /// [`Actor::starting`]: trait.Actor.html#method.starting
+    /// [`restart`]: #method.restart
+    fn spawn_restartable_with<A, C, F>(
+        &mut self,
+        factory: F,
+        config: C::Config,
+    ) -> Option<Spawned<A>>
+    where
+        A: Actor<Context = C> + Send + 'static,
+        C: Context<A, Timer = Self::Timer> + Send,
+        F: Factory<A>;
+
+    /// Asks the actor with the given identifier
+    /// to restart, replacing it with a new actor
+    /// created by its factory while keeping its
+    /// channels, and returning whether it was
+    /// spawned as restartable.
+    fn restart(&mut self, id: u64) -> bool;
+
+    /// Registers the actor linked to `spawned`
+    /// under `name`, returning an error if
+    /// another actor is already registered under
+    /// it or if the actor isn't running on the
+    /// runtime.
+    ///
+    /// The actor is automatically unregistered
+    /// when it dies.
+    fn register<A>(&mut self, name: &str, spawned: &Spawned<A>) -> Result<(), RegistryError>
+    where
+        A: Actor + 'static;
+
+    /// Returns a [`Spawned`] for the actor
+    /// registered under `name`, or [`None`] if
+    /// there is no such actor or if it isn't of
+    /// type `A`.
+    ///
+    /// [`Spawned`]: struct.Spawned.html
+    fn lookup<A>(&self, name: &str) -> Option<Spawned<A>>
+    where
+        A: Actor + 'static;
+
+    /// Unregisters the actor registered under
+    /// `name`, returning its identifier.
+    fn unregister(&mut self, name: &str) -> Option<u64>;
+
+    /// Sets the sink that the runtime's actors
+    /// (and their sub-actors) will report the
+    /// messages that couldn't be delivered to
+    /// them to, for the actors spawned after
+    /// the call.
+    fn set_dead_letters(&mut self, sink: DeadLetterSink);
+
+    /// Sets the spawner that the runtime spawns
+    /// its actors (and their sub-actors) on, for
+    /// the actors spawned after the call.
+    fn set_spawner(&mut self, spawner: Spawner);
+
+    /// Sets the spawner that the runtime's actors
+    /// (and their sub-actors) run the closures
+    /// passed to [`Context::spawn_blocking`] on,
+    /// for the actors spawned after the call.
+    ///
+    /// [`Context::spawn_blocking`]: trait.Context.html#tymethod.spawn_blocking
+    fn set_blocking_spawner(&mut self, spawner: BlockingSpawner);
+
+    /// Returns the spawner that the runtime's
+    /// actors run their blocking closures on
+    /// (which should be bounded).
+    fn blocking_spawner(&self) -> BlockingSpawner;
+
+    /// Sets the table that the runtime's actors
+    /// (and their sub-actors) use to watch the
+    /// other actors, and that the runtime
+    /// notifies when they die, for the actors
+    /// spawned after the call.
+    ///
+    /// Sharing a table between runtimes allows
+    /// their actors to watch each other.
+    fn set_monitors(&mut self, monitors: Monitors);
+
+    /// Sets the registry that the runtime's
+    /// actors are registered in, and that they
+    /// (and their sub-actors) use to look up
+    /// the other actors, for the actors spawned
+    /// after the call.
+    ///
+    /// Sharing a registry between runtimes
+    /// allows their actors to look each other
+    /// up.
+    fn set_registry(&mut self, registry: Registry);
+
+    /// Sets the timer that the runtime's actors
+    /// (and their sub-actors) use to schedule
+    /// messages, for the actors spawned after
+    /// the call (and that [`timer`] returns).
+    ///
+    /// [`timer`]: #tymethod.timer
+    fn set_timer(&mut self, timer: Self::Timer);
 
     /// Creates a new network manager, that
     /// can then be used by an actor to
@@ -69,6 +216,12 @@ pub trait Runtime: Default + Unpin + Send {
     /// an UDP socket.
     fn net(&mut self) -> Self::NetworkManager;
 
+    /// Returns the runtime's timer, that can
+    /// then be used to create futures and
+    /// streams resolving after a delay or at
+    /// a fixed interval.
+    fn timer(&mut self) -> Self::Timer;
+
     /// Returns a stream allowing to poll the
     /// runtime's actors.
     ///
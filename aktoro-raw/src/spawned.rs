@@ -1,24 +1,32 @@
+use std::future::Future;
 use std::pin::Pin;
 use std::task;
 use std::task::Poll;
+use std::time::Duration;
 
 use futures_core::Stream;
 
 use crate::action::ActionHandler;
 use crate::actor::Actor;
+use crate::ask::Ask;
+use crate::channel::MailboxStats;
 use crate::channel::Sender as RawSender;
+use crate::channel::SenderFut;
 use crate::channel::SenderRes;
 use crate::context::Context;
 use crate::control::Controller as RawController;
 use crate::control::ControllerRes;
 use crate::message::Handler;
+use crate::recipient::Recipient;
 use crate::update::Updater;
 
 type Sender<A> = <<A as Actor>::Context as Context<A>>::Sender;
 type SenderError<A> = <Sender<A> as RawSender<A>>::Error;
+type WeakSender<A> = <Sender<A> as RawSender<A>>::Weak;
 
 type Controller<A> = <<A as Actor>::Context as Context<A>>::Controller;
 type ControllerError<A> = <Controller<A> as RawController<A>>::Error;
+type WeakController<A> = <Controller<A> as RawController<A>>::Weak;
 
 type Update<A> = <<<A as Actor>::Context as Context<A>>::Updater as Updater<A>>::Update;
 type Updated<A> = <<<A as Actor>::Context as Context<A>>::Updater as Updater<A>>::Updated;
@@ -27,6 +35,8 @@ type Updated<A> = <<<A as Actor>::Context as Context<A>>::Updater as Updater<A>>
 /// message, control and update
 /// channels.
 pub struct Spawned<A: Actor> {
+    /// The actor's identifier.
+    actor_id: u64,
     /// The actor's message channel's
     /// sender.
     sender: Sender<A>,
@@ -38,11 +48,32 @@ pub struct Spawned<A: Actor> {
     updted: Option<Updated<A>>,
 }
 
+/// A weak version of [`Spawned`], that doesn't
+/// keep the actor's message channel open (thus
+/// allowing the actor to stop once all of its
+/// strong handles are dropped, if it was
+/// configured to do so), but that can be
+/// upgraded to a [`Spawned`] while the actor
+/// is still referenced.
+///
+/// [`Spawned`]: struct.Spawned.html
+pub struct WeakSpawned<A: Actor> {
+    /// The actor's identifier.
+    actor_id: u64,
+    /// A weak reference to the actor's
+    /// message channel.
+    sender: WeakSender<A>,
+    /// A weak reference to the actor's
+    /// control channel.
+    ctrler: WeakController<A>,
+}
+
 impl<A: Actor> Spawned<A> {
     /// Creates a new `Spawned` struct from an actor's
     /// context.
     pub fn new(ctx: &mut A::Context) -> Self {
         Spawned {
+            actor_id: ctx.actor_id(),
             sender: ctx.sender().clone(),
             ctrler: ctx.controller().clone(),
             updted: Some(
@@ -52,6 +83,11 @@ impl<A: Actor> Spawned<A> {
         }
     }
 
+    /// Returns the actor's identifier.
+    pub fn actor_id(&self) -> u64 {
+        self.actor_id
+    }
+
     /// Tries to send a message over the actor's
     /// message channel, returning a future
     /// resolving with the result returned by the
@@ -64,6 +100,87 @@ impl<A: Actor> Spawned<A> {
         self.sender.try_send(msg)
     }
 
+    /// Tries to send a message over the actor's
+    /// message channel with the given priority
+    /// (overriding the one returned by
+    /// [`Handler::priority`]), returning a future
+    /// resolving with the result returned by the
+    /// message handler.
+    ///
+    /// [`Handler::priority`]: trait.Handler.html#method.priority
+    pub fn try_send_msg_prio<M>(
+        &mut self,
+        msg: M,
+        prio: usize,
+    ) -> SenderRes<A::Output, SenderError<A>>
+    where
+        A: Handler<M>,
+        M: Send + 'static,
+    {
+        self.sender.try_send_prio(msg, prio)
+    }
+
+    /// Tries to send a message over the actor's
+    /// message channel that will expire after
+    /// `ttl` has elapsed if it hasn't been handled
+    /// yet, returning a future resolving with the
+    /// result returned by the message handler (or
+    /// with an error if the message expired).
+    pub fn try_send_msg_ttl<M>(
+        &mut self,
+        msg: M,
+        ttl: Duration,
+    ) -> SenderRes<A::Output, SenderError<A>>
+    where
+        A: Handler<M>,
+        M: Send + 'static,
+    {
+        self.sender.try_send_ttl(msg, ttl)
+    }
+
+    /// Sends a message over the actor's message
+    /// channel, returning a future resolving
+    /// once the message has been sent (waiting
+    /// for the channel to have free space if it
+    /// is bounded and full) with a future
+    /// resolving with the result returned by the
+    /// message handler.
+    pub fn send_msg<M>(&mut self, msg: M) -> SenderFut<A::Output, SenderError<A>>
+    where
+        A: Handler<M>,
+        M: Send + 'static,
+    {
+        self.sender.send(msg)
+    }
+
+    /// Returns a snapshot of the state of the
+    /// actor's message channel (e.g. to monitor
+    /// how many messages are waiting to be
+    /// handled).
+    pub fn mailbox_stats(&self) -> MailboxStats {
+        self.sender.stats()
+    }
+
+    /// Sends a message over the actor's message
+    /// channel, blocking the current thread until
+    /// it has been sent (waiting for the channel
+    /// to have free space if it is bounded and
+    /// full), and returning a future resolving
+    /// with the result returned by the message
+    /// handler.
+    ///
+    /// This allows to send messages to the actor
+    /// from threads that aren't running an
+    /// executor (the returned future can be
+    /// dropped if the result isn't needed).
+    pub fn send_blocking<M>(&mut self, msg: M) -> SenderRes<A::Output, SenderError<A>>
+    where
+        A: Handler<M>,
+        M: Send + 'static,
+    {
+        self.sender.send_blocking(msg)
+    }
+
     /// Tries send an action over the actor's
     /// control channel, returning a future resolving
     /// with the result returned by the action
@@ -76,6 +193,66 @@ impl<A: Actor> Spawned<A> {
         self.ctrler.try_send(action)
     }
 
+    /// Tries to send a message over the actor's
+    /// message channel, returning a future
+    /// resolving with the result returned by the
+    /// message handler, or an error if the actor
+    /// died, the handler failed or `timeout`
+    /// resolved first.
+    pub fn ask<M, T>(&mut self, msg: M, timeout: T) -> Result<Ask<A::Output>, SenderError<A>>
+    where
+        A: Handler<M>,
+        M: Send + 'static,
+        T: Future<Output = ()> + Send + 'static,
+    {
+        Ok(Ask::new(self.sender.try_send(msg)?, timeout))
+    }
+
+    /// Tries to send an action over the actor's
+    /// control channel, returning a future
+    /// resolving with the result returned by the
+    /// action handler, or an error if the actor
+    /// died, the handler failed or `timeout`
+    /// resolved first.
+    pub fn ask_action<D, T>(
+        &mut self,
+        action: D,
+        timeout: T,
+    ) -> Result<Ask<A::Output>, ControllerError<A>>
+    where
+        A: ActionHandler<D>,
+        D: Send + 'static,
+        T: Future<Output = ()> + Send + 'static,
+    {
+        Ok(Ask::new(self.ctrler.try_send(action)?, timeout))
+    }
+
+    /// Returns a new [`Recipient`] allowing to
+    /// send messages of type `M` to the actor
+    /// without knowing its type.
+    ///
+    /// [`Recipient`]: struct.Recipient.html
+    pub fn recipient<M>(&self) -> Recipient<M, A::Output>
+    where
+        A: Handler<M> + 'static,
+        M: Send + 'static,
+    {
+        Recipient::new(self.actor_id, self.sender.clone())
+    }
+
+    /// Creates a [`WeakSpawned`] referencing the
+    /// same actor, that doesn't keep its message
+    /// channel open.
+    ///
+    /// [`WeakSpawned`]: struct.WeakSpawned.html
+    pub fn downgrade(&self) -> WeakSpawned<A> {
+        WeakSpawned {
+            actor_id: self.actor_id,
+            sender: self.sender.downgrade(),
+            ctrler: self.ctrler.downgrade(),
+        }
+    }
+
     /// Returns a reference to the actor's message
     /// channel sender.
     pub fn sender(&self) -> &Sender<A> {
@@ -101,6 +278,28 @@ impl<A: Actor> Spawned<A> {
     }
 }
 
+impl<A: Actor> WeakSpawned<A> {
+    /// Returns the actor's identifier.
+    pub fn actor_id(&self) -> u64 {
+        self.actor_id
+    }
+
+    /// Tries to upgrade the weak handle to a
+    /// [`Spawned`], returning `None` if the actor's
+    /// message channel is closed or if it isn't
+    /// referenced anymore.
+    ///
+    /// [`Spawned`]: struct.Spawned.html
+    pub fn upgrade(&self) -> Option<Spawned<A>> {
+        Some(Spawned {
+            actor_id: self.actor_id,
+            sender: <Sender<A> as RawSender<A>>::upgrade(&self.sender)?,
+            ctrler: <Controller<A> as RawController<A>>::upgrade(&self.ctrler)?,
+            updted: None,
+        })
+    }
+}
+
 impl<A: Actor> Unpin for Spawned<A> {}
 
 impl<A: Actor> Stream for Spawned<A> {
@@ -118,9 +317,20 @@ impl<A: Actor> Stream for Spawned<A> {
 impl<A: Actor> Clone for Spawned<A> {
     fn clone(&self) -> Self {
         Spawned {
+            actor_id: self.actor_id,
             sender: self.sender.clone(),
             ctrler: self.ctrler.clone(),
             updted: None,
         }
     }
 }
+
+impl<A: Actor> Clone for WeakSpawned<A> {
+    fn clone(&self) -> Self {
+        WeakSpawned {
+            actor_id: self.actor_id,
+            sender: self.sender.clone(),
+            ctrler: self.ctrler.clone(),
+        }
+    }
+}
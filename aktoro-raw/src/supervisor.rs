@@ -0,0 +1,351 @@
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::actor::Actor;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+/// The strategy used by a [`Supervisor`]
+/// to decide which children to restart
+/// when one of them fails.
+///
+/// [`Supervisor`]: struct.Supervisor.html
+pub enum Strategy {
+    /// Only the child that failed is
+    /// restarted.
+    OneForOne,
+    /// All the children are restarted.
+    OneForAll,
+    /// The child that failed and all the
+    /// children that were spawned after it
+    /// are restarted.
+    RestForOne,
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+/// The maximum number of restarts that
+/// a [`Supervisor`] is allowed to do in
+/// a given period of time before giving
+/// up and escalating the failure.
+///
+/// [`Supervisor`]: struct.Supervisor.html
+pub struct Intensity {
+    /// The maximum number of restarts.
+    restarts: usize,
+    /// The period of time during which
+    /// the restarts are counted.
+    period: Duration,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+/// The decision taken by a [`Supervisor`]
+/// after one of its children failed.
+///
+/// [`Supervisor`]: struct.Supervisor.html
+pub enum Directive {
+    /// The children with the given
+    /// identifiers should be restarted
+    /// (in this order).
+    Restart(Vec<u64>),
+    /// The restart intensity has been
+    /// exceeded and the supervisor should
+    /// stop all its children and fail.
+    Escalate,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+/// The error that an actor fails with when
+/// its supervisor gave up restarting one of
+/// its children or when an actor it was
+/// linked to failed.
+pub struct SupervisionError {
+    kind: SupervisionErrorKind,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum SupervisionErrorKind {
+    /// The child with the given identifier
+    /// failed after the restart intensity
+    /// had been exceeded.
+    Escalated(u64),
+    /// The actor with the given identifier,
+    /// that the actor was linked to, failed.
+    Linked(u64),
+}
+
+/// The supervision state of an actor,
+/// keeping track of its children and of
+/// the restarts it did, and deciding what
+/// to do when one of its children fails.
+pub struct Supervisor {
+    /// The strategy used to decide which
+    /// children to restart.
+    strategy: Strategy,
+    /// The maximum number of restarts
+    /// allowed in a period of time.
+    intensity: Intensity,
+    /// The supervised children's identifier,
+    /// in the order they were spawned, and
+    /// whether they can be restarted.
+    children: Vec<(u64, bool)>,
+    /// The moments at which the recent
+    /// restarts happened.
+    restarts: VecDeque<Instant>,
+}
+
+/// A factory used to create a new actor
+/// every time it needs to be (re)started.
+pub trait Factory<A: Actor>: Send + 'static {
+    /// Creates a new actor.
+    fn create(&self) -> A;
+}
+
+impl Intensity {
+    /// Creates a new intensity allowing at
+    /// most `restarts` restarts in `period`.
+    pub fn new(restarts: usize, period: Duration) -> Self {
+        Intensity { restarts, period }
+    }
+
+    /// Returns the maximum number of
+    /// restarts.
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Returns the period of time during
+    /// which the restarts are counted.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+}
+
+impl SupervisionError {
+    /// Creates a new "failure escalated"
+    /// error, caused by the child with the
+    /// given identifier.
+    pub fn escalated(id: u64) -> Self {
+        SupervisionError {
+            kind: SupervisionErrorKind::Escalated(id),
+        }
+    }
+
+    /// Creates a new "linked actor failed"
+    /// error, caused by the actor with the
+    /// given identifier.
+    pub fn linked(id: u64) -> Self {
+        SupervisionError {
+            kind: SupervisionErrorKind::Linked(id),
+        }
+    }
+
+    /// Returns the identifier of the actor
+    /// whose failure caused the error.
+    pub fn actor_id(&self) -> u64 {
+        match self.kind {
+            SupervisionErrorKind::Escalated(id) | SupervisionErrorKind::Linked(id) => id,
+        }
+    }
+
+    /// Whether the error occured because a
+    /// child failed too many times.
+    pub fn is_escalated(&self) -> bool {
+        if let SupervisionErrorKind::Escalated(_) = self.kind {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the error occured because a
+    /// linked actor failed.
+    pub fn is_linked(&self) -> bool {
+        if let SupervisionErrorKind::Linked(_) = self.kind {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns a reference to the error's
+    /// kind.
+    pub fn kind(&self) -> &SupervisionErrorKind {
+        &self.kind
+    }
+}
+
+impl Supervisor {
+    /// Creates a new supervisor using the
+    /// provided strategy and intensity.
+    pub fn new(strategy: Strategy, intensity: Intensity) -> Self {
+        Supervisor {
+            strategy,
+            intensity,
+            children: vec![],
+            restarts: VecDeque::new(),
+        }
+    }
+
+    /// Returns the strategy used to decide
+    /// which children to restart.
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    /// Sets the strategy used to decide
+    /// which children to restart.
+    pub fn set_strategy(&mut self, strategy: Strategy) {
+        self.strategy = strategy;
+    }
+
+    /// Returns the maximum number of
+    /// restarts allowed in a period of time.
+    pub fn intensity(&self) -> Intensity {
+        self.intensity
+    }
+
+    /// Sets the maximum number of restarts
+    /// allowed in a period of time.
+    pub fn set_intensity(&mut self, intensity: Intensity) {
+        self.intensity = intensity;
+    }
+
+    /// Returns the identifiers of the
+    /// supervised children, in the order
+    /// they were spawned.
+    pub fn children(&self) -> Vec<u64> {
+        self.children.iter().map(|child| child.0).collect()
+    }
+
+    /// Starts supervising a child, which
+    /// will be restarted when needed if
+    /// `restartable` is `true`.
+    pub fn supervise(&mut self, id: u64, restartable: bool) {
+        self.children.push((id, restartable));
+    }
+
+    /// Stops supervising a child (e.g.
+    /// because it stopped).
+    pub fn remove(&mut self, id: u64) {
+        self.children.retain(|child| child.0 != id);
+    }
+
+    /// Stops supervising all the children
+    /// and forgets about the recent
+    /// restarts.
+    pub fn clear(&mut self) {
+        self.children.clear();
+        self.restarts.clear();
+    }
+
+    /// Decides what should be done after
+    /// the child with the given identifier
+    /// failed.
+    ///
+    /// ## Note
+    ///
+    /// Children that can't be restarted
+    /// are removed and never cause other
+    /// children to be restarted.
+    pub fn failed(&mut self, id: u64) -> Directive {
+        let pos = if let Some(pos) = self.children.iter().position(|child| child.0 == id) {
+            pos
+        } else {
+            return Directive::Restart(vec![]);
+        };
+
+        // If the child can't be restarted,
+        // we stop supervising it.
+        if !self.children[pos].1 {
+            self.children.remove(pos);
+            return Directive::Restart(vec![]);
+        }
+
+        // We forget about the restarts that
+        // happened before the intensity's
+        // period...
+        let now = Instant::now();
+        while let Some(restart) = self.restarts.front() {
+            if now.duration_since(*restart) > self.intensity.period {
+                self.restarts.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        // ...and escalate the failure if
+        // too many restarts happened during
+        // it.
+        if self.restarts.len() >= self.intensity.restarts {
+            return Directive::Escalate;
+        }
+
+        self.restarts.push_back(now);
+
+        let children = match self.strategy {
+            Strategy::OneForOne => return Directive::Restart(vec![id]),
+            Strategy::OneForAll => &self.children[..],
+            Strategy::RestForOne => &self.children[pos..],
+        };
+
+        Directive::Restart(
+            children
+                .iter()
+                .filter(|child| child.1)
+                .map(|child| child.0)
+                .collect(),
+        )
+    }
+}
+
+impl<A, F> Factory<A> for F
+where
+    A: Actor,
+    F: Fn() -> A + Send + 'static,
+{
+    fn create(&self) -> A {
+        self()
+    }
+}
+
+impl error::Error for SupervisionError {}
+
+impl Display for SupervisionError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            SupervisionErrorKind::Escalated(id) => write!(
+                fmt,
+                "supervision failed because child {} failed too many times",
+                id,
+            ),
+            SupervisionErrorKind::Linked(id) => {
+                write!(fmt, "supervision failed because linked actor {} failed", id)
+            }
+        }
+    }
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Strategy::OneForOne
+    }
+}
+
+impl Default for Intensity {
+    fn default() -> Self {
+        Intensity {
+            restarts: 3,
+            period: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Default for Supervisor {
+    fn default() -> Self {
+        Supervisor::new(Strategy::default(), Intensity::default())
+    }
+}
@@ -0,0 +1,137 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures_core::Stream;
+
+pub trait Timer: Clone + Unpin + Send {
+    /// The type of future returned by [`delay`]
+    /// and [`delay_until`].
+    ///
+    /// [`delay`]: #method.delay
+    /// [`delay_until`]: #method.delay_until
+    type Delay: Future<Output = ()> + Unpin + Send + 'static;
+
+    /// The type of stream returned by
+    /// [`interval`].
+    ///
+    /// [`interval`]: #method.interval
+    type Interval: Stream<Item = ()> + Unpin + Send + 'static;
+
+    /// Creates a future that will resolve
+    /// after `dur` has elapsed.
+    fn delay(&self, dur: Duration) -> Self::Delay;
+
+    /// Creates a future that will resolve
+    /// when `at` is reached.
+    fn delay_until(&self, at: Instant) -> Self::Delay;
+
+    /// Creates a stream that will yield
+    /// every time `dur` has elapsed.
+    fn interval(&self, dur: Duration) -> Self::Interval;
+}
+
+/// A future returned by a timer and
+/// holding a message, resolving with the
+/// message after the timer has elapsed.
+pub struct Delayed<M> {
+    /// The timer's future.
+    delay: Pin<Box<dyn Future<Output = ()> + Send>>,
+    /// The message that will be yielded
+    /// or `None` if it already has been.
+    msg: Option<M>,
+}
+
+/// A stream returned by a timer and
+/// holding a function, yielding the
+/// message it creates every time the
+/// timer elapses.
+pub struct Repeated<F> {
+    /// The timer's stream.
+    interval: Pin<Box<dyn Stream<Item = ()> + Send>>,
+    /// The function creating a new
+    /// message every time the timer
+    /// elapses.
+    make: F,
+}
+
+impl<M> Delayed<M> {
+    /// Creates a new future that will
+    /// resolve with `msg` once `delay` has
+    /// resolved.
+    pub fn new<D>(delay: D, msg: M) -> Self
+    where
+        D: Future<Output = ()> + Send + 'static,
+    {
+        Delayed {
+            delay: Box::pin(delay),
+            msg: Some(msg),
+        }
+    }
+
+    /// Returns the message if it hasn't
+    /// been yielded yet, consuming the
+    /// future (this can be used after
+    /// cancelling it).
+    pub fn into_msg(self) -> Option<M> {
+        self.msg
+    }
+}
+
+impl<F> Repeated<F> {
+    /// Creates a new stream that will yield
+    /// the message created by `make` every
+    /// time `interval` yields.
+    pub fn new<I, M>(interval: I, make: F) -> Self
+    where
+        I: Stream<Item = ()> + Send + 'static,
+        F: Fn() -> M,
+    {
+        Repeated {
+            interval: Box::pin(interval),
+            make,
+        }
+    }
+}
+
+impl<M> Future for Delayed<M> {
+    type Output = M;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<M> {
+        let delayed = self.get_mut();
+
+        match delayed.delay.as_mut().poll(ctx) {
+            Poll::Ready(()) => Poll::Ready(
+                delayed
+                    .msg
+                    .take()
+                    .expect("`Delayed` polled after completion"),
+            ),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<F, M> Stream for Repeated<F>
+where
+    F: Fn() -> M,
+{
+    type Item = M;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<M>> {
+        let repeated = self.get_mut();
+
+        match repeated.interval.as_mut().poll_next(ctx) {
+            Poll::Ready(Some(())) => Poll::Ready(Some((repeated.make)())),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<M> Unpin for Delayed<M> {}
+
+impl<F> Unpin for Repeated<F> {}
@@ -0,0 +1,76 @@
+use std::thread;
+use std::time::Duration;
+
+use aktoro_raw::Directive;
+use aktoro_raw::Intensity;
+use aktoro_raw::Strategy;
+use aktoro_raw::Supervisor;
+
+/// Creates a supervisor supervising three
+/// restartable children (1, 2 and 3).
+fn supervisor(strategy: Strategy, restarts: usize) -> Supervisor {
+    let mut supervisor =
+        Supervisor::new(strategy, Intensity::new(restarts, Duration::from_secs(60)));
+
+    for id in 1..=3 {
+        supervisor.supervise(id, true);
+    }
+
+    supervisor
+}
+
+#[test]
+fn one_for_one_restarts_the_failed_child() {
+    let mut supervisor = supervisor(Strategy::OneForOne, 10);
+
+    assert_eq!(supervisor.failed(2), Directive::Restart(vec![2]));
+}
+
+#[test]
+fn one_for_all_restarts_every_child() {
+    let mut supervisor = supervisor(Strategy::OneForAll, 10);
+
+    assert_eq!(supervisor.failed(2), Directive::Restart(vec![1, 2, 3]));
+}
+
+#[test]
+fn rest_for_one_restarts_the_children_spawned_after() {
+    let mut supervisor = supervisor(Strategy::RestForOne, 10);
+
+    assert_eq!(supervisor.failed(2), Directive::Restart(vec![2, 3]));
+}
+
+#[test]
+fn escalates_once_the_intensity_is_exceeded() {
+    let mut supervisor = supervisor(Strategy::OneForOne, 2);
+
+    assert_eq!(supervisor.failed(1), Directive::Restart(vec![1]));
+    assert_eq!(supervisor.failed(3), Directive::Restart(vec![3]));
+    assert_eq!(supervisor.failed(1), Directive::Escalate);
+}
+
+#[test]
+fn forgets_restarts_outside_of_the_period() {
+    let mut supervisor = Supervisor::new(
+        Strategy::OneForOne,
+        Intensity::new(1, Duration::from_millis(0)),
+    );
+    supervisor.supervise(1, true);
+
+    assert_eq!(supervisor.failed(1), Directive::Restart(vec![1]));
+
+    thread::sleep(Duration::from_millis(5));
+    assert_eq!(supervisor.failed(1), Directive::Restart(vec![1]));
+}
+
+#[test]
+fn removes_children_that_cant_be_restarted() {
+    let mut supervisor = supervisor(Strategy::OneForAll, 10);
+    supervisor.supervise(4, false);
+
+    assert_eq!(supervisor.failed(4), Directive::Restart(vec![]));
+    assert_eq!(supervisor.children(), vec![1, 2, 3]);
+
+    // Unknown children are ignored.
+    assert_eq!(supervisor.failed(4), Directive::Restart(vec![]));
+}
@@ -1,4 +1,6 @@
+use std::any::Any;
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 use std::task;
 use std::task::Poll;
@@ -12,8 +14,10 @@ use aktoro_raw as raw;
 use aktoro_raw::Context as RawContext;
 use aktoro_raw::Status as RawStatus;
 use futures_core::Stream;
+use futures_util::FutureExt;
 
 use crate::error::Error;
+use crate::error::Phase;
 
 /// A wrapper around an actor and its
 /// context.
@@ -24,6 +28,22 @@ pub(crate) struct Actor<A: raw::Actor> {
     started: bool,
     kill: KillRecver,
     killed: KilledSender,
+    /// The factory used to create a new
+    /// actor and a receiver notified when
+    /// the actor should be restarted, if it
+    /// is restartable.
+    restart: Option<(Box<dyn raw::Factory<A>>, RestartRecver)>,
+    /// Whether the actor failed and is
+    /// waiting to be restarted or killed.
+    failed: bool,
+    /// The maximum number of actions, events
+    /// and messages handled by the actor each
+    /// time it is polled.
+    budget: usize,
+    /// What the actor is doing while one of
+    /// its methods or handlers is running,
+    /// recorded if it panics.
+    phase: Option<Phase>,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -62,8 +82,22 @@ pub enum Status {
 pub(crate) struct KillSender(Option<Notify>);
 pub(crate) struct KillRecver(Option<Notify>);
 
-pub(crate) struct KilledSender(Sender<u64>);
-pub(crate) struct KilledRecver(Receiver<u64>);
+pub(crate) struct KilledSender(Sender<(u64, Killed)>);
+pub(crate) struct KilledRecver(Receiver<(u64, Killed)>);
+
+/// A notification sent by an actor over
+/// the killed channel.
+pub(crate) enum Killed {
+    /// The actor failed and is waiting
+    /// to be restarted.
+    Failed(Error),
+    /// The actor is dead (with the error
+    /// that made it die, if any).
+    Dead(Result<(), Error>),
+}
+
+pub(crate) struct RestartSender(Sender<()>);
+pub(crate) struct RestartRecver(Receiver<()>);
 
 pub(crate) fn new_kill() -> (KillSender, KillRecver) {
     let notify = Notify::new();
@@ -82,12 +116,71 @@ pub(crate) fn new_killed() -> (KilledSender, KilledRecver) {
     (KilledSender(sender), KilledRecver(recver))
 }
 
-impl<A: raw::Actor> Actor<A> {
+pub(crate) fn new_restart() -> (RestartSender, RestartRecver) {
+    let (sender, recver) = channel::Builder::new()
+        .unbounded()
+        .unlimited_msgs()
+        .unlimited_senders()
+        .unlimited_receivers()
+        .build();
+
+    (RestartSender(sender), RestartRecver(recver))
+}
+
+/// Drives the actor until it dies, notifying
+/// the runtime over the killed channel and
+/// the actors watching it through `monitors`
+/// and unregistering it from `registry` once
+/// it is (even if it panicked, in which case
+/// it is reported as having failed).
+pub(crate) async fn run<A>(
+    actor: Actor<A>,
+    mut killed: KilledSender,
+    monitors: raw::Monitors,
+    registry: raw::Registry,
+) where
+    A: raw::Actor + 'static,
+{
+    let id = actor.id;
+
+    // We only borrow the actor to know
+    // what it was doing if it panicked.
+    let mut actor = actor;
+    let res = match AssertUnwindSafe(&mut actor).catch_unwind().await {
+        Ok(res) => res,
+        Err(payload) => {
+            let err = Error::panicked(payload);
+            if let Some(phase) = actor.phase {
+                Err(err.actor::<A>(id, phase))
+            } else {
+                Err(err.of_actor::<A>(id))
+            }
+        }
+    };
+
+    drop(actor);
+
+    registry.remove(id);
+
+    // The watchers each get a clone of the
+    // error (if the actor failed).
+    monitors.down(id, || {
+        res.as_ref()
+            .err()
+            .map(|err| Box::new(err.clone()) as Box<dyn Any + Send + Sync>)
+    });
+
+    killed.killed(id, res);
+}
+
+impl<A: raw::Actor + 'static> Actor<A> {
     pub(crate) fn new(
         id: u64,
         mut act: A,
         kill: KillRecver,
         killed: KilledSender,
+        restart: Option<(Box<dyn raw::Factory<A>>, RestartRecver)>,
+        budget: usize,
         mut ctx: A::Context,
     ) -> Option<Self> {
         // Sets the actor's status as starting
@@ -123,6 +216,10 @@ impl<A: raw::Actor> Actor<A> {
             return None;
         }
 
+        // The context can override the
+        // runtime's budget.
+        let budget = ctx.budget().unwrap_or(budget).max(1);
+
         Some(Actor {
             id,
             act,
@@ -130,32 +227,101 @@ impl<A: raw::Actor> Actor<A> {
             started: false,
             kill,
             killed,
+            restart,
+            failed: false,
+            budget,
+            phase: None,
         })
     }
 
+    /// Calls `f` with the actor and its
+    /// context, recording that the actor is
+    /// in the given phase while it runs.
+    fn in_phase<F, T>(&mut self, phase: Phase, f: F) -> T
+    where
+        F: FnOnce(&mut A, &mut A::Context) -> T,
+    {
+        self.phase = Some(phase);
+        let res = f(&mut self.act, &mut self.ctx);
+        self.phase = None;
+
+        res
+    }
+
+    /// Replaces the actor with a new one
+    /// created by its factory, keeping its
+    /// context (and thus its channels).
+    fn restart(&mut self) {
+        let act = if let Some((factory, _)) = &self.restart {
+            factory.create()
+        } else {
+            return;
+        };
+
+        // If the previous actor hasn't
+        // failed, we let it know that it
+        // is being stopped.
+        if !self.failed {
+            self.ctx.set_status(A::Status::stopped());
+            self.in_phase(Phase::Stopping, |act, ctx| act.stopped(ctx));
+        }
+
+        self.act = act;
+        self.ctx.reset();
+
+        self.started = false;
+        self.failed = false;
+
+        // We start the new actor like a
+        // newly spawned one.
+        self.ctx.set_status(A::Status::starting());
+        self.in_phase(Phase::Starting, |act, ctx| act.starting(ctx));
+    }
+
+    /// Handles an error returned by one of
+    /// the actor's handlers, either marking
+    /// the actor as failed (if it can be
+    /// restarted) or as dead.
+    fn failed(&mut self, err: Error) -> Poll<Result<(), Error>> {
+        if self.restart.is_none() {
+            self.dead();
+            return Poll::Ready(Err(err));
+        }
+
+        self.failed = true;
+
+        // We notify the runtime that the
+        // actor failed, so that it can
+        // decide whether to restart it (if
+        // this fails, the runtime has been
+        // dropped and the actor dies).
+        if let Err(err) = self.killed.failed(self.id, err) {
+            self.dead();
+
+            match err.into_msg() {
+                (_, Killed::Failed(err)) => return Poll::Ready(Err(err)),
+                _ => unreachable!(),
+            }
+        }
+
+        Poll::Pending
+    }
+
     /// Marks the actor as dead.
-    fn dead(&mut self) -> Result<(), Error> {
+    fn dead(&mut self) {
         // We set the actor's status as
         // dead.
         self.ctx.set_status(A::Status::dead());
 
-        // We try to notify the actor's
-        // death over the killed channel.
-        if let Err(err) = self.killed.killed(self.id) {
-            return Err(Box::new(err).into());
-        }
-
         // We try to push the actor's
         // new status over its update
         // channel.
-        // NOTE: this is done after sending
-        //   the death notification because
-        //   if the `Spanwed` linked to
-        //   this actor has been dropped,
-        //   it will return an error.
+        // NOTE: the death notification is
+        //   sent over the killed channel
+        //   once the actor's future has
+        //   completed.
         // TODO: should we take care of the possible errors?
         self.ctx.update().ok();
-        Ok(())
     }
 }
 
@@ -213,9 +379,29 @@ impl KillSender {
 }
 
 impl KilledSender {
-    /// Notifies that the actor died.
-    fn killed(&mut self, id: u64) -> Result<(), TrySendError<u64>> {
-        self.0.try_send(id)
+    /// Notifies that the actor died (this
+    /// is the last notification sent by
+    /// the actor).
+    pub(crate) fn killed(&mut self, id: u64, res: Result<(), Error>) {
+        // If this fails, the runtime has
+        // been dropped and there is no one
+        // to notify anymore.
+        self.0.try_send((id, Killed::Dead(res))).ok();
+    }
+
+    /// Notifies that the actor failed
+    /// and is waiting to be restarted.
+    fn failed(&mut self, id: u64, err: Error) -> Result<(), TrySendError<(u64, Killed)>> {
+        self.0.try_send((id, Killed::Failed(err)))
+    }
+}
+
+impl RestartSender {
+    /// Asks the actor to restart by
+    /// sending a message over its restart
+    /// channel.
+    pub(crate) fn restart(&mut self) {
+        self.0.try_send(()).ok();
     }
 }
 
@@ -227,20 +413,53 @@ where
 
     fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Self::Output> {
         let actor = self.get_mut();
+        let mut handled = 0;
 
         loop {
+            // If the actor has handled as many
+            // actions, events and messages as
+            // its budget allows, we yield to let
+            // the other tasks make progress
+            // (asking to be polled again).
+            if handled >= actor.budget {
+                ctx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+
             // If the actor has been asked
             // to die, we kill it.
-            match Pin::new(&mut actor.kill).poll(ctx) {
-                Poll::Ready(()) => actor.act.stopped(&mut actor.ctx),
-                Poll::Pending => (),
+            if let Poll::Ready(()) = Pin::new(&mut actor.kill).poll(ctx) {
+                if actor.failed {
+                    actor.dead();
+                    return Poll::Ready(Ok(()));
+                }
+
+                if !actor.ctx.status().is_dead() {
+                    actor.ctx.set_status(A::Status::stopped());
+                }
+            }
+
+            // If the actor has been asked
+            // to restart, we do so.
+            if let Some((_, restart)) = &mut actor.restart {
+                if let Poll::Ready(Some(())) = Pin::new(restart).poll_next(ctx) {
+                    actor.restart();
+                    continue;
+                }
+            }
+
+            // If the actor failed, we wait
+            // for it to be restarted or
+            // killed.
+            if actor.failed {
+                return Poll::Pending;
             }
 
             // If the actor's status is marked
             // as stopping, we call `stopping`
             // on it.
             if actor.ctx.status().is_stopping() {
-                actor.act.stopping(&mut actor.ctx);
+                actor.in_phase(Phase::Stopping, |act, ctx| act.stopping(ctx));
 
                 // If the actor's status hasn't
                 // changed, we set it as stopped.
@@ -254,22 +473,24 @@ where
             // ont it and notify that the actor
             // is dead over the killed channel.
             if actor.ctx.status().is_stopped() {
-                actor.act.stopped(&mut actor.ctx);
-                return Poll::Ready(actor.dead());
+                actor.in_phase(Phase::Stopping, |act, ctx| act.stopped(ctx));
+                actor.dead();
+                return Poll::Ready(Ok(()));
             }
 
             // If the actor's status is marked
             // as dead, we notify that the actor
             // is dead over the killed channel.
             if actor.ctx.status().is_dead() {
-                return Poll::Ready(actor.dead());
+                actor.dead();
+                return Poll::Ready(Ok(()));
             }
 
             // If `started` hasn't been called
             // on the actor, we call it.
             if !actor.started {
                 actor.ctx.set_status(A::Status::started());
-                actor.act.started(&mut actor.ctx);
+                actor.in_phase(Phase::Starting, |act, ctx| act.started(ctx));
                 // We save that the actor's
                 // `started` method has been
                 // called.
@@ -283,33 +504,48 @@ where
                     // action for the actor to handle,
                     // we do so.
                     raw::Work::Action(mut action) => {
-                        if let Err(err) = action.handle(&mut actor.act, &mut actor.ctx) {
-                            return Poll::Ready(Err(Error::std(err).add_res(actor.dead())));
+                        let res = actor.in_phase(Phase::Action, |act, ctx| action.handle(act, ctx));
+                        if let Err(err) = res {
+                            return actor
+                                .failed(Error::std(err).actor::<A>(actor.id, Phase::Action));
                         }
 
+                        handled += 1;
                         continue;
                     }
                     // If the context has been asked
                     // to get an event handled by the
                     // actor, we do so.
                     raw::Work::Event(mut event) => {
-                        if let Err(err) = event.handle(&mut actor.act, &mut actor.ctx) {
-                            return Poll::Ready(Err(Error::std(err).add_res(actor.dead())));
+                        let res = actor.in_phase(Phase::Event, |act, ctx| event.handle(act, ctx));
+                        if let Err(err) = res {
+                            return actor
+                                .failed(Error::std(err).actor::<A>(actor.id, Phase::Event));
                         }
 
+                        handled += 1;
                         continue;
                     }
                     // If the context has received a
                     // message for the actor to handle,
                     // we do so.
                     raw::Work::Message(mut msg) => {
-                        if let Err(err) = msg.handle(&mut actor.act, &mut actor.ctx) {
-                            return Poll::Ready(Err(Error::std(err).add_res(actor.dead())));
+                        let res = actor.in_phase(Phase::Message, |act, ctx| msg.handle(act, ctx));
+                        if let Err(err) = res {
+                            return actor
+                                .failed(Error::std(err).actor::<A>(actor.id, Phase::Message));
                         }
 
+                        handled += 1;
                         continue;
                     }
                     raw::Work::Update => continue,
+                    // If the context has been asked
+                    // to make the actor fail, we do
+                    // so.
+                    raw::Work::Fail(err) => {
+                        return actor.failed(Error::std(err).of_actor::<A>(actor.id));
+                    }
                 },
                 // If the actor's context `Work`
                 // stream has been closed, we
@@ -346,9 +582,17 @@ impl Future for KillRecver {
 }
 
 impl Stream for KilledRecver {
-    type Item = u64;
+    type Item = (u64, Killed);
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<(u64, Killed)>> {
+        Pin::new(&mut self.get_mut().0).poll_next(ctx)
+    }
+}
+
+impl Stream for RestartRecver {
+    type Item = ();
 
-    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<u64>> {
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<()>> {
         Pin::new(&mut self.get_mut().0).poll_next(ctx)
     }
 }
@@ -0,0 +1,367 @@
+use std::pin::Pin;
+use std::task;
+use std::task::Poll;
+
+use aktoro_raw as raw;
+use aktoro_raw::Context as RawContext;
+use fnv::FnvHashMap;
+use futures_core::Future;
+use futures_core::Stream;
+use rand::FromEntropy;
+use rand::RngCore;
+use rand_xoshiro::Xoshiro512StarStar;
+
+use crate::actor;
+use crate::actor::Actor;
+use crate::actor::KillSender as Kill;
+use crate::actor::Killed;
+use crate::actor::KilledRecver;
+use crate::actor::KilledSender;
+use crate::actor::RestartSender;
+use crate::backend;
+use crate::blocking::BlockingPool;
+use crate::dead_letter;
+use crate::dead_letter::DeadLetters;
+use crate::error::Error;
+use crate::timer::Timer;
+
+/// The default maximum number of actions,
+/// events and messages that an actor handles
+/// each time it is polled.
+pub(crate) const DEFAULT_BUDGET: usize = 128;
+
+/// The default maximum number of threads of
+/// the pool running the blocking closures
+/// spawned by the actors.
+pub(crate) const DEFAULT_BLOCKING_THREADS: usize = 32;
+
+/// The actors of a runtime, along with the
+/// state that is passed to them when they
+/// are spawned (it is shared by [`Runtime`]
+/// and [`LocalRuntime`], which only differ
+/// in how they drive their actors).
+///
+/// [`Runtime`]: struct.Runtime.html
+/// [`LocalRuntime`]: struct.LocalRuntime.html
+pub(crate) struct Actors {
+    /// A map matching an actor's ID with
+    /// a sender for its kill channel and a
+    /// sender for its restart channel (if
+    /// it is restartable).
+    actors: FnvHashMap<u64, (Kill, Option<RestartSender>)>,
+    /// A sender for the actors' killed
+    /// channel (it will be cloned and
+    /// passed to all new actors).
+    sender: KilledSender,
+    /// A receiver for the actors' killed
+    /// channel, notified when an actor
+    /// has failed or died (it is used as
+    /// a completion queue, so that only the
+    /// actors that finished are touched
+    /// when waiting for them).
+    ///
+    /// It is shared among all the runtime's
+    /// actors.
+    recver: KilledRecver,
+    /// The registry that the runtime's actors
+    /// are registered in and that they use to
+    /// look up the other actors.
+    registry: raw::Registry,
+    /// The sink that the runtime's actors
+    /// report their dead letters to.
+    sink: raw::DeadLetterSink,
+    /// The stream yielding the dead letters
+    /// reported to the runtime's own sink
+    /// (`None` if it was already taken).
+    dead_letters: Option<DeadLetters>,
+    /// The table used by the runtime's actors
+    /// to watch the other actors, notified
+    /// when they die.
+    monitors: raw::Monitors,
+    /// The spawner passed to the actors'
+    /// contexts (and that the runtime's
+    /// actors are spawned on if it spawns
+    /// them).
+    spawner: raw::Spawner,
+    /// The spawner that the runtime's actors
+    /// run their blocking closures on (a
+    /// `BlockingPool` owned by the runtime,
+    /// unless another one is provided).
+    blocking: raw::BlockingSpawner,
+    /// The timer passed to the actors'
+    /// contexts (that they schedule their
+    /// messages with).
+    timer: Timer,
+    /// The maximum number of actions, events
+    /// and messages that the runtime's actors
+    /// handle each time they are polled (unless
+    /// their context overrides it).
+    budget: usize,
+    /// A fast (non-cryptographic) random
+    /// number generator.
+    rng: Xoshiro512StarStar,
+}
+
+impl Actors {
+    /// Returns a list of the actors'
+    /// identifier.
+    pub(crate) fn ids(&self) -> Vec<u64> {
+        self.actors.keys().copied().collect()
+    }
+
+    /// Whether all the actors are dead.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.actors.is_empty()
+    }
+
+    /// Returns the spawner passed to the
+    /// actors' contexts.
+    pub(crate) fn spawner(&self) -> &raw::Spawner {
+        &self.spawner
+    }
+
+    /// Returns the spawner that the actors run
+    /// their blocking closures on.
+    pub(crate) fn blocking_spawner(&self) -> &raw::BlockingSpawner {
+        &self.blocking
+    }
+
+    /// Returns the timer passed to the
+    /// actors' contexts.
+    pub(crate) fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    /// Creates a new actor, which will be
+    /// restartable if `factory` is provided,
+    /// and adds it to the list.
+    ///
+    /// The returned future drives the actor
+    /// until it dies, notifying the runtime
+    /// over the killed channel (and the actors
+    /// watching it) once it is.
+    pub(crate) fn create<A>(
+        &mut self,
+        actor: A,
+        factory: Option<Box<dyn raw::Factory<A>>>,
+        config: <A::Context as raw::Context<A>>::Config,
+    ) -> Option<(impl Future<Output = ()>, raw::Spawned<A>)>
+    where
+        A: raw::Actor + 'static,
+        A::Context: raw::Context<A, Timer = Timer>,
+    {
+        // Generate the actor's ID.
+        let id = self.rng.next_u64();
+
+        // Create a new context for the actor.
+        let mut ctx = A::Context::new(id, config);
+        ctx.set_dead_letters(self.sink.clone());
+        ctx.set_spawner(self.spawner.clone());
+        ctx.set_blocking_spawner(self.blocking.clone());
+        ctx.set_monitors(self.monitors.clone());
+        ctx.set_registry(self.registry.clone());
+        ctx.set_timer(self.timer.clone());
+
+        // Create a new `Spawned` struct from
+        // the actor's context.
+        let spawned = raw::Spawned::new(&mut ctx);
+
+        // Create the actor's kill channel.
+        let (sender, recver) = actor::new_kill();
+
+        // Create the actor's restart channel
+        // if it is restartable.
+        let (restart, factory) = if let Some(factory) = factory {
+            let (restart, recver) = actor::new_restart();
+            (Some(restart), Some((factory, recver)))
+        } else {
+            (None, None)
+        };
+
+        // Try to create the actor (fails if
+        // it refused to start).
+        let actor = Actor::new(
+            id,
+            actor,
+            recver,
+            self.sender.clone(),
+            factory,
+            self.budget,
+            ctx,
+        )?;
+
+        // Save the actor's kill channel's
+        // sender.
+        self.actors.insert(id, (sender, restart));
+
+        let run = actor::run(
+            actor,
+            self.sender.clone(),
+            self.monitors.clone(),
+            self.registry.clone(),
+        );
+
+        Some((run, spawned))
+    }
+
+    /// Asks the actor with the given identifier
+    /// to restart, returning whether it was
+    /// spawned as restartable.
+    pub(crate) fn restart(&mut self, id: u64) -> bool {
+        if let Some((_, Some(restart))) = self.actors.get_mut(&id) {
+            restart.restart();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Registers the actor linked to `spawned`
+    /// under `name`, returning an error if
+    /// another actor is already registered under
+    /// it or if the actor isn't in the list.
+    pub(crate) fn register<A>(
+        &mut self,
+        name: &str,
+        spawned: &raw::Spawned<A>,
+    ) -> Result<(), raw::RegistryError>
+    where
+        A: raw::Actor + 'static,
+    {
+        if !self.actors.contains_key(&spawned.actor_id()) {
+            return Err(raw::RegistryError::unknown(name.to_string()));
+        }
+
+        self.registry.register(name, spawned)
+    }
+
+    /// Returns a [`Spawned`] for the actor
+    /// registered under `name`.
+    ///
+    /// [`Spawned`]: https://docs.rs/aktoro-raw/*/aktoro_raw/struct.Spawned.html
+    pub(crate) fn lookup<A>(&self, name: &str) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor + 'static,
+    {
+        self.registry.lookup(name)
+    }
+
+    /// Unregisters the actor registered under
+    /// `name`, returning its identifier.
+    pub(crate) fn unregister(&mut self, name: &str) -> Option<u64> {
+        self.registry.unregister(name)
+    }
+
+    /// Takes the stream yielding the dead
+    /// letters reported to the runtime's own
+    /// sink.
+    pub(crate) fn dead_letters(&mut self) -> Option<DeadLetters> {
+        self.dead_letters.take()
+    }
+
+    pub(crate) fn set_dead_letters(&mut self, sink: raw::DeadLetterSink) {
+        self.sink = sink;
+    }
+
+    pub(crate) fn set_spawner(&mut self, spawner: raw::Spawner) {
+        self.spawner = spawner;
+    }
+
+    pub(crate) fn set_blocking_spawner(&mut self, spawner: raw::BlockingSpawner) {
+        self.blocking = spawner;
+    }
+
+    pub(crate) fn set_monitors(&mut self, monitors: raw::Monitors) {
+        self.monitors = monitors;
+    }
+
+    pub(crate) fn set_registry(&mut self, registry: raw::Registry) {
+        self.registry = registry;
+    }
+
+    pub(crate) fn set_timer(&mut self, timer: Timer) {
+        self.timer = timer;
+    }
+
+    pub(crate) fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+    }
+
+    /// Asks all the actors to stop.
+    pub(crate) fn stop(&mut self) {
+        for (_, actor) in self.actors.iter_mut() {
+            actor.0.kill();
+        }
+    }
+
+    /// Receives the notifications sent by the
+    /// actors that failed or died over the
+    /// killed channel, removing the dead actors
+    /// from the list.
+    pub(crate) fn poll_killed(
+        &mut self,
+        ctx: &mut task::Context,
+    ) -> Poll<Option<Result<u64, (u64, Error)>>> {
+        if self.actors.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        // We receive the notifications sent by
+        // the actors that failed or died over the
+        // killed channel (the other actors aren't
+        // polled, making this independent of the
+        // number of running actors).
+        loop {
+            match Pin::new(&mut self.recver).poll_next(ctx) {
+                // The failed restartable actors are
+                // kept as they are waiting to be
+                // restarted...
+                Poll::Ready(Some((id, Killed::Failed(err)))) => {
+                    return Poll::Ready(Some(Err((id, err))));
+                }
+                // ...while the dead actors are removed
+                // from the actors list.
+                Poll::Ready(Some((id, Killed::Dead(res)))) => {
+                    if self.actors.remove(&id).is_none() {
+                        continue;
+                    }
+
+                    match res {
+                        Ok(()) => return Poll::Ready(Some(Ok(id))),
+                        Err(err) => return Poll::Ready(Some(Err((id, err)))),
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl Default for Actors {
+    fn default() -> Self {
+        let (sender, recver) = actor::new_killed();
+        let (sink, dead_letters) = dead_letter::new();
+
+        Actors {
+            actors: FnvHashMap::default(),
+            sender,
+            recver,
+            registry: raw::Registry::new(),
+            sink,
+            dead_letters: Some(dead_letters),
+            monitors: raw::Monitors::new(),
+            spawner: backend::spawner(),
+            blocking: raw::BlockingSpawner::new(BlockingPool::new(DEFAULT_BLOCKING_THREADS)),
+            timer: Timer::default(),
+            budget: DEFAULT_BUDGET,
+            rng: Xoshiro512StarStar::from_entropy(),
+        }
+    }
+}
+
+impl Drop for Actors {
+    fn drop(&mut self) {
+        self.stop()
+    }
+}
@@ -0,0 +1,39 @@
+// The backend providing the default spawner,
+// the network types and the timers used by
+// the runtime, selected using cargo features
+// (if multiple backends are enabled, the
+// first one in this list is used).
+
+#[cfg(feature = "runtime")]
+mod runtime;
+#[cfg(feature = "runtime")]
+pub(crate) use self::runtime::*;
+
+#[cfg(all(feature = "tokio", not(feature = "runtime")))]
+mod tokio;
+#[cfg(all(feature = "tokio", not(feature = "runtime")))]
+pub(crate) use self::tokio::*;
+
+#[cfg(all(
+    feature = "async-std",
+    not(any(feature = "runtime", feature = "tokio"))
+))]
+mod async_std;
+#[cfg(all(
+    feature = "async-std",
+    not(any(feature = "runtime", feature = "tokio"))
+))]
+pub(crate) use self::async_std::*;
+
+#[cfg(not(any(feature = "runtime", feature = "tokio", feature = "async-std")))]
+compile_error!("one of the `runtime`, `tokio` or `async-std` features must be enabled");
+
+/// The error returned when an address
+/// couldn't be resolved to any socket
+/// address.
+pub(crate) fn no_addrs() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "could not resolve to any addresses",
+    )
+}
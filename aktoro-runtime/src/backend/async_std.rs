@@ -0,0 +1,77 @@
+use std::future::Future;
+use std::io;
+use std::net;
+use std::net::SocketAddr;
+use std::task;
+use std::task::Poll;
+
+use aktoro_raw as raw;
+use futures_core::future::BoxFuture;
+use futures_core::future::LocalBoxFuture;
+
+pub(crate) use ::async_std::net::TcpListener;
+pub(crate) use ::async_std::net::TcpStream;
+pub(crate) use ::async_std::net::UdpSocket;
+pub(crate) use ::futures_timer as time;
+
+/// Returns a spawner using [`async-std`]'s
+/// executor.
+///
+/// [`async-std`]: https://docs.rs/async-std
+pub(crate) fn spawner() -> raw::Spawner {
+    raw::Spawner::new(|fut: BoxFuture<'static, ()>| {
+        ::async_std::task::spawn(fut);
+    })
+}
+
+/// Connects to the first TCP server
+/// listening on one of the addresses.
+pub(crate) fn connect(addrs: Vec<SocketAddr>) -> BoxFuture<'static, io::Result<TcpStream>> {
+    Box::pin(async move { TcpStream::connect(&addrs[..]).await })
+}
+
+/// Creates a new TCP listener bound to
+/// the first available address.
+pub(crate) fn bind_tcp(addrs: Vec<SocketAddr>) -> io::Result<TcpListener> {
+    Ok(net::TcpListener::bind(&addrs[..])?.into())
+}
+
+/// Tries to accept a new TCP connection.
+pub(crate) fn poll_accept(
+    listener: &mut TcpListener,
+    ctx: &mut task::Context,
+) -> Poll<io::Result<TcpStream>> {
+    // The listener keeps track of the task
+    // waiting for a connection, so we can
+    // create a new future every time.
+    match Box::pin(listener.accept()).as_mut().poll(ctx) {
+        Poll::Ready(Ok((stream, _))) => Poll::Ready(Ok(stream)),
+        Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+        Poll::Pending => Poll::Pending,
+    }
+}
+
+/// Creates a new UDP socket bound to the
+/// first available address.
+pub(crate) fn bind_udp(addrs: Vec<SocketAddr>) -> io::Result<UdpSocket> {
+    Ok(net::UdpSocket::bind(&addrs[..])?.into())
+}
+
+/// Sends data to the given address.
+pub(crate) fn send_to<'s>(
+    socket: &'s mut UdpSocket,
+    buf: &'s [u8],
+    addr: SocketAddr,
+) -> LocalBoxFuture<'s, io::Result<usize>> {
+    Box::pin(async move { socket.send_to(buf, addr).await })
+}
+
+/// Receives data, returning the number of
+/// bytes received and the address of the
+/// sender.
+pub(crate) fn recv_from<'s>(
+    socket: &'s mut UdpSocket,
+    buf: &'s mut [u8],
+) -> LocalBoxFuture<'s, io::Result<(usize, SocketAddr)>> {
+    Box::pin(async move { socket.recv_from(buf).await })
+}
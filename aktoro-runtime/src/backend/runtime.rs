@@ -0,0 +1,74 @@
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task;
+use std::task::Poll;
+
+use aktoro_raw as raw;
+use futures_core::future::BoxFuture;
+use futures_core::future::LocalBoxFuture;
+
+pub(crate) use ::runtime::net::TcpListener;
+pub(crate) use ::runtime::net::TcpStream;
+pub(crate) use ::runtime::net::UdpSocket;
+pub(crate) use ::runtime::time;
+
+/// Returns a spawner using the [`runtime`]
+/// crate's executor.
+///
+/// [`runtime`]: https://docs.rs/runtime
+pub(crate) fn spawner() -> raw::Spawner {
+    raw::Spawner::new(|fut: BoxFuture<'static, ()>| {
+        ::runtime::spawn(fut);
+    })
+}
+
+/// Connects to the first TCP server
+/// listening on one of the addresses.
+pub(crate) fn connect(addrs: Vec<SocketAddr>) -> BoxFuture<'static, io::Result<TcpStream>> {
+    Box::pin(TcpStream::connect(&addrs[..]))
+}
+
+/// Creates a new TCP listener bound to
+/// the first available address.
+pub(crate) fn bind_tcp(addrs: Vec<SocketAddr>) -> io::Result<TcpListener> {
+    TcpListener::bind(&addrs[..])
+}
+
+/// Tries to accept a new TCP connection.
+pub(crate) fn poll_accept(
+    listener: &mut TcpListener,
+    ctx: &mut task::Context,
+) -> Poll<io::Result<TcpStream>> {
+    match Pin::new(&mut listener.accept()).poll(ctx) {
+        Poll::Ready(Ok((stream, _))) => Poll::Ready(Ok(stream)),
+        Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+        Poll::Pending => Poll::Pending,
+    }
+}
+
+/// Creates a new UDP socket bound to the
+/// first available address.
+pub(crate) fn bind_udp(addrs: Vec<SocketAddr>) -> io::Result<UdpSocket> {
+    UdpSocket::bind(&addrs[..])
+}
+
+/// Sends data to the given address.
+pub(crate) fn send_to<'s>(
+    socket: &'s mut UdpSocket,
+    buf: &'s [u8],
+    addr: SocketAddr,
+) -> LocalBoxFuture<'s, io::Result<usize>> {
+    Box::pin(socket.send_to(buf, addr))
+}
+
+/// Receives data, returning the number of
+/// bytes received and the address of the
+/// sender.
+pub(crate) fn recv_from<'s>(
+    socket: &'s mut UdpSocket,
+    buf: &'s mut [u8],
+) -> LocalBoxFuture<'s, io::Result<(usize, SocketAddr)>> {
+    Box::pin(socket.recv_from(buf))
+}
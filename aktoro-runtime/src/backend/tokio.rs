@@ -0,0 +1,138 @@
+use std::io;
+use std::net;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task;
+use std::task::Poll;
+
+use ::tokio::io::AsyncRead as TokioRead;
+use ::tokio::io::AsyncWrite as TokioWrite;
+use ::tokio::net::driver::Handle;
+use aktoro_raw as raw;
+use futures_core::future::BoxFuture;
+use futures_core::future::LocalBoxFuture;
+use futures_io::AsyncRead;
+use futures_io::AsyncWrite;
+
+pub(crate) use ::futures_timer as time;
+pub(crate) use ::tokio::net::TcpListener;
+pub(crate) use ::tokio::net::UdpSocket;
+
+/// A TCP stream using [`tokio`]'s I/O
+/// traits, wrapped to implement the
+/// [`futures-io`] ones.
+///
+/// [`tokio`]: https://docs.rs/tokio
+/// [`futures-io`]: https://docs.rs/futures-io-preview
+pub(crate) struct TcpStream(::tokio::net::TcpStream);
+
+/// Returns a spawner using [`tokio`]'s
+/// executor (it has to be used from a
+/// task running on it).
+///
+/// [`tokio`]: https://docs.rs/tokio
+pub(crate) fn spawner() -> raw::Spawner {
+    raw::Spawner::new(|fut: BoxFuture<'static, ()>| {
+        ::tokio::spawn(fut);
+    })
+}
+
+/// Connects to the first TCP server
+/// listening on one of the addresses.
+pub(crate) fn connect(addrs: Vec<SocketAddr>) -> BoxFuture<'static, io::Result<TcpStream>> {
+    Box::pin(async move {
+        let mut last = None;
+
+        for addr in addrs {
+            match ::tokio::net::TcpStream::connect(&addr).await {
+                Ok(stream) => return Ok(TcpStream(stream)),
+                Err(err) => last = Some(err),
+            }
+        }
+
+        Err(last.unwrap_or_else(super::no_addrs))
+    })
+}
+
+/// Creates a new TCP listener bound to
+/// the first available address.
+pub(crate) fn bind_tcp(addrs: Vec<SocketAddr>) -> io::Result<TcpListener> {
+    let listener = net::TcpListener::bind(&addrs[..])?;
+    TcpListener::from_std(listener, &Handle::default())
+}
+
+/// Tries to accept a new TCP connection.
+pub(crate) fn poll_accept(
+    listener: &mut TcpListener,
+    ctx: &mut task::Context,
+) -> Poll<io::Result<TcpStream>> {
+    match listener.poll_accept(ctx) {
+        Poll::Ready(Ok((stream, _))) => Poll::Ready(Ok(TcpStream(stream))),
+        Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+        Poll::Pending => Poll::Pending,
+    }
+}
+
+/// Creates a new UDP socket bound to the
+/// first available address.
+pub(crate) fn bind_udp(addrs: Vec<SocketAddr>) -> io::Result<UdpSocket> {
+    let socket = net::UdpSocket::bind(&addrs[..])?;
+    UdpSocket::from_std(socket, &Handle::default())
+}
+
+/// Sends data to the given address.
+pub(crate) fn send_to<'s>(
+    socket: &'s mut UdpSocket,
+    buf: &'s [u8],
+    addr: SocketAddr,
+) -> LocalBoxFuture<'s, io::Result<usize>> {
+    Box::pin(async move { socket.send_to(buf, &addr).await })
+}
+
+/// Receives data, returning the number of
+/// bytes received and the address of the
+/// sender.
+pub(crate) fn recv_from<'s>(
+    socket: &'s mut UdpSocket,
+    buf: &'s mut [u8],
+) -> LocalBoxFuture<'s, io::Result<(usize, SocketAddr)>> {
+    Box::pin(socket.recv_from(buf))
+}
+
+impl TcpStream {
+    pub(crate) fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.0.local_addr()
+    }
+
+    pub(crate) fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.0.peer_addr()
+    }
+}
+
+impl AsyncRead for TcpStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        ctx: &mut task::Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        TokioRead::poll_read(Pin::new(&mut self.get_mut().0), ctx, buf)
+    }
+}
+
+impl AsyncWrite for TcpStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        ctx: &mut task::Context,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        TokioWrite::poll_write(Pin::new(&mut self.get_mut().0), ctx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<io::Result<()>> {
+        TokioWrite::poll_flush(Pin::new(&mut self.get_mut().0), ctx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<io::Result<()>> {
+        TokioWrite::poll_shutdown(Pin::new(&mut self.get_mut().0), ctx)
+    }
+}
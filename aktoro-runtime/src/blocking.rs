@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use aktoro_raw as raw;
+
+/// How long a thread of the pool waits for
+/// a new closure to run before exiting.
+const KEEP_ALIVE: Duration = Duration::from_secs(10);
+
+type Job = Box<dyn FnOnce() + Send>;
+
+#[derive(Clone)]
+/// A bounded pool of threads running the
+/// blocking closures spawned by the actors
+/// (see [`Context::spawn_blocking`]).
+///
+/// Threads are started when closures are
+/// spawned and none of the running ones is
+/// idle, up to the pool's maximum number of
+/// threads (the closures are queued
+/// afterwards). They exit after having been
+/// idle for a while.
+///
+/// [`Context::spawn_blocking`]: https://docs.rs/aktoro-raw/*/aktoro_raw/trait.Context.html#tymethod.spawn_blocking
+pub struct BlockingPool {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    state: Mutex<State>,
+    /// Notified when a closure is queued
+    /// while a thread is idle.
+    condvar: Condvar,
+    /// The maximum number of threads that
+    /// the pool can run.
+    max: usize,
+}
+
+struct State {
+    /// The closures waiting for a thread
+    /// to run them.
+    queue: VecDeque<Job>,
+    /// The number of running threads.
+    threads: usize,
+    /// The number of threads waiting for
+    /// a closure to run.
+    idle: usize,
+}
+
+impl BlockingPool {
+    /// Creates a new pool running at most
+    /// `max` threads (at least one).
+    pub fn new(max: usize) -> Self {
+        BlockingPool {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State {
+                    queue: VecDeque::new(),
+                    threads: 0,
+                    idle: 0,
+                }),
+                condvar: Condvar::new(),
+                max: max.max(1),
+            }),
+        }
+    }
+
+    /// Returns the maximum number of threads
+    /// that the pool can run.
+    pub fn max_threads(&self) -> usize {
+        self.inner.max
+    }
+}
+
+impl raw::BlockingExecutor for BlockingPool {
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) {
+        let mut state = self.inner.state.lock().unwrap();
+        state.queue.push_back(f);
+
+        // We wake an idle thread up if there is
+        // one, or start a new one if the pool
+        // isn't full (otherwise, the closure
+        // stays queued until a thread is done).
+        if state.idle > 0 {
+            self.inner.condvar.notify_one();
+        } else if state.threads < self.inner.max {
+            state.threads += 1;
+
+            let inner = self.inner.clone();
+            thread::spawn(move || inner.run());
+        }
+    }
+}
+
+impl Inner {
+    /// Runs the queued closures until none
+    /// has been queued for `KEEP_ALIVE`.
+    fn run(&self) {
+        let mut state = self.state.lock().unwrap();
+
+        loop {
+            if let Some(job) = state.queue.pop_front() {
+                drop(state);
+
+                // We catch the panics so that the
+                // thread can keep running (the
+                // actor is notified by its
+                // channel being dropped).
+                let _ = panic::catch_unwind(AssertUnwindSafe(job));
+
+                state = self.state.lock().unwrap();
+                continue;
+            }
+
+            state.idle += 1;
+            let (state_, res) = self.condvar.wait_timeout(state, KEEP_ALIVE).unwrap();
+            state = state_;
+            state.idle -= 1;
+
+            if res.timed_out() && state.queue.is_empty() {
+                state.threads -= 1;
+                return;
+            }
+        }
+    }
+}
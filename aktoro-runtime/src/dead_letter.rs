@@ -0,0 +1,38 @@
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task;
+use std::task::Poll;
+
+use aktoro_channel as channel;
+use aktoro_raw as raw;
+use futures_core::Stream;
+
+/// A stream returned by [`Runtime::dead_letters`]
+/// yielding the messages that couldn't be
+/// delivered to the runtime's actors (and to
+/// their sub-actors).
+///
+/// [`Runtime::dead_letters`]: struct.Runtime.html#method.dead_letters
+pub struct DeadLetters(channel::Receiver<raw::DeadLetter>);
+
+/// Creates a new dead letters channel,
+/// returning a sink reporting to it and
+/// the stream yielding its dead letters.
+pub(crate) fn new() -> (raw::DeadLetterSink, DeadLetters) {
+    let (sender, recver) = channel::Builder::new().build();
+    let sender = Mutex::new(sender);
+
+    let sink = raw::DeadLetterSink::new(move |letter| {
+        sender.lock().unwrap().try_send(letter).ok();
+    });
+
+    (sink, DeadLetters(recver))
+}
+
+impl Stream for DeadLetters {
+    type Item = raw::DeadLetter;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<raw::DeadLetter>> {
+        Pin::new(&mut self.get_mut().0).poll_next(ctx)
+    }
+}
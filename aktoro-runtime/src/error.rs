@@ -1,81 +1,117 @@
+use std::any;
+use std::any::Any;
 use std::error;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::io;
+use std::slice;
+use std::sync::Arc;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+/// An error returned by the runtime, its
+/// actors or its network types, keeping
+/// track of the actor that failed (and of
+/// what it was doing) if there is one.
+///
+/// Cloning it is cheap (the errors that it
+/// wraps are shared), which allows to pass
+/// it to all the actors monitoring an actor
+/// that failed.
 pub struct Error {
     kind: ErrorKind,
+    /// The identifier of the actor that
+    /// failed (if any).
+    actor_id: Option<u64>,
+    /// The type name of the actor that
+    /// failed (if any).
+    type_name: Option<&'static str>,
+    /// What the actor was doing when it
+    /// failed (if any).
+    phase: Option<Phase>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ErrorKind {
-    /// Returns a `Box` contaning any error type
+    /// Returns an `Arc` contaning any error type
     /// that implements the [`Error`] trait.
     ///
     /// [`Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
-    Std(Box<dyn error::Error + Send>),
+    Std(Arc<dyn error::Error + Send + Sync>),
+    /// An I/O error occured.
+    Io(Arc<io::Error>),
     /// Multiple errors occured.
     Multiple(Vec<Error>),
+    /// The actor panicked (with the given
+    /// message, if the panic's payload was a
+    /// string).
+    Panicked(Option<String>),
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+/// What an actor was doing when it failed.
+pub enum Phase {
+    /// The actor was starting.
+    Starting,
+    /// The actor was handling an action.
+    Action,
+    /// The actor was handling an event.
+    Event,
+    /// The actor was handling a message.
+    Message,
+    /// The actor was stopping.
+    Stopping,
+}
+
+/// An iterator over the errors contained in
+/// an [`Error`], flattening the "multiple
+/// errors" errors, returned by [`Error::iter`].
+///
+/// [`Error`]: struct.Error.html
+/// [`Error::iter`]: struct.Error.html#method.iter
+pub struct Errors<'e> {
+    stack: Vec<slice::Iter<'e, Error>>,
+    next: Option<&'e Error>,
 }
 
 impl Error {
     /// Creates a new boxed error.
     pub(crate) fn std<S>(err: S) -> Self
     where
-        S: error::Error + Send + 'static,
+        S: error::Error + Send + Sync + 'static,
     {
-        Error {
-            kind: ErrorKind::Std(Box::new(err)),
-        }
+        ErrorKind::Std(Arc::new(err)).into()
     }
 
-    /// Creates a new "multiple errors" error.
-    pub(crate) fn multiple(errors: Vec<Error>) -> Self {
-        Error {
-            kind: ErrorKind::Multiple(errors),
-        }
+    /// Creates a new error from the payload
+    /// of a panic.
+    pub(crate) fn panicked(payload: Box<dyn Any + Send>) -> Self {
+        let msg = match payload.downcast::<String>() {
+            Ok(msg) => Some(*msg),
+            Err(payload) => payload.downcast_ref::<&str>().map(|msg| msg.to_string()),
+        };
+
+        ErrorKind::Panicked(msg).into()
     }
 
-    /// Add an error to the current error returning
-    /// a [`Error::Multiple`] error containing the
-    /// two errors.
-    ///
-    /// [`Error::Multiple`]: enum.ErrorKind.html#variant.Multiple
-    pub(crate) fn add_err(self, err: Error) -> Error {
-        let error;
-        match (self.kind, err.kind) {
-            (ErrorKind::Multiple(mut errs), ErrorKind::Multiple(mut errs_)) => {
-                errs.append(&mut errs_);
-                error = Error::multiple(errs);
-            }
-            (ErrorKind::Multiple(mut errs), err) => {
-                errs.push(err.into());
-                error = Error::multiple(errs);
-            }
-            (err, ErrorKind::Multiple(mut errs)) => {
-                errs.push(err.into());
-                error = Error::multiple(errs);
-            }
-            (err, err_) => {
-                return Error {
-                    kind: ErrorKind::Multiple(vec![err.into(), err_.into()]),
-                }
-            }
-        }
+    /// Records that the error occured while
+    /// the actor of type `A` with the given
+    /// identifier was running.
+    pub(crate) fn of_actor<A>(mut self, actor_id: u64) -> Self {
+        self.actor_id = Some(actor_id);
+        self.type_name = Some(any::type_name::<A>());
 
-        error
+        self
     }
 
-    /// If `res` if an `Err`, calls [`add_err`] with it,
-    /// or returns the current error otherwise.
-    ///
-    /// [`add_err`]: #method.add_err
-    pub(crate) fn add_res<O>(self, res: Result<O, Error>) -> Error {
-        match res {
-            Ok(_) => self,
-            Err(err) => self.add_err(err),
-        }
+    /// Records that the error occured while
+    /// the actor of type `A` with the given
+    /// identifier was in the given phase.
+    pub(crate) fn actor<A>(self, actor_id: u64, phase: Phase) -> Self {
+        let mut err = self.of_actor::<A>(actor_id);
+        err.phase = Some(phase);
+
+        err
     }
 
     /// Whether multiple errors occured.
@@ -87,6 +123,56 @@ impl Error {
         }
     }
 
+    /// Whether the error is an I/O error.
+    pub fn is_io(&self) -> bool {
+        if let ErrorKind::Io(_) = self.kind {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the actor panicked.
+    pub fn is_panicked(&self) -> bool {
+        if let ErrorKind::Panicked(_) = self.kind {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the identifier of the actor
+    /// that failed, if the error is related
+    /// to one.
+    pub fn actor_id(&self) -> Option<u64> {
+        self.actor_id
+    }
+
+    /// Returns the type name of the actor
+    /// that failed, if the error is related
+    /// to one.
+    pub fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    /// Returns what the actor that failed
+    /// was doing when the error occured, if
+    /// the error is related to one.
+    pub fn phase(&self) -> Option<Phase> {
+        self.phase
+    }
+
+    /// Returns an iterator over the errors
+    /// that occured, flattening the "multiple
+    /// errors" errors (it only yields the error
+    /// itself if it isn't one).
+    pub fn iter(&self) -> Errors {
+        Errors {
+            stack: vec![],
+            next: Some(self),
+        }
+    }
+
     /// Returns a reference to the error's kind.
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
@@ -99,30 +185,121 @@ impl Error {
     }
 }
 
-impl error::Error for Error {}
+impl<'e> Iterator for Errors<'e> {
+    type Item = &'e Error;
+
+    fn next(&mut self) -> Option<&'e Error> {
+        loop {
+            // We either take the error that
+            // is waiting to be yielded or the
+            // next one in the deepest list of
+            // errors that isn't exhausted.
+            let err = if let Some(err) = self.next.take() {
+                err
+            } else {
+                let errs = self.stack.last_mut()?;
+                match errs.next() {
+                    Some(err) => err,
+                    None => {
+                        self.stack.pop();
+                        continue;
+                    }
+                }
+            };
+
+            // If the error contains multiple
+            // errors, we go through them
+            // before continuing.
+            if let ErrorKind::Multiple(errs) = &err.kind {
+                self.stack.push(errs.iter());
+            } else {
+                return Some(err);
+            }
+        }
+    }
+}
+
+impl<'e> IntoIterator for &'e Error {
+    type Item = &'e Error;
+    type IntoIter = Errors<'e>;
+
+    fn into_iter(self) -> Errors<'e> {
+        self.iter()
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Std(err) => Some(&**err),
+            ErrorKind::Io(err) => Some(&**err),
+            ErrorKind::Multiple(_) | ErrorKind::Panicked(_) => None,
+        }
+    }
+}
 
 impl Display for Error {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if let Some(actor_id) = self.actor_id {
+            write!(fmt, "actor {}", actor_id)?;
+
+            if let Some(type_name) = self.type_name {
+                write!(fmt, " ({})", type_name)?;
+            }
+
+            if let Some(phase) = self.phase {
+                write!(fmt, " failed while {}", phase)?;
+            } else {
+                write!(fmt, " failed")?;
+            }
+
+            write!(fmt, ": ")?;
+        }
+
         match &self.kind {
             ErrorKind::Std(err) => write!(fmt, "{}", err),
-            ErrorKind::Multiple(_) => write!(fmt, "multiple errors",),
+            ErrorKind::Io(err) => write!(fmt, "{}", err),
+            ErrorKind::Multiple(errs) => write!(fmt, "{} errors occured", errs.len()),
+            ErrorKind::Panicked(Some(msg)) => write!(fmt, "panicked: {}", msg),
+            ErrorKind::Panicked(None) => write!(fmt, "panicked"),
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Phase::Starting => write!(fmt, "starting"),
+            Phase::Action => write!(fmt, "handling an action"),
+            Phase::Event => write!(fmt, "handling an event"),
+            Phase::Message => write!(fmt, "handling a message"),
+            Phase::Stopping => write!(fmt, "stopping"),
         }
     }
 }
 
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Error {
-        Error { kind }
+        Error {
+            kind,
+            actor_id: None,
+            type_name: None,
+            phase: None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        ErrorKind::Io(Arc::new(err)).into()
     }
 }
 
 impl<S> From<Box<S>> for Error
 where
-    S: error::Error + Send + 'static,
+    S: error::Error + Send + Sync + 'static,
 {
     fn from(err: Box<S>) -> Error {
-        Error {
-            kind: ErrorKind::Std(err),
-        }
+        ErrorKind::Std(Arc::new(*err)).into()
     }
 }
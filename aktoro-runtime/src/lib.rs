@@ -1,16 +1,30 @@
 #![feature(async_await)]
 
 mod actor;
+mod actors;
+mod backend;
+mod blocking;
+mod dead_letter;
 mod error;
+mod local;
 mod net;
 mod runtime;
 mod tcp;
+mod timer;
 mod udp;
 
 pub use crate::actor::Status;
+pub use crate::blocking::BlockingPool;
+pub use crate::dead_letter::DeadLetters;
 pub use crate::error::Error;
+pub use crate::error::ErrorKind;
+pub use crate::error::Errors;
+pub use crate::error::Phase;
+pub use crate::local::LocalRuntime;
+pub use crate::local::LocalWait;
 pub use crate::net::NetworkManager;
 pub use crate::runtime::Runtime;
 pub use crate::tcp::TcpClient;
 pub use crate::tcp::TcpServer;
+pub use crate::timer::Timer;
 pub use crate::udp::UdpSocket;
@@ -0,0 +1,478 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task;
+use std::task::Poll;
+use std::task::Waker;
+
+use aktoro_raw as raw;
+use fnv::FnvHashMap;
+use futures_core::future::LocalBoxFuture;
+use futures_core::Future;
+use futures_core::Stream;
+use futures_util::task::waker;
+use futures_util::task::ArcWake;
+use futures_util::task::AtomicWaker;
+
+use crate::actors::Actors;
+use crate::dead_letter::DeadLetters;
+use crate::error::Error;
+use crate::timer::Timer;
+
+/// The default maximum number of actors that
+/// a [`LocalRuntime`] polls each time it is
+/// polled.
+///
+/// [`LocalRuntime`]: struct.LocalRuntime.html
+const DEFAULT_TASKS_PER_POLL: usize = 128;
+
+/// An actor runtime driving its actors on the
+/// current thread, allowing to spawn actors
+/// that aren't `Send` (e.g. actors owning an
+/// `Rc`, a GUI handle or a non-`Send` FFI
+/// resource).
+///
+/// The actors are only polled while the
+/// stream returned by [`LocalRuntime::wait`]
+/// is. They can still communicate with the
+/// actors running on a [`Runtime`] through
+/// their channels, as their senders are
+/// `Send`.
+///
+/// ## Note
+///
+/// The sub-actors that the actors spawn using
+/// their context are spawned on their context's
+/// inner runtime and thus have to be `Send`.
+///
+/// [`LocalRuntime::wait`]: #method.wait
+/// [`Runtime`]: struct.Runtime.html
+pub struct LocalRuntime {
+    /// The runtime's actors, along with the
+    /// state passed to them when they are
+    /// spawned.
+    actors: Actors,
+    /// The futures driving the runtime's
+    /// actors, resolving once they are dead
+    /// (only the ones that were woken up are
+    /// polled).
+    tasks: Tasks,
+}
+
+/// The stream returned by
+/// [`LocalRuntime::wait`] that drives its
+/// actors.
+///
+/// [`LocalRuntime::wait`]: struct.LocalRuntime.html#method.wait
+pub struct LocalWait(LocalRuntime);
+
+/// The futures driving a [`LocalRuntime`]'s
+/// actors, along with a queue of the ones
+/// that were woken up.
+///
+/// [`LocalRuntime`]: struct.LocalRuntime.html
+struct Tasks {
+    /// A map matching an actor's ID with the
+    /// future driving it and the waker it is
+    /// polled with.
+    tasks: FnvHashMap<u64, (LocalBoxFuture<'static, ()>, Waker, Arc<Task>)>,
+    /// The IDs of the tasks that were woken up
+    /// and the waker of the stream polling
+    /// them.
+    ready: Arc<Ready>,
+    /// The maximum number of tasks polled
+    /// each time the stream is.
+    per_poll: usize,
+}
+
+/// The queue of the tasks that were woken
+/// up, shared with their wakers.
+struct Ready {
+    queue: Mutex<VecDeque<u64>>,
+    waker: AtomicWaker,
+}
+
+/// The state of a task shared with its
+/// waker.
+struct Task {
+    id: u64,
+    /// Whether the task is already in
+    /// the ready queue.
+    queued: AtomicBool,
+    ready: Arc<Ready>,
+}
+
+impl LocalRuntime {
+    /// Creates a new `LocalRuntime`.
+    pub fn new() -> Self {
+        LocalRuntime::default()
+    }
+
+    /// Returns a list of the runtime's actors'
+    /// identifier.
+    pub fn actors(&self) -> Vec<u64> {
+        self.actors.ids()
+    }
+
+    /// Spawns a new actor on the runtime,
+    /// returning [`Some(Spawned<A>)`] if it
+    /// succeeded or [`None`] if the actor
+    /// stopped itself when [`Actor::starting`]
+    /// was called.
+    ///
+    /// [`Some(Spawned<A>)`]: https://docs.rs/aktoro-raw/*/aktoro_raw/struct.Spawned.html
+    /// [`Actor::starting`]: https://docs.rs/aktoro-raw/*/aktoro_raw/trait.Actor.html#method.starting
+    pub fn spawn<A>(&mut self, actor: A) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor + 'static,
+        A::Context: raw::Context<A, Timer = Timer>,
+    {
+        self.spawn_with(actor, Default::default())
+    }
+
+    /// Spawns a new actor on the runtime,
+    /// passing its context the provided config
+    /// (like [`spawn`]).
+    ///
+    /// [`spawn`]: #method.spawn
+    pub fn spawn_with<A, C>(&mut self, actor: A, config: C::Config) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor<Context = C> + 'static,
+        C: raw::Context<A, Timer = Timer>,
+    {
+        self.spawn_inner(actor, None, config)
+    }
+
+    /// Spawns a new actor created by `factory`
+    /// on the runtime (like [`spawn`]).
+    ///
+    /// When the actor fails, instead of dying,
+    /// it waits to be restarted (see [`restart`])
+    /// or killed.
+    ///
+    /// [`spawn`]: #method.spawn
+    /// [`restart`]: #method.restart
+    pub fn spawn_restartable<A, F>(&mut self, factory: F) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor + 'static,
+        A::Context: raw::Context<A, Timer = Timer>,
+        F: raw::Factory<A>,
+    {
+        self.spawn_restartable_with(factory, Default::default())
+    }
+
+    /// Spawns a new actor created by `factory`
+    /// on the runtime, passing its context the
+    /// provided config (like
+    /// [`spawn_restartable`]).
+    ///
+    /// [`spawn_restartable`]: #method.spawn_restartable
+    pub fn spawn_restartable_with<A, C, F>(
+        &mut self,
+        factory: F,
+        config: C::Config,
+    ) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor<Context = C> + 'static,
+        C: raw::Context<A, Timer = Timer>,
+        F: raw::Factory<A>,
+    {
+        let actor = factory.create();
+        self.spawn_inner(actor, Some(Box::new(factory)), config)
+    }
+
+    /// Asks the actor with the given identifier
+    /// to restart, returning whether it was
+    /// spawned as restartable.
+    pub fn restart(&mut self, id: u64) -> bool {
+        self.actors.restart(id)
+    }
+
+    /// Registers the actor linked to `spawned`
+    /// under `name`, returning an error if
+    /// another actor is already registered under
+    /// it or if the actor isn't running on the
+    /// runtime.
+    pub fn register<A>(
+        &mut self,
+        name: &str,
+        spawned: &raw::Spawned<A>,
+    ) -> Result<(), raw::RegistryError>
+    where
+        A: raw::Actor + 'static,
+    {
+        self.actors.register(name, spawned)
+    }
+
+    /// Returns a [`Spawned`] for the actor
+    /// registered under `name`.
+    ///
+    /// [`Spawned`]: https://docs.rs/aktoro-raw/*/aktoro_raw/struct.Spawned.html
+    pub fn lookup<A>(&self, name: &str) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor + 'static,
+    {
+        self.actors.lookup(name)
+    }
+
+    /// Unregisters the actor registered under
+    /// `name`, returning its identifier.
+    pub fn unregister(&mut self, name: &str) -> Option<u64> {
+        self.actors.unregister(name)
+    }
+
+    /// Returns a stream yielding the messages
+    /// that couldn't be delivered to the
+    /// runtime's actors (see
+    /// [`Runtime::dead_letters`]).
+    ///
+    /// [`Runtime::dead_letters`]: struct.Runtime.html#method.dead_letters
+    pub fn dead_letters(&mut self) -> Option<DeadLetters> {
+        self.actors.dead_letters()
+    }
+
+    /// Sets the sink that the runtime's actors
+    /// (and their sub-actors) will report the
+    /// messages that couldn't be delivered to
+    /// them to, for the actors spawned after
+    /// the call.
+    pub fn set_dead_letters(&mut self, sink: raw::DeadLetterSink) {
+        self.actors.set_dead_letters(sink);
+    }
+
+    /// Sets the spawner passed to the contexts
+    /// of the actors spawned after the call
+    /// (that their sub-actors are spawned on).
+    pub fn set_spawner(&mut self, spawner: raw::Spawner) {
+        self.actors.set_spawner(spawner);
+    }
+
+    /// Sets the spawner that the actors spawned
+    /// after the call (and their sub-actors) run
+    /// their blocking closures on.
+    pub fn set_blocking_spawner(&mut self, spawner: raw::BlockingSpawner) {
+        self.actors.set_blocking_spawner(spawner);
+    }
+
+    /// Sets the table that the actors spawned
+    /// after the call (and their sub-actors) use
+    /// to watch the other actors (sharing the
+    /// table of a [`Runtime`] allows them to
+    /// watch its actors).
+    ///
+    /// [`Runtime`]: struct.Runtime.html
+    pub fn set_monitors(&mut self, monitors: raw::Monitors) {
+        self.actors.set_monitors(monitors);
+    }
+
+    /// Sets the registry that the actors spawned
+    /// after the call are registered in and that
+    /// they (and their sub-actors) use to look
+    /// up the other actors (sharing the registry
+    /// of a [`Runtime`] allows them to look up
+    /// its actors).
+    ///
+    /// [`Runtime`]: struct.Runtime.html
+    pub fn set_registry(&mut self, registry: raw::Registry) {
+        self.actors.set_registry(registry);
+    }
+
+    /// Sets the timer that the actors spawned
+    /// after the call (and their sub-actors)
+    /// use to schedule messages.
+    pub fn set_timer(&mut self, timer: Timer) {
+        self.actors.set_timer(timer);
+    }
+
+    /// Returns the timer passed to the actors'
+    /// contexts.
+    pub fn timer(&self) -> Timer {
+        self.actors.timer().clone()
+    }
+
+    /// Sets the maximum number of actions,
+    /// events and messages that the actors
+    /// spawned afterwards handle each time they
+    /// are polled before yielding to the other
+    /// actors (unless their context overrides
+    /// it).
+    pub fn set_budget(&mut self, budget: usize) {
+        self.actors.set_budget(budget);
+    }
+
+    /// Sets the maximum number of actors that
+    /// were woken up that the stream returned
+    /// by [`wait`] polls each time it is polled
+    /// before yielding to the other tasks
+    /// running on the current thread.
+    ///
+    /// [`wait`]: #method.wait
+    pub fn set_tasks_per_poll(&mut self, tasks: usize) {
+        self.tasks.per_poll = tasks.max(1);
+    }
+
+    /// Returns a stream driving the runtime's
+    /// actors on the current thread and yielding
+    /// their identifier when they fail or die.
+    ///
+    /// ## Note
+    ///
+    /// The stream can be transformed back into
+    /// a runtime.
+    pub fn wait(self) -> LocalWait {
+        LocalWait(self)
+    }
+
+    /// Asks all the runtime's actors to stop.
+    pub fn stop(&mut self) {
+        self.actors.stop();
+    }
+
+    /// Spawns a new actor, which will be
+    /// restartable if `factory` is provided.
+    fn spawn_inner<A, C>(
+        &mut self,
+        actor: A,
+        factory: Option<Box<dyn raw::Factory<A>>>,
+        config: C::Config,
+    ) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor<Context = C> + 'static,
+        C: raw::Context<A, Timer = Timer>,
+    {
+        let (run, spawned) = self.actors.create(actor, factory, config)?;
+
+        // Add the actor to the futures polled
+        // when waiting for the runtime (it will
+        // notify the runtime over the killed
+        // channel once it is dead).
+        self.tasks.push(spawned.actor_id(), run);
+
+        Some(spawned)
+    }
+}
+
+impl LocalWait {
+    /// Returns a reference to the runtime.
+    pub fn runtime(&self) -> &LocalRuntime {
+        &self.0
+    }
+
+    /// Returns the runtime, consuming the
+    /// stream.
+    pub fn into_runtime(self) -> LocalRuntime {
+        self.0
+    }
+}
+
+impl Stream for LocalWait {
+    type Item = Result<u64, (u64, Error)>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        ctx: &mut task::Context,
+    ) -> Poll<Option<Result<u64, (u64, Error)>>> {
+        let rt = &mut self.get_mut().0;
+
+        if rt.actors.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        // We poll the actors that were woken up
+        // (at most as many as allowed, to avoid
+        // starving the other tasks running on
+        // the current thread)...
+        rt.tasks.poll(ctx);
+
+        // ...and then receive the notifications
+        // they sent over the killed channel.
+        rt.actors.poll_killed(ctx)
+    }
+}
+
+impl Tasks {
+    /// Adds a future to the tasks, queuing
+    /// it to be polled.
+    fn push<F>(&mut self, id: u64, fut: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let task = Arc::new(Task {
+            id,
+            queued: AtomicBool::new(false),
+            ready: self.ready.clone(),
+        });
+
+        let waker = waker(task.clone());
+        waker.wake_by_ref();
+
+        self.tasks.insert(id, (Box::pin(fut), waker, task));
+    }
+
+    /// Polls at most `per_poll` of the tasks
+    /// that were woken up, removing the ones
+    /// that resolved.
+    fn poll(&mut self, ctx: &mut task::Context) {
+        self.ready.waker.register(ctx.waker());
+
+        for _ in 0..self.per_poll {
+            let id = match self.ready.queue.lock().unwrap().pop_front() {
+                Some(id) => id,
+                None => return,
+            };
+
+            let done = if let Some((fut, waker, task)) = self.tasks.get_mut(&id) {
+                // We allow the task to be queued
+                // again before polling it, so
+                // that it isn't missed if it is
+                // woken up meanwhile.
+                task.queued.store(false, Ordering::SeqCst);
+
+                let mut ctx = task::Context::from_waker(waker);
+                fut.as_mut().poll(&mut ctx).is_ready()
+            } else {
+                false
+            };
+
+            if done {
+                self.tasks.remove(&id);
+            }
+        }
+
+        // We wake the stream up if there are
+        // tasks left to poll, so that they are
+        // polled after the other tasks had a
+        // chance to run.
+        if !self.ready.queue.lock().unwrap().is_empty() {
+            ctx.waker().wake_by_ref();
+        }
+    }
+}
+
+impl ArcWake for Task {
+    fn wake_by_ref(task: &Arc<Self>) {
+        if !task.queued.swap(true, Ordering::SeqCst) {
+            task.ready.queue.lock().unwrap().push_back(task.id);
+            task.ready.waker.wake();
+        }
+    }
+}
+
+impl Default for LocalRuntime {
+    fn default() -> Self {
+        LocalRuntime {
+            actors: Actors::default(),
+            tasks: Tasks {
+                tasks: FnvHashMap::default(),
+                ready: Arc::new(Ready {
+                    queue: Mutex::new(VecDeque::new()),
+                    waker: AtomicWaker::new(),
+                }),
+                per_poll: DEFAULT_TASKS_PER_POLL,
+            },
+        }
+    }
+}
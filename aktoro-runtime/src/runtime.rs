@@ -1,47 +1,32 @@
-use std::future::Future;
 use std::pin::Pin;
 use std::task;
 use std::task::Poll;
 
 use aktoro_raw as raw;
-use aktoro_raw::Runtime as RawRuntime;
-use fnv::FnvHashMap;
 use futures_core::Stream;
-use rand::FromEntropy;
-use rand::RngCore;
-use rand_xoshiro::Xoshiro512StarStar;
-use runtime::task::JoinHandle;
-
-use crate::actor;
-use crate::actor::Actor;
-use crate::actor::KillSender as Kill;
-use crate::actor::KilledRecver;
-use crate::actor::KilledSender;
+
+use crate::actors::Actors;
+use crate::dead_letter::DeadLetters;
 use crate::error::Error;
 use crate::net::NetworkManager;
-
-/// An actor runtime using the [`runtime`] crate.
+use crate::timer::Timer;
+
+#[derive(Default)]
+/// An actor runtime using the [`runtime`] crate
+/// (or [`tokio`] or [`async-std`], depending on
+/// the enabled features) to spawn its actors,
+/// unless another spawner is provided using
+/// [`Runtime::set_spawner`].
 ///
 /// [`runtime`]: https://docs.rs/runtime
+/// [`tokio`]: https://docs.rs/tokio
+/// [`async-std`]: https://docs.rs/async-std
+/// [`Runtime::set_spawner`]: https://docs.rs/aktoro-raw/*/aktoro_raw/trait.Runtime.html#tymethod.set_spawner
 pub struct Runtime {
-    /// A map matching an actor's ID with
-    /// a sender for its kill channel and
-    /// a handle for it.
-    actors: FnvHashMap<u64, (Kill, JoinHandle<Result<(), Error>>)>,
-    /// A sender for the actors' killed
-    /// channel (it will be cloned and
-    /// passed to all new actors).
-    sender: KilledSender,
-    /// A receiver the the actors' killed
-    /// channel, notified when an actor
-    /// has stopped/been killed.
-    ///
-    /// It is shared among all the runtime's
-    /// actors.
-    recver: KilledRecver,
-    /// A fast (non-cryptographic) random
-    /// number generator.
-    rng: Xoshiro512StarStar,
+    /// The runtime's actors, along with the
+    /// state passed to them when they are
+    /// spawned.
+    actors: Actors,
 }
 
 /// The stream returned by [`Runtime::wait`]
@@ -55,71 +40,186 @@ impl Runtime {
     pub fn new() -> Self {
         Runtime::default()
     }
+
+    /// Returns a stream yielding the messages
+    /// that couldn't be delivered to the
+    /// runtime's actors (and to their
+    /// sub-actors), along with the identifier
+    /// of the actor they were sent to, the
+    /// reason why and their type's name.
+    ///
+    /// The stream can only be taken once (this
+    /// method returns `None` afterwards) and
+    /// doesn't yield anything if the runtime's
+    /// sink was replaced using
+    /// [`Runtime::set_dead_letters`].
+    ///
+    /// [`Runtime::set_dead_letters`]: https://docs.rs/aktoro-raw/*/aktoro_raw/trait.Runtime.html#tymethod.set_dead_letters
+    pub fn dead_letters(&mut self) -> Option<DeadLetters> {
+        self.actors.dead_letters()
+    }
+
+    /// Sets the maximum number of actions,
+    /// events and messages that the actors
+    /// spawned afterwards handle each time they
+    /// are polled before yielding to the other
+    /// tasks (unless their context overrides
+    /// it).
+    ///
+    /// This prevents an actor that is flooded
+    /// with messages from starving the other
+    /// tasks running on the same thread, as
+    /// long as the executor queues the tasks
+    /// that woke themselves up behind the
+    /// others (`futures`' `ThreadPool` polls
+    /// them again right away).
+    pub fn set_budget(&mut self, budget: usize) {
+        self.actors.set_budget(budget);
+    }
+
+    /// Spawns a new actor, which will be
+    /// restartable if `factory` is provided.
+    fn spawn_inner<A, C>(
+        &mut self,
+        actor: A,
+        factory: Option<Box<dyn raw::Factory<A>>>,
+        config: C::Config,
+    ) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor<Context = C> + Send + 'static,
+        C: raw::Context<A, Timer = Timer> + Send,
+    {
+        let (run, spawned) = self.actors.create(actor, factory, config)?;
+
+        // Spawn the actor, which will notify
+        // the runtime over the killed channel
+        // once it is dead.
+        self.actors.spawner().spawn(run);
+
+        Some(spawned)
+    }
 }
 
 impl raw::Runtime for Runtime {
     type NetworkManager = NetworkManager;
 
+    type Timer = Timer;
+
     type Wait = Wait;
 
     type Error = Error;
 
     fn actors(&self) -> Vec<u64> {
-        self.actors.keys().copied().collect()
+        self.actors.ids()
     }
 
     fn spawn<A>(&mut self, actor: A) -> Option<raw::Spawned<A>>
     where
-        A: raw::Actor + 'static,
+        A: raw::Actor + Send + 'static,
+        A::Context: raw::Context<A, Timer = Timer> + Send,
     {
         self.spawn_with(actor, Default::default())
     }
 
     fn spawn_with<A, C>(&mut self, actor: A, config: C::Config) -> Option<raw::Spawned<A>>
     where
-        A: raw::Actor<Context = C> + 'static,
-        C: raw::Context<A>,
+        A: raw::Actor<Context = C> + Send + 'static,
+        C: raw::Context<A, Timer = Timer> + Send,
+    {
+        self.spawn_inner(actor, None, config)
+    }
+
+    fn spawn_restartable<A, F>(&mut self, factory: F) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor + Send + 'static,
+        A::Context: raw::Context<A, Timer = Timer> + Send,
+        F: raw::Factory<A>,
+    {
+        self.spawn_restartable_with(factory, Default::default())
+    }
+
+    fn spawn_restartable_with<A, C, F>(
+        &mut self,
+        factory: F,
+        config: C::Config,
+    ) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor<Context = C> + Send + 'static,
+        C: raw::Context<A, Timer = Timer> + Send,
+        F: raw::Factory<A>,
+    {
+        let actor = factory.create();
+        self.spawn_inner(actor, Some(Box::new(factory)), config)
+    }
+
+    fn restart(&mut self, id: u64) -> bool {
+        self.actors.restart(id)
+    }
+
+    fn register<A>(
+        &mut self,
+        name: &str,
+        spawned: &raw::Spawned<A>,
+    ) -> Result<(), raw::RegistryError>
+    where
+        A: raw::Actor + 'static,
     {
-        // Generate the actor's ID.
-        let id = self.rng.next_u64();
+        self.actors.register(name, spawned)
+    }
 
-        // Create a new context for the actor.
-        let mut ctx = C::new(id, config);
+    fn lookup<A>(&self, name: &str) -> Option<raw::Spawned<A>>
+    where
+        A: raw::Actor + 'static,
+    {
+        self.actors.lookup(name)
+    }
 
-        // Create a new `Spawned` struct from
-        // the actor's context.
-        let spawned = raw::Spawned::new(&mut ctx);
+    fn unregister(&mut self, name: &str) -> Option<u64> {
+        self.actors.unregister(name)
+    }
 
-        // Create the actor's kill channel.
-        let (sender, recver) = actor::new_kill();
+    fn set_dead_letters(&mut self, sink: raw::DeadLetterSink) {
+        self.actors.set_dead_letters(sink);
+    }
 
-        // Try to create the actor (fails if
-        // it refused to start).
-        let actor = Actor::new(id, actor, recver, self.sender.clone(), ctx)?;
+    fn set_spawner(&mut self, spawner: raw::Spawner) {
+        self.actors.set_spawner(spawner);
+    }
 
-        // Spawn the actor.
-        let handle = runtime::spawn(actor);
+    fn set_blocking_spawner(&mut self, spawner: raw::BlockingSpawner) {
+        self.actors.set_blocking_spawner(spawner);
+    }
 
-        // Save the actor's kill channel's
-        // sender.
-        self.actors.insert(id, (sender, handle));
+    fn blocking_spawner(&self) -> raw::BlockingSpawner {
+        self.actors.blocking_spawner().clone()
+    }
 
-        Some(spawned)
+    fn set_monitors(&mut self, monitors: raw::Monitors) {
+        self.actors.set_monitors(monitors);
+    }
+
+    fn set_registry(&mut self, registry: raw::Registry) {
+        self.actors.set_registry(registry);
+    }
+
+    fn set_timer(&mut self, timer: Timer) {
+        self.actors.set_timer(timer);
     }
 
     fn net(&mut self) -> NetworkManager {
         NetworkManager
     }
 
+    fn timer(&mut self) -> Timer {
+        self.actors.timer().clone()
+    }
+
     fn wait(self) -> Wait {
         Wait(self)
     }
 
     fn stop(&mut self) {
-        // Ask to every actor to stop.
-        for (_, actor) in self.actors.iter_mut() {
-            actor.0.kill();
-        }
+        self.actors.stop();
     }
 }
 
@@ -140,68 +240,6 @@ impl Stream for Wait {
         self: Pin<&mut Self>,
         ctx: &mut task::Context,
     ) -> Poll<Option<Result<u64, (u64, Error)>>> {
-        let rt = &mut self.get_mut().0;
-
-        if rt.actors.is_empty() {
-            return Poll::Ready(None);
-        }
-
-        // We poll all the runtime's actors until
-        // one yields.
-        let mut remove = None;
-        for (id, act) in rt.actors.iter_mut() {
-            if let Poll::Ready(res) = Pin::new(&mut act.1).poll(ctx) {
-                remove = Some((*id, res));
-
-                break;
-            }
-        }
-
-        // If an actor yielded, we remove it from
-        // the actors list and yield what's been
-        // yielded.
-        if let Some((id, res)) = remove {
-            let removed = rt.actors.remove(&id);
-
-            match (removed, res) {
-                (Some(_), Err(err)) => return Poll::Ready(Some(Err((id, err)))),
-                (None, Err(err)) => return Poll::Ready(Some(Err((id, Error::std(err))))),
-                _ => return Poll::Ready(Some(Ok(id))),
-            }
-        }
-
-        // We try to receive the identifier of the
-        // dead actors via the killed channel, to
-        // remove them and yield an update.
-        match Pin::new(&mut rt.recver).poll_next(ctx) {
-            Poll::Ready(Some(actor)) => {
-                rt.actors.remove(&actor);
-
-                return Poll::Ready(Some(Ok(actor)));
-            }
-            Poll::Ready(None) => return Poll::Ready(None),
-            Poll::Pending => (),
-        }
-
-        Poll::Pending
-    }
-}
-
-impl Default for Runtime {
-    fn default() -> Self {
-        let (sender, recver) = actor::new_killed();
-
-        Runtime {
-            actors: FnvHashMap::default(),
-            sender,
-            recver,
-            rng: Xoshiro512StarStar::from_entropy(),
-        }
-    }
-}
-
-impl Drop for Runtime {
-    fn drop(&mut self) {
-        self.stop()
+        self.get_mut().0.actors.poll_killed(ctx)
     }
 }
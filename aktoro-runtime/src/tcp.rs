@@ -6,12 +6,13 @@ use std::task;
 use std::task::Poll;
 
 use aktoro_raw as raw;
+use futures_core::future::BoxFuture;
 use futures_core::Stream;
 use futures_io as io;
 use futures_io::AsyncRead;
 use futures_io::AsyncWrite;
-use runtime::net;
 
+use crate::backend;
 use crate::error::Error;
 
 /// A TCP client allowing to connect to
@@ -19,7 +20,7 @@ use crate::error::Error;
 pub struct TcpClient {
     /// The TCP stream between the client
     /// and the server.
-    stream: net::TcpStream,
+    stream: backend::TcpStream,
 }
 
 /// A TCP server allowing to listen for
@@ -28,7 +29,7 @@ pub struct TcpClient {
 pub struct TcpServer {
     /// The stream that receives new TCP
     /// connections.
-    listener: net::TcpListener,
+    listener: backend::TcpListener,
 }
 
 /// A future returned by
@@ -40,14 +41,15 @@ pub struct TcpServer {
 /// [`TcpClient::connect`]: struct.TcpClient.html#method.connect
 pub struct Connect {
     /// The actual future.
-    connect: net::tcp::ConnectFuture,
+    connect: BoxFuture<'static, io::Result<backend::TcpStream>>,
 }
 
 /// A stream that yields new TCP
 /// connections.
 pub struct TcpIncoming<'i> {
-    /// The actual stream.
-    incoming: net::tcp::IncomingStream<'i>,
+    /// The stream that receives new TCP
+    /// connections.
+    listener: &'i mut backend::TcpListener,
 }
 
 /// A stream that yields new TCP
@@ -69,7 +71,7 @@ pub struct OwnedTcpIcoming {
 /// a client.
 pub struct TcpStream {
     /// The actual stream.
-    stream: net::TcpStream,
+    stream: backend::TcpStream,
 }
 
 impl raw::TcpClient for TcpClient {
@@ -79,7 +81,7 @@ impl raw::TcpClient for TcpClient {
 
     fn connect<A: ToSocketAddrs>(addr: A) -> Result<Self::Connect, Error> {
         Ok(Connect {
-            connect: net::TcpStream::connect(addr),
+            connect: backend::connect(addr.to_socket_addrs()?.collect()),
         })
     }
 }
@@ -90,16 +92,16 @@ impl raw::TcpServer for TcpServer {
     type Error = Error;
 
     fn bind<A: ToSocketAddrs>(addr: A) -> Result<Self, Error> {
-        match net::TcpListener::bind(addr) {
+        match backend::bind_tcp(addr.to_socket_addrs()?.collect()) {
             Ok(listener) => Ok(TcpServer { listener }),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 
     fn local_addr(&self) -> Result<SocketAddr, Self::Error> {
         match self.listener.local_addr() {
             Ok(addr) => Ok(addr),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 
@@ -107,7 +109,7 @@ impl raw::TcpServer for TcpServer {
         &'i mut self,
     ) -> Result<Box<dyn Stream<Item = Result<TcpStream, Error>> + Unpin + Send + 'i>, Error> {
         Ok(Box::new(TcpIncoming {
-            incoming: self.listener.incoming(),
+            listener: &mut self.listener,
         }))
     }
 
@@ -124,14 +126,14 @@ impl raw::TcpStream for TcpClient {
     fn local_addr(&self) -> Result<SocketAddr, Error> {
         match self.stream.local_addr() {
             Ok(addr) => Ok(addr),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 
     fn peer_addr(&self) -> Result<SocketAddr, Error> {
         match self.stream.peer_addr() {
             Ok(addr) => Ok(addr),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 }
@@ -142,14 +144,14 @@ impl raw::TcpStream for TcpStream {
     fn local_addr(&self) -> Result<SocketAddr, Error> {
         match self.stream.local_addr() {
             Ok(addr) => Ok(addr),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 
     fn peer_addr(&self) -> Result<SocketAddr, Error> {
         match self.stream.peer_addr() {
             Ok(addr) => Ok(addr),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 }
@@ -160,7 +162,7 @@ impl Future for Connect {
     fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Self::Output> {
         match Pin::new(&mut self.get_mut().connect).poll(ctx) {
             Poll::Ready(Ok(stream)) => Poll::Ready(Ok(TcpClient { stream })),
-            Poll::Ready(Err(err)) => Poll::Ready(Err(Box::new(err).into())),
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err.into())),
             Poll::Pending => Poll::Pending,
         }
     }
@@ -170,12 +172,9 @@ impl<'i> Stream for TcpIncoming<'i> {
     type Item = Result<TcpStream, Error>;
 
     fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<Self::Item>> {
-        match Pin::new(&mut self.get_mut().incoming).poll_next(ctx) {
-            Poll::Ready(Some(res)) => match res {
-                Ok(stream) => Poll::Ready(Some(Ok(TcpStream { stream }))),
-                Err(err) => Poll::Ready(Some(Err(Box::new(err).into()))),
-            },
-            Poll::Ready(None) => Poll::Ready(None),
+        match backend::poll_accept(self.get_mut().listener, ctx) {
+            Poll::Ready(Ok(stream)) => Poll::Ready(Some(Ok(TcpStream { stream }))),
+            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(err.into()))),
             Poll::Pending => Poll::Pending,
         }
     }
@@ -185,9 +184,9 @@ impl Stream for OwnedTcpIcoming {
     type Item = Result<TcpStream, Error>;
 
     fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<Self::Item>> {
-        match Pin::new(&mut self.get_mut().server.listener.accept()).poll(ctx) {
-            Poll::Ready(Ok((stream, _))) => Poll::Ready(Some(Ok(TcpStream { stream }))),
-            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(Box::new(err).into()))),
+        match backend::poll_accept(&mut self.get_mut().server.listener, ctx) {
+            Poll::Ready(Ok(stream)) => Poll::Ready(Some(Ok(TcpStream { stream }))),
+            Poll::Ready(Err(err)) => Poll::Ready(Some(Err(err.into()))),
             Poll::Pending => Poll::Pending,
         }
     }
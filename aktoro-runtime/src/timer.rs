@@ -0,0 +1,169 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use aktoro_raw as raw;
+use futures_core::Stream;
+
+use crate::backend::time;
+
+#[derive(Default, Clone)]
+/// A timer using the [`runtime`] crate's
+/// timers (or the [`futures-timer`] crate's
+/// if the `runtime` feature is disabled),
+/// unless it was created from another timer
+/// using [`Timer::new`] (e.g. to use a
+/// virtual clock in tests).
+///
+/// [`runtime`]: https://docs.rs/runtime
+/// [`futures-timer`]: https://docs.rs/futures-timer
+/// [`Timer::new`]: #method.new
+pub struct Timer(Option<Arc<dyn Source>>);
+
+/// A future returned by [`Timer::delay`]
+/// and [`Timer::delay_until`] and that
+/// resolves after the delay has elapsed.
+///
+/// [`Timer::delay`]: struct.Timer.html#method.delay
+/// [`Timer::delay_until`]: struct.Timer.html#method.delay_until
+pub struct Delay {
+    /// The actual future.
+    delay: DelayInner,
+}
+
+/// A stream returned by [`Timer::interval`]
+/// and that yields every time the interval
+/// has elapsed.
+///
+/// [`Timer::interval`]: struct.Timer.html#method.interval
+pub struct Interval {
+    /// The actual stream.
+    interval: IntervalInner,
+}
+
+/// The backend's future or the one of
+/// the timer passed to [`Timer::new`].
+///
+/// [`Timer::new`]: struct.Timer.html#method.new
+enum DelayInner {
+    Backend(time::Delay),
+    Custom(Pin<Box<dyn Future<Output = ()> + Send>>),
+}
+
+/// The backend's stream or the one of
+/// the timer passed to [`Timer::new`].
+///
+/// [`Timer::new`]: struct.Timer.html#method.new
+enum IntervalInner {
+    Backend(time::Interval),
+    Custom(Pin<Box<dyn Stream<Item = ()> + Send>>),
+}
+
+/// A timer passed to [`Timer::new`], with
+/// its futures and streams boxed.
+///
+/// [`Timer::new`]: struct.Timer.html#method.new
+trait Source: Send + Sync {
+    fn delay(&self, dur: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    fn delay_until(&self, at: Instant) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    fn interval(&self, dur: Duration) -> Pin<Box<dyn Stream<Item = ()> + Send>>;
+}
+
+impl Timer {
+    /// Creates a new timer using `timer`'s
+    /// futures and streams instead of the
+    /// backend's.
+    pub fn new<T>(timer: T) -> Self
+    where
+        T: raw::Timer + Sync + 'static,
+    {
+        Timer(Some(Arc::new(timer)))
+    }
+}
+
+impl raw::Timer for Timer {
+    type Delay = Delay;
+    type Interval = Interval;
+
+    fn delay(&self, dur: Duration) -> Delay {
+        let delay = if let Some(source) = &self.0 {
+            DelayInner::Custom(source.delay(dur))
+        } else {
+            DelayInner::Backend(time::Delay::new(dur))
+        };
+
+        Delay { delay }
+    }
+
+    fn delay_until(&self, at: Instant) -> Delay {
+        let delay = if let Some(source) = &self.0 {
+            DelayInner::Custom(source.delay_until(at))
+        } else {
+            DelayInner::Backend(time::Delay::new_at(at))
+        };
+
+        Delay { delay }
+    }
+
+    fn interval(&self, dur: Duration) -> Interval {
+        let interval = if let Some(source) = &self.0 {
+            IntervalInner::Custom(source.interval(dur))
+        } else {
+            IntervalInner::Backend(time::Interval::new(dur))
+        };
+
+        Interval { interval }
+    }
+}
+
+impl<T> Source for T
+where
+    T: raw::Timer + Sync + 'static,
+{
+    fn delay(&self, dur: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(raw::Timer::delay(self, dur))
+    }
+
+    fn delay_until(&self, at: Instant) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(raw::Timer::delay_until(self, at))
+    }
+
+    fn interval(&self, dur: Duration) -> Pin<Box<dyn Stream<Item = ()> + Send>> {
+        Box::pin(raw::Timer::interval(self, dur))
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<()> {
+        match &mut self.get_mut().delay {
+            DelayInner::Backend(delay) => match Pin::new(delay).poll(ctx) {
+                Poll::Ready(_) => Poll::Ready(()),
+                Poll::Pending => Poll::Pending,
+            },
+            DelayInner::Custom(delay) => delay.as_mut().poll(ctx),
+        }
+    }
+}
+
+impl Stream for Interval {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<()>> {
+        match &mut self.get_mut().interval {
+            IntervalInner::Backend(interval) => match Pin::new(interval).poll_next(ctx) {
+                Poll::Ready(Some(_)) => Poll::Ready(Some(())),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            },
+            IntervalInner::Custom(interval) => interval.as_mut().poll_next(ctx),
+        }
+    }
+}
@@ -5,9 +5,12 @@ use std::pin::Pin;
 use std::task;
 use std::task::Poll;
 
+use std::io;
+
 use aktoro_raw as raw;
-use runtime::net;
+use futures_core::future::LocalBoxFuture;
 
+use crate::backend;
 use crate::error::Error;
 
 /// A UDP socket, allowing to listen for
@@ -15,7 +18,7 @@ use crate::error::Error;
 /// communicate with other sockets.
 pub struct UdpSocket {
     /// The actual socket.
-    socket: net::UdpSocket,
+    socket: backend::UdpSocket,
 }
 
 /// A future returned by [`UdpSocket::send_to`]
@@ -25,9 +28,9 @@ pub struct UdpSocket {
 /// bytes sent.
 ///
 /// [`UdpSocket::send_to`]: struct.UdpSocket.html#method.send_to
-pub struct SendTo<'s, 'b> {
+pub struct SendTo<'s> {
     /// The actual future.
-    send_to: net::udp::SendToFuture<'s, 'b>,
+    send_to: LocalBoxFuture<'s, io::Result<usize>>,
 }
 
 /// A future returned by [`UdpSocket::recv`]
@@ -38,25 +41,25 @@ pub struct SendTo<'s, 'b> {
 /// the address of the sender.
 ///
 /// [`UdpSocket::recv`]: struct.UdpSocket.html#method.recv
-pub struct Recv<'s, 'b> {
+pub struct Recv<'s> {
     /// The actual future.
-    recv_from: net::udp::RecvFromFuture<'s, 'b>,
+    recv_from: LocalBoxFuture<'s, io::Result<(usize, SocketAddr)>>,
 }
 
 impl raw::UdpSocket for UdpSocket {
     type Error = Error;
 
     fn bind<A: ToSocketAddrs>(addr: A) -> Result<Self, Error> {
-        match net::UdpSocket::bind(addr) {
+        match backend::bind_udp(addr.to_socket_addrs()?.collect()) {
             Ok(socket) => Ok(UdpSocket { socket }),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 
     fn local_addr(&self) -> Result<SocketAddr, Error> {
         match self.socket.local_addr() {
             Ok(addr) => Ok(addr),
-            Err(err) => Err(Box::new(err).into()),
+            Err(err) => Err(err.into()),
         }
     }
 
@@ -65,37 +68,44 @@ impl raw::UdpSocket for UdpSocket {
         buf: &'s [u8],
         addr: A,
     ) -> Result<raw::UdpSocketSendTo<'s, Error>, Error> {
+        // We send the data to the first address
+        // the given one resolves to.
+        let addr = match addr.to_socket_addrs()?.next() {
+            Some(addr) => addr,
+            None => return Err(backend::no_addrs().into()),
+        };
+
         Ok(Box::new(SendTo {
-            send_to: self.socket.send_to(buf, addr),
+            send_to: backend::send_to(&mut self.socket, buf, addr),
         }))
     }
 
     fn recv<'s>(&'s mut self, buf: &'s mut [u8]) -> Result<raw::UdpSocketRecv<'s, Error>, Error> {
         Ok(Box::new(Recv {
-            recv_from: self.socket.recv_from(buf),
+            recv_from: backend::recv_from(&mut self.socket, buf),
         }))
     }
 }
 
-impl<'s, 'b> Future for SendTo<'s, 'b> {
+impl<'s> Future for SendTo<'s> {
     type Output = Result<usize, Error>;
 
     fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Self::Output> {
         match Pin::new(&mut self.get_mut().send_to).poll(ctx) {
             Poll::Ready(Ok(sent)) => Poll::Ready(Ok(sent)),
-            Poll::Ready(Err(err)) => Poll::Ready(Err(Box::new(err).into())),
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err.into())),
             Poll::Pending => Poll::Pending,
         }
     }
 }
 
-impl<'s, 'b> Future for Recv<'s, 'b> {
+impl<'s> Future for Recv<'s> {
     type Output = Result<(usize, SocketAddr), Error>;
 
     fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Self::Output> {
         match Pin::new(&mut self.get_mut().recv_from).poll(ctx) {
             Poll::Ready(Ok(recved)) => Poll::Ready(Ok(recved)),
-            Poll::Ready(Err(err)) => Poll::Ready(Err(Box::new(err).into())),
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err.into())),
             Poll::Pending => Poll::Pending,
         }
     }
@@ -0,0 +1,118 @@
+mod common;
+
+use aktoro_context::Context;
+use aktoro_raw::ActionHandler;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::future;
+
+use self::common::Failed;
+use self::common::Fifo;
+
+/// An actor doubling the numbers it
+/// receives.
+struct Doubler;
+
+struct Double(u64);
+
+struct Fail;
+
+struct Stop;
+
+impl Actor for Doubler {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Handler<Double> for Doubler {
+    type Output = u64;
+
+    fn handle(&mut self, Double(n): Double, _: &mut Self::Context) -> Result<u64, Failed> {
+        Ok(n * 2)
+    }
+}
+
+impl ActionHandler<Double> for Doubler {
+    type Output = u64;
+
+    fn handle(&mut self, Double(n): Double, _: &mut Self::Context) -> Result<u64, Failed> {
+        Ok(n * 2)
+    }
+}
+
+impl Handler<Fail> for Doubler {
+    type Output = u64;
+
+    fn handle(&mut self, _: Fail, _: &mut Self::Context) -> Result<u64, Failed> {
+        Err(Failed)
+    }
+}
+
+impl Handler<Stop> for Doubler {
+    type Output = u64;
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<u64, Failed> {
+        ctx.set_status(Status::Dead);
+        Ok(0)
+    }
+}
+
+#[test]
+fn resolves_with_the_output() {
+    let mut rt = common::runtime();
+
+    let mut spawned = rt.spawn(Doubler).unwrap();
+
+    let ask = spawned.ask(Double(21), future::pending()).unwrap();
+    assert_eq!(block_on(ask), Ok(42));
+
+    let ask = spawned.ask_action(Double(4), future::pending()).unwrap();
+    assert_eq!(block_on(ask), Ok(8));
+}
+
+#[test]
+fn fails_when_the_handler_fails() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let mut spawned = rt.spawn(Doubler).unwrap();
+
+    let ask = spawned.ask(Fail, future::pending()).unwrap();
+    fifo.run();
+
+    assert!(block_on(ask).unwrap_err().is_failed());
+}
+
+#[test]
+fn fails_when_the_actor_dies() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let mut spawned = rt.spawn(Doubler).unwrap();
+
+    drop(spawned.try_send_msg(Stop).unwrap());
+    let ask = spawned.ask(Double(1), future::pending()).unwrap();
+    fifo.run();
+
+    assert!(block_on(ask).unwrap_err().is_dead());
+}
+
+#[test]
+fn fails_when_the_timeout_elapses() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let mut spawned = rt.spawn(Doubler).unwrap();
+
+    // The actor is never polled, so the
+    // timeout resolves first.
+    let ask = spawned.ask(Double(1), future::ready(())).unwrap();
+
+    assert!(block_on(ask).unwrap_err().is_timeout());
+}
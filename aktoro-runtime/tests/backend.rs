@@ -0,0 +1,72 @@
+mod common;
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use aktoro_context::Context;
+use aktoro_raw as raw;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Error;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+
+use self::common::Fifo;
+
+/// An actor spawning a child when it
+/// is started.
+struct Parent(Arc<AtomicUsize>);
+
+/// An actor counting how many times it
+/// was started.
+struct Child(Arc<AtomicUsize>);
+
+impl Actor for Parent {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.spawn(Child(self.0.clone())).unwrap();
+    }
+}
+
+impl Actor for Child {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+
+    fn started(&mut self, _: &mut Self::Context) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn spawns_actors_on_a_custom_executor() {
+    let fifo = Fifo::default();
+    let inner = fifo.spawner();
+
+    // We count the futures spawned on the
+    // executor before queuing them.
+    let spawned = Arc::new(AtomicUsize::new(0));
+    let spawned_ = spawned.clone();
+    let spawner = raw::Spawner::new(move |fut| {
+        spawned_.fetch_add(1, Ordering::SeqCst);
+        inner.spawn(fut);
+    });
+
+    let mut rt = Runtime::new();
+    rt.set_spawner(spawner);
+
+    let started = Arc::new(AtomicUsize::new(0));
+    rt.spawn(Parent(started.clone())).unwrap();
+
+    fifo.run();
+
+    // Both the parent and the child it spawned
+    // using its context run on the executor.
+    assert_eq!(started.load(Ordering::SeqCst), 1);
+    assert_eq!(spawned.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aktoro_channel as channel;
+use aktoro_context::Context;
+use aktoro_raw::Actor;
+use aktoro_raw::BatchHandler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+
+use self::common::Failed;
+use self::common::Fifo;
+
+/// An actor subscribing to a channel by
+/// batches and logging them.
+struct Batcher {
+    recver: Option<channel::Receiver<u8>>,
+    batches: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+impl Actor for Batcher {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.subscribe_batch(self.recver.take().unwrap(), 2);
+    }
+}
+
+impl BatchHandler<u8> for Batcher {
+    fn handle_batch(&mut self, msgs: Vec<u8>, _: &mut Self::Context) -> Result<(), Failed> {
+        self.batches.lock().unwrap().push(msgs);
+        Ok(())
+    }
+}
+
+#[test]
+fn handles_messages_by_batches() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let (sender, recver) = channel::Builder::new().build();
+    let batches = Arc::new(Mutex::new(vec![]));
+
+    rt.spawn(Batcher {
+        recver: Some(recver),
+        batches: batches.clone(),
+    })
+    .unwrap();
+
+    sender.try_send_many(0..5).unwrap();
+    fifo.run();
+
+    assert_eq!(
+        *batches.lock().unwrap(),
+        vec![vec![0, 1], vec![2, 3], vec![4]]
+    );
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use aktoro_context::Context;
+use aktoro_raw as raw;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::BlockingPool;
+use aktoro_runtime::Error;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+/// The number of closures run by the actor.
+const CLOSURES: usize = 6;
+
+/// The maximum number of threads of the
+/// blocking pool.
+const THREADS: usize = 2;
+
+/// An actor running closures on the blocking
+/// pool, stopping once all of them are done.
+struct Runner {
+    /// The number of closures running.
+    running: Arc<AtomicUsize>,
+    /// The maximum number of closures that
+    /// were running at the same time.
+    max: Arc<AtomicUsize>,
+    /// The number of closures done.
+    done: usize,
+}
+
+struct Run;
+
+struct Done;
+
+impl Actor for Runner {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl Handler<Run> for Runner {
+    type Output = ();
+
+    fn handle(&mut self, _: Run, ctx: &mut Self::Context) -> Result<(), Error> {
+        for _ in 0..CLOSURES {
+            let running = self.running.clone();
+            let max = self.max.clone();
+
+            ctx.spawn_blocking(
+                move || {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    let mut cur = max.load(Ordering::SeqCst);
+                    while now > cur {
+                        match max.compare_exchange(cur, now, Ordering::SeqCst, Ordering::SeqCst) {
+                            Ok(_) => break,
+                            Err(max) => cur = max,
+                        }
+                    }
+
+                    thread::sleep(Duration::from_millis(20));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                },
+                |()| Done,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Handler<Done> for Runner {
+    type Output = ();
+
+    fn handle(&mut self, _: Done, ctx: &mut Self::Context) -> Result<(), Error> {
+        self.done += 1;
+        if self.done == CLOSURES {
+            ctx.set_status(Status::Dead);
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn blocking_closures_run_on_a_bounded_pool() {
+    let mut rt = common::runtime();
+    rt.set_blocking_spawner(raw::BlockingSpawner::new(BlockingPool::new(THREADS)));
+
+    let max = Arc::new(AtomicUsize::new(0));
+    let mut spawned = rt
+        .spawn(Runner {
+            running: Arc::new(AtomicUsize::new(0)),
+            max: max.clone(),
+            done: 0,
+        })
+        .unwrap();
+
+    drop(spawned.try_send_msg(Run).unwrap());
+
+    let mut wait = rt.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), spawned.actor_id());
+
+    assert!(max.load(Ordering::SeqCst) <= THREADS);
+}
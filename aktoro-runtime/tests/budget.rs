@@ -0,0 +1,109 @@
+mod common;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aktoro_context::Context;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Error;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Fifo;
+
+/// The number of messages sent to each
+/// actor.
+const FLOOD: usize = 64;
+
+/// An actor saving its name to a shared log
+/// each time it handles a message.
+struct Logger(char, Arc<Mutex<Vec<char>>>);
+
+struct Log;
+
+struct Stop;
+
+impl Actor for Logger {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl Handler<Log> for Logger {
+    type Output = ();
+
+    fn handle(&mut self, _: Log, _: &mut Self::Context) -> Result<(), Error> {
+        self.1.lock().unwrap().push(self.0);
+        Ok(())
+    }
+}
+
+impl Handler<Stop> for Logger {
+    type Output = ();
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<(), Error> {
+        ctx.set_status(Status::Dead);
+        Ok(())
+    }
+}
+
+#[test]
+fn flooded_actors_interleave() {
+    let budget = 4;
+
+    // We only run the actors once both
+    // mailboxes are full.
+    let fifo = Fifo::default();
+
+    let mut rt = fifo.runtime();
+    rt.set_budget(budget);
+
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut first = rt.spawn(Logger('a', log.clone())).unwrap();
+    let mut second = rt.spawn(Logger('b', log.clone())).unwrap();
+
+    for _ in 0..FLOOD {
+        drop(first.try_send_msg(Log).unwrap());
+        drop(second.try_send_msg(Log).unwrap());
+    }
+
+    drop(first.try_send_msg(Stop).unwrap());
+    drop(second.try_send_msg(Stop).unwrap());
+
+    fifo.run();
+
+    let mut wait = rt.wait();
+    assert!(block_on(wait.next()).unwrap().is_ok());
+    assert!(block_on(wait.next()).unwrap().is_ok());
+
+    let log = log.lock().unwrap();
+    assert_eq!(log.len(), FLOOD * 2);
+
+    // Neither actor handled more than its
+    // budget of messages in a row...
+    let mut run = 0;
+    let mut prev = None;
+    for name in log.iter() {
+        if prev == Some(name) {
+            run += 1;
+        } else {
+            run = 1;
+            prev = Some(name);
+        }
+
+        assert!(run <= budget, "{:?}", log);
+    }
+
+    // ...so that both made progress before
+    // either was done.
+    let last_a = log.iter().rposition(|name| *name == 'a').unwrap();
+    let last_b = log.iter().rposition(|name| *name == 'b').unwrap();
+    let first_a = log.iter().position(|name| *name == 'a').unwrap();
+    let first_b = log.iter().position(|name| *name == 'b').unwrap();
+    assert!(first_a < last_b && first_b < last_a);
+}
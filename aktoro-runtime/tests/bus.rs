@@ -0,0 +1,131 @@
+mod common;
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context as TaskContext;
+use std::task::Poll;
+
+use aktoro_context::Context;
+use aktoro_context::EventBus;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::EventBus as RawEventBus;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_core::Stream;
+use futures_util::task::noop_waker;
+
+use self::common::Failed;
+use self::common::Fifo;
+
+#[derive(Clone)]
+/// A message published on the bus.
+struct News(&'static str);
+
+/// An actor subscribing to the news
+/// published on a bus and logging them.
+struct Reader {
+    name: &'static str,
+    bus: EventBus,
+    log: Arc<Mutex<Vec<(&'static str, &'static str)>>>,
+}
+
+impl Actor for Reader {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.subscribe_topic::<EventBus, News>(&self.bus);
+    }
+}
+
+impl Handler<News> for Reader {
+    type Output = ();
+
+    fn handle(&mut self, News(news): News, _: &mut Self::Context) -> Result<(), Failed> {
+        self.log.lock().unwrap().push((self.name, news));
+        Ok(())
+    }
+}
+
+#[test]
+fn delivers_published_messages_to_subscribed_actors() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let bus = EventBus::default();
+    let log = Arc::new(Mutex::new(vec![]));
+
+    for name in &["alice", "bob"] {
+        rt.spawn(Reader {
+            name,
+            bus: bus.clone(),
+            log: log.clone(),
+        })
+        .unwrap();
+    }
+
+    // We start the actors so that they
+    // subscribe to the bus.
+    fifo.run();
+    assert_eq!(bus.subscribers::<News>(), 2);
+
+    assert_eq!(bus.publish(News("first")), 2);
+    assert_eq!(bus.publish(News("second")), 2);
+    fifo.run();
+
+    let mut log = log.lock().unwrap().clone();
+    log.sort();
+
+    assert_eq!(
+        log,
+        vec![
+            ("alice", "first"),
+            ("alice", "second"),
+            ("bob", "first"),
+            ("bob", "second"),
+        ]
+    );
+}
+
+#[test]
+fn publishing_without_subscribers_does_nothing() {
+    let bus = EventBus::default();
+
+    assert_eq!(bus.publish(News("unread")), 0);
+
+    // Subscribers only get the messages
+    // published after they subscribed.
+    let mut topic = bus.topic::<News>();
+    let waker = noop_waker();
+    let mut ctx = TaskContext::from_waker(&waker);
+
+    assert!(Pin::new(&mut topic).poll_next(&mut ctx).is_pending());
+}
+
+#[test]
+fn slow_subscribers_skip_overwritten_messages() {
+    let bus = EventBus::new(2);
+    let mut topic = bus.topic::<u8>();
+
+    for msg in 0..5u8 {
+        bus.publish(msg);
+    }
+
+    let waker = noop_waker();
+    let mut ctx = TaskContext::from_waker(&waker);
+
+    assert_eq!(
+        Pin::new(&mut topic).poll_next(&mut ctx),
+        Poll::Ready(Some(3))
+    );
+    assert_eq!(topic.lagged(), 3);
+    assert_eq!(
+        Pin::new(&mut topic).poll_next(&mut ctx),
+        Poll::Ready(Some(4))
+    );
+}
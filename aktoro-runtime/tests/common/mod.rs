@@ -0,0 +1,150 @@
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+
+use aktoro_context::Context as ActorContext;
+use aktoro_raw as raw;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_core::future::BoxFuture;
+use futures_executor::ThreadPool;
+use futures_util::task;
+use futures_util::task::ArcWake;
+
+#[derive(Debug)]
+/// The error returned by the actors of the
+/// tests when they are asked to fail.
+pub struct Failed;
+
+/// An actor handling `Ping` messages (without
+/// doing anything) and dying when it receives
+/// a `Stop` message, for the tests that don't
+/// need an actor with a specific behaviour.
+pub struct Echo;
+
+pub struct Ping;
+
+pub struct Stop;
+
+/// Returns a spawner running the actors on
+/// a pool of `threads` threads.
+pub fn spawner(threads: usize) -> raw::Spawner {
+    let pool = ThreadPool::builder().pool_size(threads).create().unwrap();
+
+    raw::Spawner::new(move |fut| pool.spawn_ok(fut))
+}
+
+/// Returns a runtime running its actors on a
+/// pool of one thread.
+pub fn runtime() -> Runtime {
+    let mut rt = Runtime::new();
+    rt.set_spawner(spawner(1));
+
+    rt
+}
+
+/// An executor polling its futures one at a
+/// time, in the order they were spawned or
+/// woken up (unlike a `ThreadPool`, which
+/// polls a future again right away if it
+/// woke itself up).
+#[derive(Clone, Default)]
+pub struct Fifo(Arc<Mutex<VecDeque<Arc<Task>>>>);
+
+/// A future spawned on a `Fifo`.
+struct Task {
+    fut: Mutex<Option<BoxFuture<'static, ()>>>,
+    fifo: Fifo,
+}
+
+impl Fifo {
+    /// Returns a spawner spawning the futures
+    /// on the executor.
+    pub fn spawner(&self) -> raw::Spawner {
+        let fifo = self.clone();
+
+        raw::Spawner::new(move |fut| {
+            let task = Arc::new(Task {
+                fut: Mutex::new(Some(fut)),
+                fifo: fifo.clone(),
+            });
+
+            fifo.0.lock().unwrap().push_back(task);
+        })
+    }
+
+    /// Returns a runtime running its actors
+    /// on the executor.
+    pub fn runtime(&self) -> Runtime {
+        let mut rt = Runtime::new();
+        rt.set_spawner(self.spawner());
+
+        rt
+    }
+
+    /// Polls the queued futures until none
+    /// is queued anymore.
+    pub fn run(&self) {
+        loop {
+            let task = if let Some(task) = self.0.lock().unwrap().pop_front() {
+                task
+            } else {
+                return;
+            };
+
+            let waker = task::waker(task.clone());
+            let mut ctx = Context::from_waker(&waker);
+
+            let mut fut = task.fut.lock().unwrap();
+            if let Some(inner) = fut.as_mut() {
+                if inner.as_mut().poll(&mut ctx).is_ready() {
+                    fut.take();
+                }
+            }
+        }
+    }
+}
+
+impl raw::Actor for Echo {
+    type Context = ActorContext<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl raw::Handler<Ping> for Echo {
+    type Output = ();
+
+    fn handle(&mut self, _: Ping, _: &mut Self::Context) -> Result<(), Failed> {
+        Ok(())
+    }
+}
+
+impl raw::Handler<Stop> for Echo {
+    type Output = ();
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<(), Failed> {
+        ctx.set_status(Status::Dead);
+        Ok(())
+    }
+}
+
+impl ArcWake for Task {
+    fn wake_by_ref(task: &Arc<Self>) {
+        task.fifo.0.lock().unwrap().push_back(task.clone());
+    }
+}
+
+impl fmt::Display for Failed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed")
+    }
+}
+
+impl error::Error for Failed {}
@@ -0,0 +1,156 @@
+mod common;
+
+use std::any;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aktoro_context::Context;
+use aktoro_context::ContextConfig;
+use aktoro_context::MailboxConfig;
+use aktoro_context::Overflow;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::DeadLetter;
+use aktoro_raw::DeadLetterReason;
+use aktoro_raw::DeadLetterSink;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Error;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Fifo;
+
+/// An actor saving the messages it handled.
+struct Saver(Arc<Mutex<Vec<usize>>>);
+
+struct Msg(usize);
+
+struct Stop;
+
+impl Actor for Saver {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl Handler<Msg> for Saver {
+    type Output = ();
+
+    fn handle(&mut self, msg: Msg, _: &mut Self::Context) -> Result<(), Error> {
+        self.0.lock().unwrap().push(msg.0);
+        Ok(())
+    }
+}
+
+impl Handler<Stop> for Saver {
+    type Output = ();
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<(), Error> {
+        ctx.set_status(Status::Dead);
+        Ok(())
+    }
+}
+
+/// Spawns an actor with a mailbox of one
+/// message using `overflow` and sends it
+/// three messages before it is polled,
+/// checking that the two dropped ones are
+/// reported as dead letters and returning
+/// the messages it handled.
+fn overflow(overflow: Overflow) -> Vec<usize> {
+    let fifo = Fifo::default();
+
+    let mut rt = fifo.runtime();
+
+    let mut dead_letters = rt.dead_letters().unwrap();
+
+    let handled = Arc::new(Mutex::new(vec![]));
+    let config =
+        ContextConfig::default().messages(MailboxConfig::new().bounded(1).overflow(overflow));
+    let mut spawned = rt.spawn_with(Saver(handled.clone()), config).unwrap();
+
+    for i in 0..3 {
+        drop(spawned.try_send_msg(Msg(i)).unwrap());
+    }
+
+    // The actor reports the dropped messages
+    // when it is polled.
+    fifo.run();
+
+    for _ in 0..2 {
+        let letter = block_on(dead_letters.next()).unwrap();
+
+        assert_eq!(letter.target(), spawned.actor_id());
+        assert_eq!(letter.reason(), DeadLetterReason::Full);
+        assert_eq!(letter.type_name(), any::type_name::<Msg>());
+        assert!(letter.msg().is_some());
+    }
+
+    let handled = handled.lock().unwrap().clone();
+    handled
+}
+
+#[test]
+fn reports_newest_dropped_messages() {
+    assert_eq!(overflow(Overflow::DropNewest), vec![0]);
+}
+
+#[test]
+fn reports_oldest_dropped_messages() {
+    assert_eq!(overflow(Overflow::DropOldest), vec![2]);
+}
+
+#[test]
+fn reports_messages_left_when_the_actor_dies() {
+    let fifo = Fifo::default();
+
+    let mut rt = fifo.runtime();
+
+    let mut dead_letters = rt.dead_letters().unwrap();
+
+    let handled = Arc::new(Mutex::new(vec![]));
+    let mut spawned = rt.spawn(Saver(handled.clone())).unwrap();
+
+    drop(spawned.try_send_msg(Msg(0)).unwrap());
+    drop(spawned.try_send_msg(Stop).unwrap());
+    drop(spawned.try_send_msg(Msg(1)).unwrap());
+
+    fifo.run();
+
+    let letter = block_on(dead_letters.next()).unwrap();
+    assert_eq!(letter.target(), spawned.actor_id());
+    assert_eq!(letter.reason(), DeadLetterReason::Dead);
+    assert_eq!(letter.type_name(), any::type_name::<Msg>());
+
+    // Messages sent once the actor is dead
+    // are refused and reported without them.
+    assert!(spawned.try_send_msg(Msg(2)).is_err());
+
+    let letter = block_on(dead_letters.next()).unwrap();
+    assert_eq!(letter.reason(), DeadLetterReason::Closed);
+    assert!(letter.msg().is_none());
+
+    assert_eq!(*handled.lock().unwrap(), vec![0]);
+}
+
+#[test]
+fn reports_to_a_custom_sink() {
+    let mut rt = common::runtime();
+
+    let reported = Arc::new(Mutex::new(vec![]));
+    let reported_ = reported.clone();
+    rt.set_dead_letters(DeadLetterSink::new(move |letter: DeadLetter| {
+        reported_.lock().unwrap().push(letter.reason());
+    }));
+
+    let config = ContextConfig::default().messages(MailboxConfig::new().limited_msgs(0));
+    let mut spawned = rt
+        .spawn_with(Saver(Arc::new(Mutex::new(vec![]))), config)
+        .unwrap();
+
+    assert!(spawned.try_send_msg(Msg(0)).is_err());
+    assert_eq!(*reported.lock().unwrap(), vec![DeadLetterReason::Limit]);
+}
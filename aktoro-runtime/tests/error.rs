@@ -0,0 +1,167 @@
+mod common;
+
+use std::any;
+use std::error::Error as StdError;
+use std::io;
+
+use aktoro_context::Context;
+use aktoro_raw::ActionHandler;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Error;
+use aktoro_runtime::ErrorKind;
+use aktoro_runtime::Phase;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Failed;
+
+/// An actor failing (or panicking) when
+/// asked to, and panicking when stopping.
+struct Faulty;
+
+struct Fail;
+
+struct Panic;
+
+struct Stop;
+
+impl Actor for Faulty {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+
+    fn stopping(&mut self, _: &mut Self::Context) {
+        panic!("asked to stop");
+    }
+}
+
+impl Handler<Fail> for Faulty {
+    type Output = ();
+
+    fn handle(&mut self, _: Fail, _: &mut Self::Context) -> Result<(), Failed> {
+        Err(Failed)
+    }
+}
+
+impl Handler<Panic> for Faulty {
+    type Output = ();
+
+    fn handle(&mut self, _: Panic, _: &mut Self::Context) -> Result<(), Failed> {
+        panic!("asked to panic");
+    }
+}
+
+impl Handler<Stop> for Faulty {
+    type Output = ();
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<(), Failed> {
+        ctx.set_status(Status::Stopping);
+        Ok(())
+    }
+}
+
+impl ActionHandler<Fail> for Faulty {
+    type Output = ();
+
+    fn handle(&mut self, _: Fail, _: &mut Self::Context) -> Result<(), Failed> {
+        Err(Failed)
+    }
+}
+
+/// Spawns a `Faulty` actor, makes it fail
+/// using `fail` and returns the error it
+/// failed with.
+fn fail<F>(fail: F) -> (u64, Error)
+where
+    F: FnOnce(&mut aktoro_raw::Spawned<Faulty>),
+{
+    let mut rt = common::runtime();
+
+    let mut spawned = rt.spawn(Faulty).unwrap();
+    fail(&mut spawned);
+
+    let mut wait = rt.wait();
+    let (id, err) = block_on(wait.next()).unwrap().unwrap_err();
+    assert_eq!(id, spawned.actor_id());
+
+    (id, err)
+}
+
+#[test]
+fn records_where_an_actor_failed() {
+    let (id, err) = fail(|spawned| drop(spawned.try_send_msg(Fail).unwrap()));
+
+    assert_eq!(err.actor_id(), Some(id));
+    assert_eq!(err.type_name(), Some(any::type_name::<Faulty>()));
+    assert_eq!(err.phase(), Some(Phase::Message));
+
+    // The actor's error is kept as the
+    // error's source.
+    assert!(err.source().unwrap().downcast_ref::<Failed>().is_some());
+
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "actor {} ({}) failed while handling a message: failed",
+            id,
+            any::type_name::<Faulty>()
+        )
+    );
+}
+
+#[test]
+fn records_the_phase_of_actions() {
+    let (_, err) = fail(|spawned| drop(spawned.try_send_action(Fail).unwrap()));
+
+    assert_eq!(err.phase(), Some(Phase::Action));
+}
+
+#[test]
+fn records_the_phase_of_panics() {
+    let (id, err) = fail(|spawned| drop(spawned.try_send_msg(Panic).unwrap()));
+
+    assert!(err.is_panicked());
+    assert_eq!(err.phase(), Some(Phase::Message));
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "actor {} ({}) failed while handling a message: panicked: asked to panic",
+            id,
+            any::type_name::<Faulty>()
+        )
+    );
+
+    let (_, err) = fail(|spawned| drop(spawned.try_send_msg(Stop).unwrap()));
+
+    assert!(err.is_panicked());
+    assert_eq!(err.phase(), Some(Phase::Stopping));
+}
+
+#[test]
+fn converts_io_errors() {
+    let err = Error::from(io::Error::new(io::ErrorKind::Other, "io"));
+
+    assert!(err.is_io());
+    assert!(!err.is_multiple());
+    assert_eq!(err.actor_id(), None);
+    assert_eq!(err.to_string(), "io");
+}
+
+#[test]
+fn iterates_over_multiple_errors() {
+    let io = |msg| Error::from(io::Error::new(io::ErrorKind::Other, msg));
+
+    let nested = Error::from(ErrorKind::Multiple(vec![io("second"), io("third")]));
+    let err = Error::from(ErrorKind::Multiple(vec![io("first"), nested, io("fourth")]));
+
+    assert!(err.is_multiple());
+    assert_eq!(err.to_string(), "3 errors occured");
+
+    let msgs = err.iter().map(|err| err.to_string()).collect::<Vec<_>>();
+    assert_eq!(msgs, vec!["first", "second", "third", "fourth"]);
+}
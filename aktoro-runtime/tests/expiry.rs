@@ -0,0 +1,48 @@
+mod common;
+
+use std::any;
+use std::thread;
+use std::time::Duration;
+
+use aktoro_raw::DeadLetterReason;
+use aktoro_raw::Runtime as RawRuntime;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Echo;
+use self::common::Fifo;
+use self::common::Ping;
+
+#[test]
+fn reports_expired_messages() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let mut dead_letters = rt.dead_letters().unwrap();
+    let mut spawned = rt.spawn(Echo).unwrap();
+    let mut clone = spawned.clone();
+    let id = spawned.actor_id();
+
+    let expired = spawned
+        .try_send_msg_ttl(Ping, Duration::from_millis(1))
+        .unwrap();
+    let handled = clone
+        .try_send_msg_ttl(Ping, Duration::from_secs(60))
+        .unwrap();
+
+    // We let the first message expire before
+    // polling the actor.
+    thread::sleep(Duration::from_millis(10));
+    fifo.run();
+
+    assert!(block_on(handled).is_ok());
+
+    // The sender of the expired message is
+    // notified that it won't be handled.
+    assert!(block_on(expired).unwrap_err().is_dead());
+
+    let letter = block_on(dead_letters.next()).unwrap();
+    assert_eq!(letter.target(), id);
+    assert_eq!(letter.reason(), DeadLetterReason::Expired);
+    assert_eq!(letter.type_name(), any::type_name::<Ping>());
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use std::cell::Cell;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task;
+
+use aktoro_context::Context;
+use aktoro_raw as raw;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Error;
+use aktoro_runtime::LocalRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_core::Stream;
+use futures_executor::block_on;
+use futures_util::task::noop_waker_ref;
+use futures_util::StreamExt;
+
+/// An actor that isn't `Send`, counting the
+/// messages it handled.
+struct Counter(Rc<Cell<usize>>);
+
+/// An actor that isn't `Send`, pinging a
+/// `Ponger` until it answered three times.
+struct Pinger {
+    ponger: raw::Spawned<Ponger>,
+    pongs: Rc<Cell<usize>>,
+}
+
+/// An actor answering the pings of a
+/// `Pinger`.
+struct Ponger(Option<raw::Spawned<Pinger>>);
+
+struct Incr;
+
+struct Stop;
+
+struct Connect(raw::Spawned<Pinger>);
+
+struct Ping;
+
+struct Pong;
+
+impl Actor for Counter {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl Handler<Incr> for Counter {
+    type Output = ();
+
+    fn handle(&mut self, _: Incr, _: &mut Self::Context) -> Result<(), Error> {
+        self.0.set(self.0.get() + 1);
+        Ok(())
+    }
+}
+
+impl Handler<Stop> for Counter {
+    type Output = ();
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<(), Error> {
+        ctx.set_status(Status::Dead);
+        Ok(())
+    }
+}
+
+impl Actor for Pinger {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+
+    fn started(&mut self, _: &mut Self::Context) {
+        drop(self.ponger.try_send_msg(Ping).unwrap());
+    }
+}
+
+impl Handler<Pong> for Pinger {
+    type Output = ();
+
+    fn handle(&mut self, _: Pong, ctx: &mut Self::Context) -> Result<(), Error> {
+        self.pongs.set(self.pongs.get() + 1);
+
+        if self.pongs.get() == 3 {
+            ctx.set_status(Status::Dead);
+        } else {
+            drop(self.ponger.try_send_msg(Ping).unwrap());
+        }
+
+        Ok(())
+    }
+}
+
+impl Actor for Ponger {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl Handler<Connect> for Ponger {
+    type Output = ();
+
+    fn handle(&mut self, msg: Connect, _: &mut Self::Context) -> Result<(), Error> {
+        self.0 = Some(msg.0);
+        Ok(())
+    }
+}
+
+impl Handler<Ping> for Ponger {
+    type Output = ();
+
+    fn handle(&mut self, _: Ping, _: &mut Self::Context) -> Result<(), Error> {
+        if let Some(pinger) = &mut self.0 {
+            drop(pinger.try_send_msg(Pong).unwrap());
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn drives_non_send_actors() {
+    let count = Rc::new(Cell::new(0));
+
+    let mut rt = LocalRuntime::new();
+    let mut spawned = rt.spawn(Counter(count.clone())).unwrap();
+
+    drop(spawned.try_send_msg(Incr).unwrap());
+    drop(spawned.try_send_msg(Incr).unwrap());
+    drop(spawned.try_send_msg(Stop).unwrap());
+
+    let mut wait = rt.wait();
+
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), spawned.actor_id());
+    assert_eq!(count.get(), 2);
+    assert!(block_on(wait.next()).is_none());
+}
+
+#[test]
+fn polls_a_limited_number_of_actors_at_a_time() {
+    let count = Rc::new(Cell::new(0));
+
+    let mut rt = LocalRuntime::new();
+    rt.set_tasks_per_poll(1);
+
+    let mut first = rt.spawn(Counter(count.clone())).unwrap();
+    let mut second = rt.spawn(Counter(count.clone())).unwrap();
+
+    drop(first.try_send_msg(Incr).unwrap());
+    drop(second.try_send_msg(Incr).unwrap());
+
+    let mut wait = rt.wait();
+    let mut ctx = task::Context::from_waker(noop_waker_ref());
+
+    // Only one actor is polled each time the
+    // runtime is.
+    assert!(Pin::new(&mut wait).poll_next(&mut ctx).is_pending());
+    assert_eq!(count.get(), 1);
+
+    assert!(Pin::new(&mut wait).poll_next(&mut ctx).is_pending());
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn exchanges_messages_with_a_runtime() {
+    let mut rt = common::runtime();
+    let ponger = rt.spawn(Ponger(None)).unwrap();
+
+    let pongs = Rc::new(Cell::new(0));
+
+    let mut local = LocalRuntime::new();
+    let pinger = local
+        .spawn(Pinger {
+            ponger: ponger.clone(),
+            pongs: pongs.clone(),
+        })
+        .unwrap();
+
+    // The ponger receives the pinger before
+    // its first ping, which is only sent once
+    // the local runtime is polled.
+    let mut connect = ponger.clone();
+    drop(connect.try_send_msg(Connect(pinger.clone())).unwrap());
+
+    let mut wait = local.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), pinger.actor_id());
+    assert_eq!(pongs.get(), 3);
+
+    rt.stop();
+}
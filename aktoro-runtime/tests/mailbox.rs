@@ -0,0 +1,79 @@
+mod common;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aktoro_context::Context;
+use aktoro_context::ContextConfig;
+use aktoro_context::MailboxConfig;
+use aktoro_raw::Actor;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Error;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+
+use self::common::Fifo;
+
+/// An actor counting the messages it
+/// handled.
+struct Counter(Arc<Mutex<usize>>);
+
+struct Incr;
+
+impl Actor for Counter {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl Handler<Incr> for Counter {
+    type Output = usize;
+
+    fn handle(&mut self, _: Incr, _: &mut Self::Context) -> Result<usize, Error> {
+        let mut count = self.0.lock().unwrap();
+        *count += 1;
+
+        Ok(*count)
+    }
+}
+
+#[test]
+fn bounded_mailboxes_refuse_messages_when_full() {
+    // We don't run the actor, so that its
+    // mailbox fills up.
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let config = ContextConfig::default().messages(MailboxConfig::new().bounded(2));
+    let count = Arc::new(Mutex::new(0));
+    let mut spawned = rt.spawn_with(Counter(count.clone()), config).unwrap();
+
+    drop(spawned.try_send_msg(Incr).unwrap());
+    drop(spawned.try_send_msg(Incr).unwrap());
+    assert!(spawned.try_send_msg(Incr).err().unwrap().is_full());
+
+    let stats = spawned.mailbox_stats();
+    assert_eq!(stats.len, 2);
+    assert_eq!(stats.capacity, Some(2));
+
+    fifo.run();
+
+    assert_eq!(*count.lock().unwrap(), 2);
+    assert_eq!(spawned.mailbox_stats().len, 0);
+}
+
+#[test]
+fn limited_mailboxes_refuse_messages_past_the_limit() {
+    let mut rt = common::runtime();
+
+    let config = ContextConfig::default().messages(MailboxConfig::new().limited_msgs(2));
+    let mut spawned = rt
+        .spawn_with(Counter(Arc::new(Mutex::new(0))), config)
+        .unwrap();
+
+    assert_eq!(block_on(spawned.try_send_msg(Incr).unwrap()).unwrap(), 1);
+    assert_eq!(block_on(spawned.try_send_msg(Incr).unwrap()).unwrap(), 2);
+    assert!(spawned.try_send_msg(Incr).err().unwrap().is_limit());
+}
@@ -0,0 +1,193 @@
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aktoro_context::Context;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Down;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_raw::Spawned;
+use aktoro_raw::SupervisionError;
+use aktoro_runtime::Error;
+use aktoro_runtime::ErrorKind;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Failed;
+
+/// The `Down` messages received by a
+/// `Watcher` (the identifier of the actor
+/// and whether it failed).
+type Downs = Arc<Mutex<Vec<(u64, bool)>>>;
+
+/// An actor watching other actors, saving the
+/// `Down` messages it receives.
+struct Watcher(Downs);
+
+/// An actor that can't handle `Down` messages.
+struct Target;
+
+struct Monitor(u64);
+
+struct Link(u64);
+
+struct Stop;
+
+struct Fail;
+
+impl Actor for Watcher {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Actor for Target {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Handler<Monitor> for Watcher {
+    type Output = bool;
+
+    fn handle(&mut self, msg: Monitor, ctx: &mut Self::Context) -> Result<bool, Failed> {
+        Ok(ctx.monitor(msg.0))
+    }
+}
+
+impl Handler<Link> for Watcher {
+    type Output = bool;
+
+    fn handle(&mut self, msg: Link, ctx: &mut Self::Context) -> Result<bool, Failed> {
+        Ok(ctx.link(msg.0))
+    }
+}
+
+impl Handler<Down<Error>> for Watcher {
+    type Output = ();
+
+    fn handle(&mut self, down: Down<Error>, _: &mut Self::Context) -> Result<(), Failed> {
+        self.0.lock().unwrap().push((down.id(), down.is_failure()));
+        Ok(())
+    }
+}
+
+impl Handler<Stop> for Watcher {
+    type Output = ();
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<(), Failed> {
+        ctx.set_status(Status::Dead);
+        Ok(())
+    }
+}
+
+impl Handler<Fail> for Watcher {
+    type Output = ();
+
+    fn handle(&mut self, _: Fail, _: &mut Self::Context) -> Result<(), Failed> {
+        Err(Failed)
+    }
+}
+
+impl Handler<Fail> for Target {
+    type Output = ();
+
+    fn handle(&mut self, _: Fail, _: &mut Self::Context) -> Result<(), Failed> {
+        Err(Failed)
+    }
+}
+
+/// Spawns a watcher and a target on a new
+/// runtime.
+fn spawn() -> (Runtime, Spawned<Watcher>, Spawned<Target>, Downs) {
+    let mut rt = common::runtime();
+
+    let downs = Arc::new(Mutex::new(vec![]));
+    let watcher = rt.spawn(Watcher(downs.clone())).unwrap();
+    let target = rt.spawn(Target).unwrap();
+
+    (rt, watcher, target, downs)
+}
+
+/// Waits for all the runtime's actors to
+/// die, returning their results.
+fn wait(rt: Runtime) -> HashMap<u64, Result<(), Error>> {
+    let mut wait = rt.wait();
+    let mut res = HashMap::new();
+
+    while let Some(item) = block_on(wait.next()) {
+        match item {
+            Ok(id) => res.insert(id, Ok(())),
+            Err((id, err)) => res.insert(id, Err(err)),
+        };
+    }
+
+    res
+}
+
+/// Returns the supervision error that `err`
+/// wraps.
+fn supervision(err: &Error) -> &SupervisionError {
+    match err.kind() {
+        ErrorKind::Std(err) => err.downcast_ref().unwrap(),
+        _ => panic!("expected a supervision error"),
+    }
+}
+
+#[test]
+fn monitors_any_actor() {
+    let (rt, mut watcher, mut target, downs) = spawn();
+
+    assert!(block_on(watcher.try_send_msg(Monitor(target.actor_id())).unwrap()).unwrap());
+    assert!(!block_on(watcher.try_send_msg(Monitor(!0)).unwrap()).unwrap());
+
+    drop(target.try_send_msg(Fail).unwrap());
+
+    let mut wait = rt.wait();
+    let (id, _) = block_on(wait.next()).unwrap().unwrap_err();
+    assert_eq!(id, target.actor_id());
+
+    drop(watcher.try_send_msg(Stop).unwrap());
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), watcher.actor_id());
+
+    assert_eq!(*downs.lock().unwrap(), vec![(target.actor_id(), true)]);
+}
+
+#[test]
+fn linked_failure_kills_watcher() {
+    let (rt, mut watcher, mut target, downs) = spawn();
+
+    assert!(block_on(watcher.try_send_msg(Link(target.actor_id())).unwrap()).unwrap());
+    drop(target.try_send_msg(Fail).unwrap());
+
+    let res = wait(rt);
+
+    let err = res[&watcher.actor_id()].as_ref().unwrap_err();
+    assert!(supervision(err).is_linked());
+    assert_eq!(supervision(err).actor_id(), target.actor_id());
+    assert!(res[&target.actor_id()].is_err());
+
+    // The watcher handled the `Down` message
+    // before failing.
+    assert_eq!(*downs.lock().unwrap(), vec![(target.actor_id(), true)]);
+}
+
+#[test]
+fn linked_failure_kills_target() {
+    let (rt, mut watcher, target, _) = spawn();
+
+    assert!(block_on(watcher.try_send_msg(Link(target.actor_id())).unwrap()).unwrap());
+    drop(watcher.try_send_msg(Fail).unwrap());
+
+    let res = wait(rt);
+
+    let err = res[&target.actor_id()].as_ref().unwrap_err();
+    assert!(supervision(err).is_linked());
+    assert_eq!(supervision(err).actor_id(), watcher.actor_id());
+}
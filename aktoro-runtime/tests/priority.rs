@@ -0,0 +1,78 @@
+mod common;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aktoro_context::Context;
+use aktoro_context::ContextConfig;
+use aktoro_context::MailboxConfig;
+use aktoro_raw::Actor;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+
+use self::common::Failed;
+use self::common::Fifo;
+
+/// An actor logging the messages it
+/// handles.
+struct Logger(Arc<Mutex<Vec<&'static str>>>);
+
+/// A message with a normal priority.
+struct Normal(&'static str);
+
+/// A message that is always urgent.
+struct Urgent(&'static str);
+
+impl Actor for Logger {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Handler<Normal> for Logger {
+    type Output = ();
+
+    fn handle(&mut self, Normal(msg): Normal, _: &mut Self::Context) -> Result<(), Failed> {
+        self.0.lock().unwrap().push(msg);
+        Ok(())
+    }
+}
+
+impl Handler<Urgent> for Logger {
+    type Output = ();
+
+    fn handle(&mut self, Urgent(msg): Urgent, _: &mut Self::Context) -> Result<(), Failed> {
+        self.0.lock().unwrap().push(msg);
+        Ok(())
+    }
+
+    fn priority(_: &Urgent) -> usize {
+        2
+    }
+}
+
+#[test]
+fn handles_higher_priorities_first() {
+    // We only run the actor once all the
+    // messages have been sent.
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let log = Arc::new(Mutex::new(vec![]));
+    let config = ContextConfig::default().messages(MailboxConfig::new().priorities(3));
+    let mut spawned = rt.spawn_with(Logger(log.clone()), config).unwrap();
+
+    drop(spawned.try_send_msg(Normal("first")).unwrap());
+    drop(spawned.try_send_msg(Urgent("urgent")).unwrap());
+    drop(spawned.try_send_msg_prio(Normal("important"), 1).unwrap());
+    drop(spawned.try_send_msg(Normal("last")).unwrap());
+
+    fifo.run();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["urgent", "important", "first", "last"]
+    );
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use aktoro_context::Context;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Recipient;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::future;
+use futures_util::StreamExt;
+
+use self::common::Failed;
+
+/// An actor greeting in English.
+struct English;
+
+/// An actor greeting in French.
+struct French;
+
+struct Greet(&'static str);
+
+struct Stop;
+
+impl Actor for English {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Actor for French {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Handler<Greet> for English {
+    type Output = String;
+
+    fn handle(&mut self, Greet(name): Greet, _: &mut Self::Context) -> Result<String, Failed> {
+        Ok(format!("Hello, {}!", name))
+    }
+}
+
+impl Handler<Greet> for French {
+    type Output = String;
+
+    fn handle(&mut self, Greet(name): Greet, _: &mut Self::Context) -> Result<String, Failed> {
+        Ok(format!("Bonjour, {} !", name))
+    }
+}
+
+impl Handler<Stop> for French {
+    type Output = String;
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<String, Failed> {
+        ctx.set_status(Status::Dead);
+        Ok(String::new())
+    }
+}
+
+#[test]
+fn sends_to_actors_of_different_types() {
+    let mut rt = common::runtime();
+
+    let english = rt.spawn(English).unwrap();
+    let french = rt.spawn(French).unwrap();
+
+    let mut recipients: Vec<Recipient<Greet, String>> =
+        vec![english.recipient(), french.recipient()];
+
+    assert_eq!(recipients[0].actor_id(), english.actor_id());
+    assert_eq!(recipients[1].actor_id(), french.actor_id());
+
+    let greetings = recipients
+        .iter_mut()
+        .map(|recipient| block_on(recipient.try_send(Greet("Ada")).unwrap()).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(greetings, vec!["Hello, Ada!", "Bonjour, Ada !"]);
+
+    let mut clone = recipients[1].clone();
+    let ask = clone.ask(Greet("Alan"), future::pending()).unwrap();
+    assert_eq!(block_on(ask).unwrap(), "Bonjour, Alan !");
+}
+
+#[test]
+fn fails_once_the_actor_is_dead() {
+    let mut rt = common::runtime();
+
+    let mut french = rt.spawn(French).unwrap();
+    let mut recipient = french.recipient::<Greet>();
+
+    drop(french.try_send_msg(Stop).unwrap());
+
+    let mut wait = rt.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), french.actor_id());
+
+    assert!(recipient.try_send(Greet("Ada")).is_err());
+}
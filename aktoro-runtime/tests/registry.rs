@@ -0,0 +1,191 @@
+mod common;
+
+use aktoro_context::Context;
+use aktoro_context::ContextConfig;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_raw::Spawned;
+use aktoro_raw::Wait as RawWait;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Failed;
+use self::common::Fifo;
+
+/// An actor answering with its name.
+struct Named(&'static str);
+
+/// An actor of another type.
+struct Other;
+
+/// An actor looking up and registering
+/// actors from its handlers.
+struct Finder;
+
+struct Name;
+
+struct Find;
+
+struct Register(Spawned<Named>);
+
+struct Stop;
+
+impl Actor for Named {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Actor for Other {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Actor for Finder {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Handler<Name> for Named {
+    type Output = &'static str;
+
+    fn handle(&mut self, _: Name, _: &mut Self::Context) -> Result<&'static str, Failed> {
+        Ok(self.0)
+    }
+}
+
+impl Handler<Stop> for Named {
+    type Output = &'static str;
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<&'static str, Failed> {
+        ctx.set_status(Status::Dead);
+        Ok(self.0)
+    }
+}
+
+impl Handler<Find> for Finder {
+    type Output = Option<u64>;
+
+    fn handle(&mut self, _: Find, ctx: &mut Self::Context) -> Result<Option<u64>, Failed> {
+        Ok(ctx
+            .lookup::<Named>("actor")
+            .map(|spawned| spawned.actor_id()))
+    }
+}
+
+impl Handler<Register> for Finder {
+    type Output = ();
+
+    fn handle(&mut self, msg: Register, ctx: &mut Self::Context) -> Result<(), Failed> {
+        ctx.register("registered", &msg.0).map_err(|_| Failed)
+    }
+}
+
+#[test]
+fn looks_up_registered_actors() {
+    let mut rt = common::runtime();
+
+    let first = rt.spawn(Named("first")).unwrap();
+    let second = rt.spawn(Named("second")).unwrap();
+
+    rt.register("actor", &first).unwrap();
+
+    let err = rt.register("actor", &second).unwrap_err();
+    assert!(err.is_taken());
+    assert_eq!(err.name(), "actor");
+
+    let mut found = rt.lookup::<Named>("actor").unwrap();
+    assert_eq!(found.actor_id(), first.actor_id());
+    assert_eq!(block_on(found.try_send_msg(Name).unwrap()), Ok("first"));
+
+    // The lookup fails if the actor isn't of
+    // the requested type.
+    assert!(rt.lookup::<Other>("actor").is_none());
+    assert!(rt.lookup::<Named>("unknown").is_none());
+
+    assert_eq!(rt.unregister("actor"), Some(first.actor_id()));
+    assert!(rt.lookup::<Named>("actor").is_none());
+
+    rt.register("actor", &second).unwrap();
+    assert_eq!(
+        rt.lookup::<Named>("actor").unwrap().actor_id(),
+        second.actor_id()
+    );
+}
+
+#[test]
+fn refuses_unknown_actors() {
+    let mut rt = common::runtime();
+
+    let mut other_rt = common::runtime();
+
+    let spawned = other_rt.spawn(Named("other")).unwrap();
+
+    let err = rt.register("actor", &spawned).unwrap_err();
+    assert!(err.is_unknown());
+}
+
+#[test]
+fn unregisters_dead_actors() {
+    let mut rt = common::runtime();
+
+    let mut spawned = rt.spawn(Named("dying")).unwrap();
+    rt.register("actor", &spawned).unwrap();
+
+    drop(spawned.try_send_msg(Stop).unwrap());
+
+    let mut wait = rt.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), spawned.actor_id());
+
+    assert!(wait.runtime().lookup::<Named>("actor").is_none());
+}
+
+#[test]
+fn shares_the_registry_with_the_actors() {
+    let mut rt = common::runtime();
+
+    let named = rt.spawn(Named("named")).unwrap();
+    let mut finder = rt.spawn(Finder).unwrap();
+
+    assert_eq!(block_on(finder.try_send_msg(Find).unwrap()), Ok(None));
+
+    rt.register("actor", &named).unwrap();
+    assert_eq!(
+        block_on(finder.try_send_msg(Find).unwrap()),
+        Ok(Some(named.actor_id()))
+    );
+
+    // Actors registered by an actor can be
+    // looked up through the runtime.
+    block_on(finder.try_send_msg(Register(named.clone())).unwrap()).unwrap();
+    assert_eq!(
+        rt.lookup::<Named>("registered").unwrap().actor_id(),
+        named.actor_id()
+    );
+}
+
+#[test]
+fn doesnt_keep_registered_actors_alive() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let config = ContextConfig::default().stop_when_unreferenced();
+    let spawned = rt.spawn_with(Named("unreferenced"), config).unwrap();
+    let id = spawned.actor_id();
+
+    rt.register("actor", &spawned).unwrap();
+    fifo.run();
+
+    drop(spawned);
+    fifo.run();
+
+    let mut wait = rt.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), id);
+    assert!(wait.runtime().lookup::<Named>("actor").is_none());
+}
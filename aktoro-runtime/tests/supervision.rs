@@ -0,0 +1,147 @@
+mod common;
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use aktoro_context::Context;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::EventHandler;
+use aktoro_raw::Handler;
+use aktoro_raw::Intensity;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_raw::SupervisionError;
+use aktoro_raw::Wait as RawWait;
+use aktoro_runtime::Error;
+use aktoro_runtime::ErrorKind;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Failed;
+
+/// An actor spawning a restartable child
+/// and asking it to fail, without allowing
+/// it to be restarted.
+struct Parent;
+
+struct Child;
+
+/// An actor restarting a child that fails
+/// every time it is started, allowing it
+/// to be restarted twice.
+struct Restarter(Arc<AtomicUsize>);
+
+/// An actor counting how many times it was
+/// started and failing right away.
+struct Flaky;
+
+struct Fail;
+
+impl Actor for Parent {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.set_intensity(Intensity::new(0, Duration::from_secs(60)));
+
+        let mut child = ctx.spawn_restartable(|| Child).unwrap();
+        drop(child.try_send_msg(Fail).unwrap());
+    }
+}
+
+impl Actor for Child {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+}
+
+impl Actor for Restarter {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.set_intensity(Intensity::new(2, Duration::from_secs(60)));
+
+        let starts = self.0.clone();
+        ctx.spawn_restartable(move || {
+            starts.fetch_add(1, Ordering::SeqCst);
+            Flaky
+        })
+        .unwrap();
+    }
+}
+
+impl Actor for Flaky {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.emit(Fail);
+    }
+}
+
+impl EventHandler<Fail> for Flaky {
+    fn handle(&mut self, _: Fail, _: &mut Self::Context) -> Result<(), Failed> {
+        Err(Failed)
+    }
+}
+
+impl Handler<Fail> for Child {
+    type Output = ();
+
+    fn handle(&mut self, _: Fail, _: &mut Self::Context) -> Result<(), Failed> {
+        Err(Failed)
+    }
+}
+
+#[test]
+fn escalated_failure_makes_parent_fail() {
+    let mut rt = common::runtime();
+
+    let parent = rt.spawn(Parent).unwrap();
+
+    let mut wait = rt.wait();
+    let (id, err) = block_on(wait.next()).unwrap().unwrap_err();
+
+    assert_eq!(id, parent.actor_id());
+    assert_eq!(err.actor_id(), Some(id));
+
+    match err.kind() {
+        ErrorKind::Std(err) => assert!(err.downcast_ref::<SupervisionError>().is_some()),
+        _ => panic!("expected a supervision error"),
+    }
+
+    assert!(wait.runtime().actors().is_empty());
+}
+
+#[test]
+fn restarts_failed_children_within_intensity() {
+    let mut rt = common::runtime();
+
+    let starts = Arc::new(AtomicUsize::new(0));
+    let parent = rt.spawn(Restarter(starts.clone())).unwrap();
+
+    let mut wait = rt.wait();
+    let (id, err) = block_on(wait.next()).unwrap().unwrap_err();
+    assert_eq!(id, parent.actor_id());
+
+    match err.kind() {
+        ErrorKind::Std(err) => assert!(err
+            .downcast_ref::<SupervisionError>()
+            .unwrap()
+            .is_escalated()),
+        _ => panic!("expected a supervision error"),
+    }
+
+    // The child was started once, then
+    // restarted twice before the failure
+    // was escalated.
+    assert_eq!(starts.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,229 @@
+mod common;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task;
+use std::task::Poll;
+use std::task::Waker;
+use std::time::Duration;
+use std::time::Instant;
+
+use aktoro_context::Context;
+use aktoro_raw as raw;
+use aktoro_raw::Actor;
+use aktoro_raw::Context as RawContext;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use aktoro_runtime::Timer;
+use futures_core::Stream;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Failed;
+use self::common::Fifo;
+
+#[derive(Clone)]
+/// A virtual clock whose time only passes
+/// when it is advanced.
+struct Clock(Arc<Mutex<State>>);
+
+struct State {
+    /// The clock's current time.
+    now: Instant,
+    /// The wakers of the futures and streams
+    /// waiting for the time to pass.
+    wakers: Vec<Waker>,
+}
+
+/// A future returned by `Clock::delay`.
+struct Delay {
+    clock: Clock,
+    at: Instant,
+}
+
+/// A stream returned by `Clock::interval`.
+struct Interval {
+    clock: Clock,
+    next: Instant,
+    dur: Duration,
+}
+
+/// An actor scheduling messages to itself
+/// when it starts, and logging them.
+struct Scheduler {
+    log: Arc<Mutex<Vec<&'static str>>>,
+    at: Instant,
+}
+
+struct Later;
+
+struct At;
+
+struct Beat;
+
+impl Clock {
+    fn new() -> Self {
+        Clock(Arc::new(Mutex::new(State {
+            now: Instant::now(),
+            wakers: vec![],
+        })))
+    }
+
+    fn now(&self) -> Instant {
+        self.0.lock().unwrap().now
+    }
+
+    /// Lets `dur` pass, waking up the
+    /// futures and streams waiting for it.
+    fn advance(&self, dur: Duration) {
+        let wakers = {
+            let mut state = self.0.lock().unwrap();
+            state.now += dur;
+            state.wakers.drain(..).collect::<Vec<_>>()
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Returns whether `at` is reached,
+    /// registering `waker` if it isn't.
+    fn reached(&self, at: Instant, waker: &Waker) -> bool {
+        let mut state = self.0.lock().unwrap();
+        if state.now >= at {
+            true
+        } else {
+            state.wakers.push(waker.clone());
+            false
+        }
+    }
+}
+
+impl raw::Timer for Clock {
+    type Delay = Delay;
+    type Interval = Interval;
+
+    fn delay(&self, dur: Duration) -> Delay {
+        self.delay_until(self.now() + dur)
+    }
+
+    fn delay_until(&self, at: Instant) -> Delay {
+        Delay {
+            clock: self.clone(),
+            at,
+        }
+    }
+
+    fn interval(&self, dur: Duration) -> Interval {
+        Interval {
+            clock: self.clone(),
+            next: self.now() + dur,
+            dur,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<()> {
+        if self.clock.reached(self.at, ctx.waker()) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Stream for Interval {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut task::Context) -> Poll<Option<()>> {
+        let interval = self.get_mut();
+
+        if interval.clock.reached(interval.next, ctx.waker()) {
+            interval.next += interval.dur;
+            Poll::Ready(Some(()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Actor for Scheduler {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Failed;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.send_later(Later, Duration::from_millis(15));
+        ctx.run_at(At, self.at);
+        ctx.run_interval(Duration::from_millis(10), || Beat);
+    }
+}
+
+impl Handler<Later> for Scheduler {
+    type Output = ();
+
+    fn handle(&mut self, _: Later, _: &mut Self::Context) -> Result<(), Failed> {
+        self.log.lock().unwrap().push("later");
+        Ok(())
+    }
+}
+
+impl Handler<At> for Scheduler {
+    type Output = ();
+
+    fn handle(&mut self, _: At, ctx: &mut Self::Context) -> Result<(), Failed> {
+        self.log.lock().unwrap().push("at");
+        ctx.set_status(Status::Dead);
+        Ok(())
+    }
+}
+
+impl Handler<Beat> for Scheduler {
+    type Output = ();
+
+    fn handle(&mut self, _: Beat, _: &mut Self::Context) -> Result<(), Failed> {
+        self.log.lock().unwrap().push("beat");
+        Ok(())
+    }
+}
+
+#[test]
+fn delivers_scheduled_messages() {
+    let fifo = Fifo::default();
+    let clock = Clock::new();
+
+    let mut rt = fifo.runtime();
+    rt.set_timer(Timer::new(clock.clone()));
+
+    let log = Arc::new(Mutex::new(vec![]));
+    let spawned = rt
+        .spawn(Scheduler {
+            log: log.clone(),
+            at: clock.now() + Duration::from_millis(35),
+        })
+        .unwrap();
+
+    // We advance the clock in steps during
+    // which at most one message is due.
+    fifo.run();
+    for _ in 0..7 {
+        clock.advance(Duration::from_millis(5));
+        fifo.run();
+    }
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["beat", "later", "beat", "beat", "at"]
+    );
+
+    let mut wait = rt.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), spawned.actor_id());
+}
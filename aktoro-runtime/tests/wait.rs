@@ -0,0 +1,46 @@
+mod common;
+
+use aktoro_context::Context;
+use aktoro_raw::Actor;
+use aktoro_raw::Handler;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_raw::Wait as RawWait;
+use aktoro_runtime::Error;
+use aktoro_runtime::Runtime;
+use aktoro_runtime::Status;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+struct Panicking;
+
+struct Panic;
+
+impl Actor for Panicking {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl Handler<Panic> for Panicking {
+    type Output = ();
+
+    fn handle(&mut self, _: Panic, _: &mut Self::Context) -> Result<(), Error> {
+        panic!("asked to panic");
+    }
+}
+
+#[test]
+fn panicking_actor_is_reported() {
+    let mut rt = common::runtime();
+
+    let mut spawned = rt.spawn(Panicking).unwrap();
+    drop(spawned.try_send_msg(Panic).unwrap());
+
+    let mut wait = rt.wait();
+    let (id, err) = block_on(wait.next()).unwrap().unwrap_err();
+
+    assert_eq!(id, spawned.actor_id());
+    assert!(err.is_panicked());
+    assert_eq!(err.actor_id(), Some(id));
+    assert!(wait.runtime().actors().is_empty());
+}
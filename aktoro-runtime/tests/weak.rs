@@ -0,0 +1,59 @@
+mod common;
+
+use aktoro_context::ContextConfig;
+use aktoro_raw::Runtime as RawRuntime;
+use aktoro_raw::Wait as RawWait;
+use futures_executor::block_on;
+use futures_util::StreamExt;
+
+use self::common::Echo;
+use self::common::Fifo;
+use self::common::Ping;
+use self::common::Stop;
+
+#[test]
+fn upgrades_while_the_actor_lives() {
+    let mut rt = common::runtime();
+
+    let mut spawned = rt.spawn(Echo).unwrap();
+    let weak = spawned.downgrade();
+
+    let mut upgraded = weak.upgrade().unwrap();
+    assert_eq!(upgraded.actor_id(), spawned.actor_id());
+    block_on(upgraded.try_send_msg(Ping).unwrap()).unwrap();
+    drop(upgraded);
+
+    drop(spawned.try_send_msg(Stop).unwrap());
+
+    let mut wait = rt.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), spawned.actor_id());
+
+    drop(spawned);
+    assert!(weak.upgrade().is_none());
+    // We try again to make sure that a
+    // failed upgrade doesn't change
+    // anything.
+    assert!(weak.upgrade().is_none());
+
+    assert!(wait.runtime().actors().is_empty());
+}
+
+#[test]
+fn weak_handles_dont_keep_the_actor_alive() {
+    let fifo = Fifo::default();
+    let mut rt = fifo.runtime();
+
+    let config = ContextConfig::default().stop_when_unreferenced();
+    let spawned = rt.spawn_with(Echo, config).unwrap();
+    let weak = spawned.downgrade();
+
+    fifo.run();
+    assert!(weak.upgrade().is_some());
+
+    drop(spawned);
+    fifo.run();
+
+    let mut wait = rt.wait();
+    assert_eq!(block_on(wait.next()).unwrap().unwrap(), weak.actor_id());
+    assert!(weak.upgrade().is_none());
+}
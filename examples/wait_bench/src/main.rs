@@ -0,0 +1,76 @@
+#![feature(async_await)]
+
+use std::time::Instant;
+
+use aktoro::prelude::*;
+use futures_util::StreamExt;
+
+/// The number of actors that are stopped
+/// (and waited for) for each run.
+const STOPPED: usize = 1_000;
+
+/// The number of idle actors that keep
+/// running during each run.
+const IDLE: [usize; 4] = [1_000, 10_000, 50_000, 100_000];
+
+struct Idle;
+
+struct Stop;
+
+impl Actor for Idle {
+    type Context = Context<Self, Runtime>;
+    type Status = Status;
+    type Error = Error;
+}
+
+impl ActionHandler<Stop> for Idle {
+    type Output = ();
+
+    fn handle(&mut self, _: Stop, ctx: &mut Self::Context) -> Result<(), Error> {
+        ctx.set_status(Status::Dead);
+        Ok(())
+    }
+}
+
+#[runtime::main]
+async fn main() {
+    for &idle in IDLE.iter() {
+        let mut rt = Runtime::new();
+
+        let mut actors = Vec::with_capacity(idle + STOPPED);
+        for _ in 0..idle + STOPPED {
+            actors.push(rt.spawn(Idle).unwrap());
+        }
+
+        let mut wait = rt.wait();
+
+        // We measure how long it takes for the
+        // runtime to yield the stopped actors
+        // while the idle ones keep running.
+        let start = Instant::now();
+
+        // The futures resolving with the result
+        // of the actions are dropped as we wait
+        // for the actors to stop using the runtime.
+        for spawned in actors.iter_mut().take(STOPPED) {
+            let _ = spawned.try_send_action(Stop).unwrap();
+        }
+
+        for _ in 0..STOPPED {
+            wait.next()
+                .await
+                .unwrap()
+                .expect("an error occured while waiting for an actor to stop");
+        }
+
+        let elapsed = start.elapsed();
+
+        println!(
+            "{:>7} idle actors: {} actors stopped in {:?} ({:?} per actor)",
+            idle,
+            STOPPED,
+            elapsed,
+            elapsed / STOPPED as u32,
+        );
+    }
+}
@@ -3,7 +3,7 @@ pub use aktoro_raw as raw;
 #[cfg(feature = "context")]
 pub use aktoro_context as context;
 
-#[cfg(feature = "runtime")]
+#[cfg(any(feature = "runtime", feature = "tokio", feature = "async-std"))]
 pub use aktoro_runtime as runtime;
 
 pub mod prelude {
@@ -13,11 +13,53 @@ pub mod prelude {
     pub use aktoro_raw::Context as RawContext;
 
     pub use aktoro_raw::ActionHandler;
+    pub use aktoro_raw::BatchHandler;
     pub use aktoro_raw::EventHandler;
     pub use aktoro_raw::Handler;
 
     pub use aktoro_raw::Runtime as RawRuntime;
     pub use aktoro_raw::Spawned;
+    pub use aktoro_raw::WeakSpawned;
+
+    pub use aktoro_raw::Blocking;
+    pub use aktoro_raw::BlockingExecutor;
+    pub use aktoro_raw::BlockingSpawner;
+    pub use aktoro_raw::Executor;
+    pub use aktoro_raw::Spawner;
+
+    pub use aktoro_raw::Batch;
+
+    pub use aktoro_raw::Ask;
+    pub use aktoro_raw::AskError;
+
+    pub use aktoro_raw::Delayed;
+    pub use aktoro_raw::Repeated;
+    pub use aktoro_raw::Timer as RawTimer;
+
+    pub use aktoro_raw::MailboxStats;
+
+    pub use aktoro_raw::DeadLetter;
+    pub use aktoro_raw::DeadLetterReason;
+    pub use aktoro_raw::DeadLetterSink;
+
+    pub use aktoro_raw::Down;
+    pub use aktoro_raw::Monitors;
+
+    pub use aktoro_raw::EventBus as RawEventBus;
+    pub use aktoro_raw::Topic as RawTopic;
+
+    pub use aktoro_raw::Recipient;
+    pub use aktoro_raw::RecipientError;
+
+    pub use aktoro_raw::Registry;
+    pub use aktoro_raw::RegistryError;
+
+    pub use aktoro_raw::Directive;
+    pub use aktoro_raw::Factory;
+    pub use aktoro_raw::Intensity;
+    pub use aktoro_raw::Strategy;
+    pub use aktoro_raw::SupervisionError;
+    pub use aktoro_raw::Supervisor;
 
     pub use aktoro_raw::Controlled as RawControlled;
     pub use aktoro_raw::Controller as RawController;
@@ -37,6 +79,6 @@ pub mod prelude {
     #[cfg(feature = "context")]
     pub use crate::context::*;
 
-    #[cfg(feature = "runtime")]
+    #[cfg(any(feature = "runtime", feature = "tokio", feature = "async-std"))]
     pub use crate::runtime::*;
 }